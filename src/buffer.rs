@@ -2,11 +2,17 @@
 
 use crate::{
     conntrack::ConntrackMessage,
+    cthelper::HelperMessage,
+    cttimeout::TimeoutMessage,
+    expect::ExpectMessage,
     message::{
         NetfilterHeader, NetfilterMessage, NetfilterMessageInner, Subsystem,
         NETFILTER_HEADER_LEN,
     },
+    nfacct::AcctMessage,
     nflog::ULogMessage,
+    nfqueue::QueueMessage,
+    nftables::NftMessage,
 };
 use netlink_packet_core::{
     buffer, fields, DecodeError, DefaultNla, ErrorContext, NlaBuffer,
@@ -62,6 +68,36 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 ConntrackMessage::parse_with_param(buf, message_type)
                     .context("failed to parse conntrack payload")?,
             ),
+            Subsystem::ConntrackExpect => {
+                NetfilterMessageInner::ConntrackExpect(
+                    ExpectMessage::parse_with_param(buf, message_type)
+                        .context("failed to parse conntrack expect payload")?,
+                )
+            }
+            Subsystem::ConntrackTimeout => {
+                NetfilterMessageInner::ConntrackTimeout(
+                    TimeoutMessage::parse_with_param(buf, message_type)
+                        .context("failed to parse cttimeout payload")?,
+                )
+            }
+            Subsystem::ConntrackHelper => {
+                NetfilterMessageInner::ConntrackHelper(
+                    HelperMessage::parse_with_param(buf, message_type)
+                        .context("failed to parse cthelper payload")?,
+                )
+            }
+            Subsystem::Acct => NetfilterMessageInner::Acct(
+                AcctMessage::parse_with_param(buf, message_type)
+                    .context("failed to parse nfacct payload")?,
+            ),
+            Subsystem::Queue => NetfilterMessageInner::Queue(
+                QueueMessage::parse_with_param(buf, message_type)
+                    .context("failed to parse nfqueue payload")?,
+            ),
+            Subsystem::Nftables => NetfilterMessageInner::Nftables(
+                NftMessage::parse_with_param(buf, message_type)
+                    .context("failed to parse nftables payload")?,
+            ),
             subsys_enum @ Subsystem::Other(_) => NetfilterMessageInner::Other {
                 subsys: subsys_enum,
                 message_type,
@@ -1,20 +1,43 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_core::{
-    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
-    Nla, NlaBuffer, NlasIterator, Parseable,
+    emit_u16_be, emit_u32_be, parse_u16_be, parse_u32_be, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable,
 };
 
 use crate::conntrack::attributes::{
-    protoinfo::ProtoInfo, status::Status, tuple::Tuple,
+    counters::Counter, filter::Filter, help::Help, labels::ConntrackLabels,
+    nat::Nat, protoinfo::ProtoInfo, secctx::SecCtx, seqadj::SeqAdj,
+    status::Status, synproxy::Synproxy, timestamp::Timestamp, tuple::Tuple,
 };
 
 const CTA_TUPLE_ORIG: u16 = 1;
 const CTA_TUPLE_REPLY: u16 = 2;
-const CTA_PROTOINFO: u16 = 4;
 const CTA_STATUS: u16 = 3;
+const CTA_PROTOINFO: u16 = 4;
+const CTA_HELP: u16 = 5;
+const CTA_NAT_SRC: u16 = 6;
 const CTA_TIMEOUT: u16 = 7;
 const CTA_MARK: u16 = 8;
+const CTA_COUNTERS_ORIG: u16 = 9;
+const CTA_COUNTERS_REPLY: u16 = 10;
+const CTA_USE: u16 = 11;
+const CTA_ID: u16 = 12;
+const CTA_NAT_DST: u16 = 13;
+const CTA_TUPLE_MASTER: u16 = 14;
+const CTA_SEQ_ADJ_ORIG: u16 = 15;
+const CTA_SEQ_ADJ_REPLY: u16 = 16;
+const CTA_SECMARK: u16 = 17;
+const CTA_ZONE: u16 = 18;
+const CTA_SECCTX: u16 = 19;
+const CTA_TIMESTAMP: u16 = 20;
+const CTA_MARK_MASK: u16 = 21;
+const CTA_LABELS: u16 = 22;
+const CTA_LABELS_MASK: u16 = 23;
+const CTA_SYNPROXY: u16 = 24;
+const CTA_FILTER: u16 = 25;
+const CTA_STATUS_MASK: u16 = 26;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -25,6 +48,26 @@ pub enum ConntrackAttribute {
     CtaStatus(Status),
     CtaTimeout(u32),
     CtaMark(u32),
+    CtaCountersOrig(Vec<Counter>),
+    CtaCountersReply(Vec<Counter>),
+    CtaTimestamp(Vec<Timestamp>),
+    CtaNatSrc(Vec<Nat>),
+    CtaNatDst(Vec<Nat>),
+    CtaSeqAdjOrig(Vec<SeqAdj>),
+    CtaSeqAdjReply(Vec<SeqAdj>),
+    CtaZone(u16),
+    CtaId(u32),
+    CtaUse(u32),
+    CtaMarkMask(u32),
+    CtaStatusMask(Status),
+    CtaFilter(Vec<Filter>),
+    CtaHelp(Vec<Help>),
+    CtaSecCtx(Vec<SecCtx>),
+    CtaSecmark(u32),
+    CtaLabels(ConntrackLabels),
+    CtaLabelsMask(ConntrackLabels),
+    CtaTupleMaster(Vec<Tuple>),
+    CtaSynproxy(Vec<Synproxy>),
     Other(DefaultNla),
 }
 
@@ -43,6 +86,50 @@ impl Nla for ConntrackAttribute {
             ConntrackAttribute::CtaStatus(_) => size_of::<u32>(),
             ConntrackAttribute::CtaTimeout(attr) => size_of_val(attr),
             ConntrackAttribute::CtaMark(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaCountersOrig(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaCountersReply(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaTimestamp(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaNatSrc(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaNatDst(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaSeqAdjOrig(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaSeqAdjReply(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaZone(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaId(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaUse(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaMarkMask(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaStatusMask(_) => size_of::<u32>(),
+            ConntrackAttribute::CtaSecmark(attr) => size_of_val(attr),
+            ConntrackAttribute::CtaLabels(attr) => attr.buffer_len(),
+            ConntrackAttribute::CtaLabelsMask(attr) => attr.buffer_len(),
+            ConntrackAttribute::CtaFilter(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaHelp(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaSecCtx(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaTupleMaster(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ConntrackAttribute::CtaSynproxy(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
             ConntrackAttribute::Other(attr) => attr.value_len(),
         }
     }
@@ -55,6 +142,26 @@ impl Nla for ConntrackAttribute {
             ConntrackAttribute::CtaStatus(_) => CTA_STATUS,
             ConntrackAttribute::CtaTimeout(_) => CTA_TIMEOUT,
             ConntrackAttribute::CtaMark(_) => CTA_MARK,
+            ConntrackAttribute::CtaCountersOrig(_) => CTA_COUNTERS_ORIG,
+            ConntrackAttribute::CtaCountersReply(_) => CTA_COUNTERS_REPLY,
+            ConntrackAttribute::CtaTimestamp(_) => CTA_TIMESTAMP,
+            ConntrackAttribute::CtaNatSrc(_) => CTA_NAT_SRC,
+            ConntrackAttribute::CtaNatDst(_) => CTA_NAT_DST,
+            ConntrackAttribute::CtaSeqAdjOrig(_) => CTA_SEQ_ADJ_ORIG,
+            ConntrackAttribute::CtaSeqAdjReply(_) => CTA_SEQ_ADJ_REPLY,
+            ConntrackAttribute::CtaZone(_) => CTA_ZONE,
+            ConntrackAttribute::CtaId(_) => CTA_ID,
+            ConntrackAttribute::CtaUse(_) => CTA_USE,
+            ConntrackAttribute::CtaMarkMask(_) => CTA_MARK_MASK,
+            ConntrackAttribute::CtaStatusMask(_) => CTA_STATUS_MASK,
+            ConntrackAttribute::CtaSecmark(_) => CTA_SECMARK,
+            ConntrackAttribute::CtaLabels(_) => CTA_LABELS,
+            ConntrackAttribute::CtaLabelsMask(_) => CTA_LABELS_MASK,
+            ConntrackAttribute::CtaFilter(_) => CTA_FILTER,
+            ConntrackAttribute::CtaHelp(_) => CTA_HELP,
+            ConntrackAttribute::CtaSecCtx(_) => CTA_SECCTX,
+            ConntrackAttribute::CtaTupleMaster(_) => CTA_TUPLE_MASTER,
+            ConntrackAttribute::CtaSynproxy(_) => CTA_SYNPROXY,
             ConntrackAttribute::Other(attr) => attr.kind(),
         }
     }
@@ -91,6 +198,110 @@ impl Nla for ConntrackAttribute {
             ConntrackAttribute::CtaMark(attr) => {
                 emit_u32_be(buffer, *attr).unwrap()
             }
+            ConntrackAttribute::CtaCountersOrig(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaCountersReply(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaTimestamp(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaNatSrc(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaNatDst(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaSeqAdjOrig(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaSeqAdjReply(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaZone(attr) => {
+                emit_u16_be(buffer, *attr).unwrap()
+            }
+            ConntrackAttribute::CtaId(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ConntrackAttribute::CtaUse(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ConntrackAttribute::CtaMarkMask(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ConntrackAttribute::CtaStatusMask(attr) => {
+                emit_u32_be(buffer, (*attr).bits()).unwrap()
+            }
+            ConntrackAttribute::CtaSecmark(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ConntrackAttribute::CtaLabels(attr) => attr.emit(buffer),
+            ConntrackAttribute::CtaLabelsMask(attr) => attr.emit(buffer),
+            ConntrackAttribute::CtaFilter(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaHelp(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaSecCtx(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaTupleMaster(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ConntrackAttribute::CtaSynproxy(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
             ConntrackAttribute::Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -100,6 +311,18 @@ impl Nla for ConntrackAttribute {
             ConntrackAttribute::CtaTupleOrig(_)
                 | ConntrackAttribute::CtaTupleReply(_)
                 | ConntrackAttribute::CtaProtoInfo(_)
+                | ConntrackAttribute::CtaCountersOrig(_)
+                | ConntrackAttribute::CtaCountersReply(_)
+                | ConntrackAttribute::CtaTimestamp(_)
+                | ConntrackAttribute::CtaNatSrc(_)
+                | ConntrackAttribute::CtaNatDst(_)
+                | ConntrackAttribute::CtaSeqAdjOrig(_)
+                | ConntrackAttribute::CtaSeqAdjReply(_)
+                | ConntrackAttribute::CtaFilter(_)
+                | ConntrackAttribute::CtaHelp(_)
+                | ConntrackAttribute::CtaSecCtx(_)
+                | ConntrackAttribute::CtaTupleMaster(_)
+                | ConntrackAttribute::CtaSynproxy(_)
         )
     }
 }
@@ -149,6 +372,136 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
             CTA_MARK => ConntrackAttribute::CtaMark(
                 parse_u32_be(payload).context("invalid CTA_MARK value")?,
             ),
+            CTA_COUNTERS_ORIG => {
+                let mut counters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_COUNTERS_ORIG value")?;
+                    counters.push(Counter::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaCountersOrig(counters)
+            }
+            CTA_COUNTERS_REPLY => {
+                let mut counters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_COUNTERS_REPLY value")?;
+                    counters.push(Counter::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaCountersReply(counters)
+            }
+            CTA_TIMESTAMP => {
+                let mut timestamps = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_TIMESTAMP value")?;
+                    timestamps.push(Timestamp::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaTimestamp(timestamps)
+            }
+            CTA_NAT_SRC => {
+                let mut nats = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_NAT_SRC value")?;
+                    nats.push(Nat::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaNatSrc(nats)
+            }
+            CTA_NAT_DST => {
+                let mut nats = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_NAT_DST value")?;
+                    nats.push(Nat::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaNatDst(nats)
+            }
+            CTA_SEQ_ADJ_ORIG => {
+                let mut seq_adjs = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_SEQ_ADJ_ORIG value")?;
+                    seq_adjs.push(SeqAdj::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaSeqAdjOrig(seq_adjs)
+            }
+            CTA_SEQ_ADJ_REPLY => {
+                let mut seq_adjs = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_SEQ_ADJ_REPLY value")?;
+                    seq_adjs.push(SeqAdj::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaSeqAdjReply(seq_adjs)
+            }
+            CTA_ZONE => ConntrackAttribute::CtaZone(
+                parse_u16_be(payload).context("invalid CTA_ZONE value")?,
+            ),
+            CTA_ID => ConntrackAttribute::CtaId(
+                parse_u32_be(payload).context("invalid CTA_ID value")?,
+            ),
+            CTA_USE => ConntrackAttribute::CtaUse(
+                parse_u32_be(payload).context("invalid CTA_USE value")?,
+            ),
+            CTA_MARK_MASK => ConntrackAttribute::CtaMarkMask(
+                parse_u32_be(payload).context("invalid CTA_MARK_MASK value")?,
+            ),
+            CTA_SECMARK => ConntrackAttribute::CtaSecmark(
+                parse_u32_be(payload).context("invalid CTA_SECMARK value")?,
+            ),
+            CTA_LABELS => ConntrackAttribute::CtaLabels(
+                ConntrackLabels::parse(payload)
+                    .context("invalid CTA_LABELS value")?,
+            ),
+            CTA_LABELS_MASK => ConntrackAttribute::CtaLabelsMask(
+                ConntrackLabels::parse(payload)
+                    .context("invalid CTA_LABELS_MASK value")?,
+            ),
+            CTA_STATUS_MASK => {
+                ConntrackAttribute::CtaStatusMask(Status::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid CTA_STATUS_MASK value")?,
+                ))
+            }
+            CTA_FILTER => {
+                let mut filters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_FILTER value")?;
+                    filters.push(Filter::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaFilter(filters)
+            }
+            CTA_HELP => {
+                let mut helps = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_HELP value")?;
+                    helps.push(Help::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaHelp(helps)
+            }
+            CTA_SECCTX => {
+                let mut secctxs = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_SECCTX value")?;
+                    secctxs.push(SecCtx::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaSecCtx(secctxs)
+            }
+            CTA_TUPLE_MASTER => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_TUPLE_MASTER value")?;
+                    tuples.push(Tuple::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaTupleMaster(tuples)
+            }
+            CTA_SYNPROXY => {
+                let mut synproxy = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_SYNPROXY value")?;
+                    synproxy.push(Synproxy::parse(nlas)?);
+                }
+                ConntrackAttribute::CtaSynproxy(synproxy)
+            }
             _ => ConntrackAttribute::Other(DefaultNla::parse(buf)?),
         };
         Ok(nla)
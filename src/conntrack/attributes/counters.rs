@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_u32_be, parse_u64_be, DecodeError,
+    DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+const CTA_COUNTERS_PACKETS: u16 = 1;
+const CTA_COUNTERS_BYTES: u16 = 2;
+const CTA_COUNTERS32_PACKETS: u16 = 3;
+const CTA_COUNTERS32_BYTES: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Counter {
+    Packets(u64),
+    Bytes(u64),
+    // Legacy 32 bit counters, no longer sent by recent kernels
+    Packets32(u32),
+    Bytes32(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Counter {
+    fn value_len(&self) -> usize {
+        match self {
+            Counter::Packets(attr) => size_of_val(attr),
+            Counter::Bytes(attr) => size_of_val(attr),
+            Counter::Packets32(attr) => size_of_val(attr),
+            Counter::Bytes32(attr) => size_of_val(attr),
+            Counter::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Counter::Packets(_) => CTA_COUNTERS_PACKETS,
+            Counter::Bytes(_) => CTA_COUNTERS_BYTES,
+            Counter::Packets32(_) => CTA_COUNTERS32_PACKETS,
+            Counter::Bytes32(_) => CTA_COUNTERS32_BYTES,
+            Counter::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Counter::Packets(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Counter::Bytes(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Counter::Packets32(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            Counter::Bytes32(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            Counter::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Counter
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_COUNTERS_PACKETS => Counter::Packets(
+                parse_u64_be(payload)
+                    .context("invalid CTA_COUNTERS_PACKETS value")?,
+            ),
+            CTA_COUNTERS_BYTES => Counter::Bytes(
+                parse_u64_be(payload)
+                    .context("invalid CTA_COUNTERS_BYTES value")?,
+            ),
+            CTA_COUNTERS32_PACKETS => Counter::Packets32(
+                parse_u32_be(payload)
+                    .context("invalid CTA_COUNTERS32_PACKETS value")?,
+            ),
+            CTA_COUNTERS32_BYTES => Counter::Bytes32(
+                parse_u32_be(payload)
+                    .context("invalid CTA_COUNTERS32_BYTES value")?,
+            ),
+            _ => Counter::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+// DCCP conntrack states and roles from linux/netfilter/nf_conntrack_dccp.h
+const CT_DCCP_NONE: u8 = 0;
+const CT_DCCP_REQUEST: u8 = 1;
+const CT_DCCP_RESPOND: u8 = 2;
+const CT_DCCP_PARTOPEN: u8 = 3;
+const CT_DCCP_OPEN: u8 = 4;
+const CT_DCCP_CLOSEREQ: u8 = 5;
+const CT_DCCP_CLOSING: u8 = 6;
+const CT_DCCP_TIMEWAIT: u8 = 7;
+const CT_DCCP_IGNORE: u8 = 8;
+const CT_DCCP_INVALID: u8 = 9;
+
+const CT_DCCP_ROLE_CLIENT: u8 = 0;
+const CT_DCCP_ROLE_SERVER: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DccpState {
+    None,
+    Request,
+    Respond,
+    PartOpen,
+    Open,
+    CloseReq,
+    Closing,
+    TimeWait,
+    Ignore,
+    Invalid,
+    Other(u8),
+}
+
+impl From<DccpState> for u8 {
+    fn from(state: DccpState) -> Self {
+        match state {
+            DccpState::None => CT_DCCP_NONE,
+            DccpState::Request => CT_DCCP_REQUEST,
+            DccpState::Respond => CT_DCCP_RESPOND,
+            DccpState::PartOpen => CT_DCCP_PARTOPEN,
+            DccpState::Open => CT_DCCP_OPEN,
+            DccpState::CloseReq => CT_DCCP_CLOSEREQ,
+            DccpState::Closing => CT_DCCP_CLOSING,
+            DccpState::TimeWait => CT_DCCP_TIMEWAIT,
+            DccpState::Ignore => CT_DCCP_IGNORE,
+            DccpState::Invalid => CT_DCCP_INVALID,
+            DccpState::Other(s) => s,
+        }
+    }
+}
+
+impl From<u8> for DccpState {
+    fn from(state: u8) -> Self {
+        match state {
+            CT_DCCP_NONE => DccpState::None,
+            CT_DCCP_REQUEST => DccpState::Request,
+            CT_DCCP_RESPOND => DccpState::Respond,
+            CT_DCCP_PARTOPEN => DccpState::PartOpen,
+            CT_DCCP_OPEN => DccpState::Open,
+            CT_DCCP_CLOSEREQ => DccpState::CloseReq,
+            CT_DCCP_CLOSING => DccpState::Closing,
+            CT_DCCP_TIMEWAIT => DccpState::TimeWait,
+            CT_DCCP_IGNORE => DccpState::Ignore,
+            CT_DCCP_INVALID => DccpState::Invalid,
+            _ => DccpState::Other(state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DccpRole {
+    Client,
+    Server,
+    Other(u8),
+}
+
+impl From<DccpRole> for u8 {
+    fn from(role: DccpRole) -> Self {
+        match role {
+            DccpRole::Client => CT_DCCP_ROLE_CLIENT,
+            DccpRole::Server => CT_DCCP_ROLE_SERVER,
+            DccpRole::Other(r) => r,
+        }
+    }
+}
+
+impl From<u8> for DccpRole {
+    fn from(role: u8) -> Self {
+        match role {
+            CT_DCCP_ROLE_CLIENT => DccpRole::Client,
+            CT_DCCP_ROLE_SERVER => DccpRole::Server,
+            _ => DccpRole::Other(role),
+        }
+    }
+}
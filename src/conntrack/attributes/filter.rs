@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+use netlink_packet_core::{
+    emit_u32, parse_u32, DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer,
+    Parseable,
+};
+
+const CTA_FILTER_ORIG_FLAGS: u16 = 1;
+const CTA_FILTER_REPLY_FLAGS: u16 = 2;
+
+// Filter flags from net/netfilter/nf_conntrack_netlink.c, each one selects a
+// tuple field the kernel compares when dumping or flushing. Unlike the other
+// ctnetlink attributes, they are in host byte order.
+const CTA_FILTER_FLAG_CTA_IP_SRC: u32 = 1;
+const CTA_FILTER_FLAG_CTA_IP_DST: u32 = 1 << 1;
+const CTA_FILTER_FLAG_CTA_TUPLE_ZONE: u32 = 1 << 2;
+const CTA_FILTER_FLAG_CTA_PROTO_NUM: u32 = 1 << 3;
+const CTA_FILTER_FLAG_CTA_PROTO_SRC_PORT: u32 = 1 << 4;
+const CTA_FILTER_FLAG_CTA_PROTO_DST_PORT: u32 = 1 << 5;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMP_TYPE: u32 = 1 << 6;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMP_CODE: u32 = 1 << 7;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMP_ID: u32 = 1 << 8;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_TYPE: u32 = 1 << 9;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_CODE: u32 = 1 << 10;
+const CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_ID: u32 = 1 << 11;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FilterFlags: u32 {
+        const IpSrc         = CTA_FILTER_FLAG_CTA_IP_SRC;
+        const IpDst         = CTA_FILTER_FLAG_CTA_IP_DST;
+        const TupleZone     = CTA_FILTER_FLAG_CTA_TUPLE_ZONE;
+        const ProtoNum      = CTA_FILTER_FLAG_CTA_PROTO_NUM;
+        const ProtoSrcPort  = CTA_FILTER_FLAG_CTA_PROTO_SRC_PORT;
+        const ProtoDstPort  = CTA_FILTER_FLAG_CTA_PROTO_DST_PORT;
+        const IcmpType      = CTA_FILTER_FLAG_CTA_PROTO_ICMP_TYPE;
+        const IcmpCode      = CTA_FILTER_FLAG_CTA_PROTO_ICMP_CODE;
+        const IcmpId        = CTA_FILTER_FLAG_CTA_PROTO_ICMP_ID;
+        const Icmpv6Type    = CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_TYPE;
+        const Icmpv6Code    = CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_CODE;
+        const Icmpv6Id      = CTA_FILTER_FLAG_CTA_PROTO_ICMPV6_ID;
+        const _ = !0;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Filter {
+    OrigFlags(FilterFlags),
+    ReplyFlags(FilterFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for Filter {
+    fn value_len(&self) -> usize {
+        match self {
+            Filter::OrigFlags(_) => size_of::<u32>(),
+            Filter::ReplyFlags(_) => size_of::<u32>(),
+            Filter::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Filter::OrigFlags(_) => CTA_FILTER_ORIG_FLAGS,
+            Filter::ReplyFlags(_) => CTA_FILTER_REPLY_FLAGS,
+            Filter::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Filter::OrigFlags(attr) => emit_u32(buffer, attr.bits()).unwrap(),
+            Filter::ReplyFlags(attr) => emit_u32(buffer, attr.bits()).unwrap(),
+            Filter::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Filter
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_FILTER_ORIG_FLAGS => {
+                Filter::OrigFlags(FilterFlags::from_bits_retain(
+                    parse_u32(payload)
+                        .context("invalid CTA_FILTER_ORIG_FLAGS value")?,
+                ))
+            }
+            CTA_FILTER_REPLY_FLAGS => {
+                Filter::ReplyFlags(FilterFlags::from_bits_retain(
+                    parse_u32(payload)
+                        .context("invalid CTA_FILTER_REPLY_FLAGS value")?,
+                ))
+            }
+            _ => Filter::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_string, DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer,
+    Parseable,
+};
+
+const CTA_HELP_NAME: u16 = 1;
+const CTA_HELP_INFO: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Help {
+    Name(String),
+    // Helper specific private data, only sent by userspace helpers
+    Info(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Help {
+    fn value_len(&self) -> usize {
+        match self {
+            Help::Name(attr) => attr.len() + 1,
+            Help::Info(attr) => attr.len(),
+            Help::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Help::Name(_) => CTA_HELP_NAME,
+            Help::Info(_) => CTA_HELP_INFO,
+            Help::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Help::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            Help::Info(attr) => buffer.copy_from_slice(attr),
+            Help::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Help
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_HELP_NAME => Help::Name(
+                parse_string(payload).context("invalid CTA_HELP_NAME value")?,
+            ),
+            CTA_HELP_INFO => Help::Info(payload.to_vec()),
+            _ => Help::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use std::{convert::TryInto, iter::FromIterator};
+
+use netlink_packet_core::{DecodeError, Parseable};
+
+const LABEL_WORDS: usize = 4;
+const LABEL_WORD_BITS: u32 = u32::BITS;
+
+// The 128 bit connlabel bitmap of CTA_LABELS and CTA_LABELS_MASK. The kernel
+// copies its bitmap of unsigned long as is, which userspace (and this type)
+// reads as 32 bit words in host byte order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConntrackLabels {
+    words: [u32; LABEL_WORDS],
+}
+
+impl ConntrackLabels {
+    pub const MAX_BITS: u32 = LABEL_WORDS as u32 * LABEL_WORD_BITS;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Panics if `bit` is not lower than `ConntrackLabels::MAX_BITS`
+    pub fn set(&mut self, bit: u32) {
+        let (word, mask) = Self::position(bit);
+        self.words[word] |= mask;
+    }
+
+    // Panics if `bit` is not lower than `ConntrackLabels::MAX_BITS`
+    pub fn clear(&mut self, bit: u32) {
+        let (word, mask) = Self::position(bit);
+        self.words[word] &= !mask;
+    }
+
+    pub fn is_set(&self, bit: u32) -> bool {
+        bit < Self::MAX_BITS && {
+            let (word, mask) = Self::position(bit);
+            self.words[word] & mask != 0
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    // Iterates over the bits which are set, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..Self::MAX_BITS).filter(move |bit| self.is_set(*bit))
+    }
+
+    fn position(bit: u32) -> (usize, u32) {
+        assert!(
+            bit < Self::MAX_BITS,
+            "connlabel bit {bit} out of range, must be lower than {}",
+            Self::MAX_BITS
+        );
+        (
+            (bit / LABEL_WORD_BITS) as usize,
+            1 << (bit % LABEL_WORD_BITS),
+        )
+    }
+
+    pub(crate) fn buffer_len(&self) -> usize {
+        LABEL_WORDS * size_of::<u32>()
+    }
+
+    pub(crate) fn emit(&self, buffer: &mut [u8]) {
+        for (chunk, word) in buffer.chunks_exact_mut(4).zip(self.words) {
+            chunk.copy_from_slice(&word.to_ne_bytes());
+        }
+    }
+}
+
+impl FromIterator<u32> for ConntrackLabels {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut labels = Self::new();
+        for bit in iter {
+            labels.set(bit);
+        }
+        labels
+    }
+}
+
+impl Parseable<[u8]> for ConntrackLabels {
+    fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        // Shorter bitmaps are accepted by the kernel, missing words are zero
+        if !buf.chunks_exact(4).remainder().is_empty()
+            || buf.len() > LABEL_WORDS * size_of::<u32>()
+        {
+            return Err(DecodeError::from(format!(
+                "invalid connlabel bitmap length {}",
+                buf.len()
+            )));
+        }
+        let mut labels = Self::new();
+        for (word, chunk) in labels.words.iter_mut().zip(buf.chunks_exact(4)) {
+            *word = u32::from_ne_bytes(chunk.try_into().unwrap());
+        }
+        Ok(labels)
+    }
+}
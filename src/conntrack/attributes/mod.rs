@@ -1,19 +1,51 @@
 // SPDX-License-Identifier: MIT
 
 mod attribute;
+mod counters;
+mod dccp_state;
+mod filter;
+mod help;
 mod iptuple;
+mod labels;
+mod nat;
 mod protoinfo;
+mod protoinfodccp;
+mod protoinfosctp;
 mod protoinfotcp;
+mod protonat;
 mod prototuple;
+mod sctp_state;
+mod secctx;
+mod seqadj;
+mod stats;
 mod status;
+mod synproxy;
 mod tcp_flags;
+mod tcp_state;
+mod timestamp;
 mod tuple;
 
 pub use attribute::ConntrackAttribute;
+pub use counters::Counter;
+pub use dccp_state::{DccpRole, DccpState};
+pub use filter::{Filter, FilterFlags};
+pub use help::Help;
 pub use iptuple::IPTuple;
+pub use labels::ConntrackLabels;
+pub use nat::Nat;
 pub use protoinfo::ProtoInfo;
+pub use protoinfodccp::ProtoInfoDCCP;
+pub use protoinfosctp::ProtoInfoSCTP;
 pub use protoinfotcp::ProtoInfoTCP;
+pub use protonat::ProtoNat;
 pub use prototuple::{ProtoTuple, Protocol};
+pub use sctp_state::SctpState;
+pub use secctx::SecCtx;
+pub use seqadj::SeqAdj;
+pub use stats::{StatsCpu, StatsGlobal};
 pub use status::Status;
-pub use tcp_flags::TCPFlags;
+pub use synproxy::Synproxy;
+pub use tcp_flags::{TCPFlags, TCPWindowFlags};
+pub use tcp_state::TcpState;
+pub use timestamp::Timestamp;
 pub use tuple::Tuple;
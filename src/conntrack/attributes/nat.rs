@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_ip, DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+use std::net::IpAddr;
+
+use crate::conntrack::attributes::{iptuple::emit_ip, protonat::ProtoNat};
+
+const CTA_NAT_V4_MINIP: u16 = 1;
+const CTA_NAT_V4_MAXIP: u16 = 2;
+const CTA_NAT_PROTO: u16 = 3;
+const CTA_NAT_V6_MINIP: u16 = 4;
+const CTA_NAT_V6_MAXIP: u16 = 5;
+
+const IPV4_LEN: usize = 4;
+const IPV6_LEN: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Nat {
+    MinIp(IpAddr),
+    MaxIp(IpAddr),
+    Proto(Vec<ProtoNat>),
+    Other(DefaultNla),
+}
+
+impl Nla for Nat {
+    fn value_len(&self) -> usize {
+        match self {
+            Nat::MinIp(attr) | Nat::MaxIp(attr) => match *attr {
+                IpAddr::V4(_) => IPV4_LEN,
+                IpAddr::V6(_) => IPV6_LEN,
+            },
+            Nat::Proto(nlas) => nlas.iter().map(|op| op.buffer_len()).sum(),
+            Nat::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Nat::MinIp(attr) => match *attr {
+                IpAddr::V4(_) => CTA_NAT_V4_MINIP,
+                IpAddr::V6(_) => CTA_NAT_V6_MINIP,
+            },
+            Nat::MaxIp(attr) => match *attr {
+                IpAddr::V4(_) => CTA_NAT_V4_MAXIP,
+                IpAddr::V6(_) => CTA_NAT_V6_MAXIP,
+            },
+            Nat::Proto(_) => CTA_NAT_PROTO,
+            Nat::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Nat::MinIp(attr) => emit_ip(attr, buffer),
+            Nat::MaxIp(attr) => emit_ip(attr, buffer),
+            Nat::Proto(nlas) => {
+                let mut len = 0;
+                for op in nlas {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            Nat::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Nat::Proto(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Nat
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_NAT_V4_MINIP | CTA_NAT_V6_MINIP => Nat::MinIp(
+                parse_ip(payload).context("invalid CTA_NAT_MINIP value")?,
+            ),
+            CTA_NAT_V4_MAXIP | CTA_NAT_V6_MAXIP => Nat::MaxIp(
+                parse_ip(payload).context("invalid CTA_NAT_MAXIP value")?,
+            ),
+            CTA_NAT_PROTO => {
+                let mut proto_nats = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_NAT_PROTO value")?;
+                    proto_nats.push(ProtoNat::parse(nlas)?);
+                }
+                Nat::Proto(proto_nats)
+            }
+            _ => Nat::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
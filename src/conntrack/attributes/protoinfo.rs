@@ -5,20 +5,33 @@ use netlink_packet_core::{
     NlasIterator, Parseable,
 };
 
-use crate::conntrack::attributes::protoinfotcp::ProtoInfoTCP;
+use crate::conntrack::attributes::{
+    protoinfodccp::ProtoInfoDCCP, protoinfosctp::ProtoInfoSCTP,
+    protoinfotcp::ProtoInfoTCP,
+};
 
 const CTA_PROTOINFO_TCP: u16 = 1;
+const CTA_PROTOINFO_DCCP: u16 = 2;
+const CTA_PROTOINFO_SCTP: u16 = 3;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ProtoInfo {
     TCP(Vec<ProtoInfoTCP>),
+    Dccp(Vec<ProtoInfoDCCP>),
+    Sctp(Vec<ProtoInfoSCTP>),
     Other(DefaultNla),
 }
 impl Nla for ProtoInfo {
     fn value_len(&self) -> usize {
         match self {
             ProtoInfo::TCP(nlas) => nlas.iter().map(|op| op.buffer_len()).sum(),
+            ProtoInfo::Dccp(nlas) => {
+                nlas.iter().map(|op| op.buffer_len()).sum()
+            }
+            ProtoInfo::Sctp(nlas) => {
+                nlas.iter().map(|op| op.buffer_len()).sum()
+            }
             ProtoInfo::Other(attr) => attr.value_len(),
         }
     }
@@ -26,6 +39,8 @@ impl Nla for ProtoInfo {
     fn kind(&self) -> u16 {
         match self {
             ProtoInfo::TCP(_) => CTA_PROTOINFO_TCP,
+            ProtoInfo::Dccp(_) => CTA_PROTOINFO_DCCP,
+            ProtoInfo::Sctp(_) => CTA_PROTOINFO_SCTP,
             ProtoInfo::Other(attr) => attr.kind(),
         }
     }
@@ -38,11 +53,28 @@ impl Nla for ProtoInfo {
                     len += op.buffer_len();
                 }
             }
+            ProtoInfo::Dccp(nlas) => {
+                let mut len = 0;
+                for op in nlas {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ProtoInfo::Sctp(nlas) => {
+                let mut len = 0;
+                for op in nlas {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
             ProtoInfo::Other(attr) => attr.emit_value(buffer),
         }
     }
     fn is_nested(&self) -> bool {
-        matches!(self, ProtoInfo::TCP(_))
+        matches!(
+            self,
+            ProtoInfo::TCP(_) | ProtoInfo::Dccp(_) | ProtoInfo::Sctp(_)
+        )
     }
 }
 
@@ -62,6 +94,24 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
                 }
                 ProtoInfo::TCP(proto_info_tcps)
             }
+            CTA_PROTOINFO_DCCP => {
+                let mut proto_info_dccps = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_PROTOINFO_DCCP value")?;
+                    proto_info_dccps.push(ProtoInfoDCCP::parse(nlas)?);
+                }
+                ProtoInfo::Dccp(proto_info_dccps)
+            }
+            CTA_PROTOINFO_SCTP => {
+                let mut proto_info_sctps = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_PROTOINFO_SCTP value")?;
+                    proto_info_sctps.push(ProtoInfoSCTP::parse(nlas)?);
+                }
+                ProtoInfo::Sctp(proto_info_sctps)
+            }
             _ => ProtoInfo::Other(DefaultNla::parse(buf)?),
         };
         Ok(nla)
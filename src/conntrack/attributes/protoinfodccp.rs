@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u64_be, parse_u64_be, parse_u8, DecodeError, DefaultNla, ErrorContext,
+    Nla, NlaBuffer, Parseable,
+};
+
+use crate::conntrack::attributes::dccp_state::{DccpRole, DccpState};
+
+const CTA_PROTOINFO_DCCP_STATE: u16 = 1;
+const CTA_PROTOINFO_DCCP_ROLE: u16 = 2;
+const CTA_PROTOINFO_DCCP_HANDSHAKE_SEQ: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProtoInfoDCCP {
+    State(DccpState),
+    Role(DccpRole),
+    HandshakeSeq(u64),
+    Other(DefaultNla),
+}
+impl Nla for ProtoInfoDCCP {
+    fn value_len(&self) -> usize {
+        match self {
+            ProtoInfoDCCP::State(_) => size_of::<u8>(),
+            ProtoInfoDCCP::Role(_) => size_of::<u8>(),
+            ProtoInfoDCCP::HandshakeSeq(attr) => size_of_val(attr),
+            ProtoInfoDCCP::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ProtoInfoDCCP::State(_) => CTA_PROTOINFO_DCCP_STATE,
+            ProtoInfoDCCP::Role(_) => CTA_PROTOINFO_DCCP_ROLE,
+            ProtoInfoDCCP::HandshakeSeq(_) => CTA_PROTOINFO_DCCP_HANDSHAKE_SEQ,
+            ProtoInfoDCCP::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ProtoInfoDCCP::State(attr) => buffer[0] = (*attr).into(),
+            ProtoInfoDCCP::Role(attr) => buffer[0] = (*attr).into(),
+            ProtoInfoDCCP::HandshakeSeq(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            ProtoInfoDCCP::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ProtoInfoDCCP
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_PROTOINFO_DCCP_STATE => ProtoInfoDCCP::State(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTOINFO_DCCP_STATE value")?
+                    .into(),
+            ),
+            CTA_PROTOINFO_DCCP_ROLE => ProtoInfoDCCP::Role(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTOINFO_DCCP_ROLE value")?
+                    .into(),
+            ),
+            CTA_PROTOINFO_DCCP_HANDSHAKE_SEQ => {
+                ProtoInfoDCCP::HandshakeSeq(parse_u64_be(payload).context(
+                    "invalid CTA_PROTOINFO_DCCP_HANDSHAKE_SEQ value",
+                )?)
+            }
+            _ => ProtoInfoDCCP::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
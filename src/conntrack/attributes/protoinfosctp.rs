@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, parse_u8, DecodeError, DefaultNla, ErrorContext,
+    Nla, NlaBuffer, Parseable,
+};
+
+use crate::conntrack::attributes::sctp_state::SctpState;
+
+const CTA_PROTOINFO_SCTP_STATE: u16 = 1;
+const CTA_PROTOINFO_SCTP_VTAG_ORIGINAL: u16 = 2;
+const CTA_PROTOINFO_SCTP_VTAG_REPLY: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProtoInfoSCTP {
+    State(SctpState),
+    OriginalVerificationTag(u32),
+    ReplyVerificationTag(u32),
+    Other(DefaultNla),
+}
+impl Nla for ProtoInfoSCTP {
+    fn value_len(&self) -> usize {
+        match self {
+            ProtoInfoSCTP::State(_) => size_of::<u8>(),
+            ProtoInfoSCTP::OriginalVerificationTag(attr) => size_of_val(attr),
+            ProtoInfoSCTP::ReplyVerificationTag(attr) => size_of_val(attr),
+            ProtoInfoSCTP::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ProtoInfoSCTP::State(_) => CTA_PROTOINFO_SCTP_STATE,
+            ProtoInfoSCTP::OriginalVerificationTag(_) => {
+                CTA_PROTOINFO_SCTP_VTAG_ORIGINAL
+            }
+            ProtoInfoSCTP::ReplyVerificationTag(_) => {
+                CTA_PROTOINFO_SCTP_VTAG_REPLY
+            }
+            ProtoInfoSCTP::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ProtoInfoSCTP::State(attr) => buffer[0] = (*attr).into(),
+            ProtoInfoSCTP::OriginalVerificationTag(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ProtoInfoSCTP::ReplyVerificationTag(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ProtoInfoSCTP::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ProtoInfoSCTP
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_PROTOINFO_SCTP_STATE => ProtoInfoSCTP::State(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTOINFO_SCTP_STATE value")?
+                    .into(),
+            ),
+            CTA_PROTOINFO_SCTP_VTAG_ORIGINAL => {
+                ProtoInfoSCTP::OriginalVerificationTag(
+                    parse_u32_be(payload).context(
+                        "invalid CTA_PROTOINFO_SCTP_VTAG_ORIGINAL value",
+                    )?,
+                )
+            }
+            CTA_PROTOINFO_SCTP_VTAG_REPLY => {
+                ProtoInfoSCTP::ReplyVerificationTag(
+                    parse_u32_be(payload).context(
+                        "invalid CTA_PROTOINFO_SCTP_VTAG_REPLY value",
+                    )?,
+                )
+            }
+            _ => ProtoInfoSCTP::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -5,7 +5,10 @@ use netlink_packet_core::{
     Parseable,
 };
 
-use crate::conntrack::attributes::tcp_flags::{TCPFlags, TCPFlagsBuffer};
+use crate::conntrack::attributes::{
+    tcp_flags::{TCPFlags, TCPFlagsBuffer},
+    tcp_state::TcpState,
+};
 
 const CTA_PROTOINFO_TCP_STATE: u16 = 1;
 const CTA_PROTOINFO_TCP_WSCALE_ORIGINAL: u16 = 2;
@@ -16,7 +19,7 @@ const CTA_PROTOINFO_TCP_FLAGS_REPLY: u16 = 5;
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ProtoInfoTCP {
-    State(u8),
+    State(TcpState),
     OriginalWindowScale(u8),
     ReplyWindowScale(u8),
     OriginalFlags(TCPFlags),
@@ -26,7 +29,7 @@ pub enum ProtoInfoTCP {
 impl Nla for ProtoInfoTCP {
     fn value_len(&self) -> usize {
         match self {
-            ProtoInfoTCP::State(attr) => size_of_val(attr),
+            ProtoInfoTCP::State(_) => size_of::<u8>(),
             ProtoInfoTCP::OriginalWindowScale(attr) => size_of_val(attr),
             ProtoInfoTCP::ReplyWindowScale(attr) => size_of_val(attr),
             ProtoInfoTCP::OriginalFlags(attr) => attr.buffer_len(),
@@ -50,7 +53,7 @@ impl Nla for ProtoInfoTCP {
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
-            ProtoInfoTCP::State(attr) => buffer[0] = *attr,
+            ProtoInfoTCP::State(attr) => buffer[0] = (*attr).into(),
             ProtoInfoTCP::OriginalWindowScale(attr) => buffer[0] = *attr,
             ProtoInfoTCP::ReplyWindowScale(attr) => buffer[0] = *attr,
             ProtoInfoTCP::OriginalFlags(attr) => attr.emit(buffer),
@@ -68,7 +71,8 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
         let nla = match kind {
             CTA_PROTOINFO_TCP_STATE => ProtoInfoTCP::State(
                 parse_u8(payload)
-                    .context("invalid CTA_PROTOINFO_TCP_STATE value")?,
+                    .context("invalid CTA_PROTOINFO_TCP_STATE value")?
+                    .into(),
             ),
             CTA_PROTOINFO_TCP_WSCALE_ORIGINAL => {
                 ProtoInfoTCP::OriginalWindowScale(parse_u8(payload).context(
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, parse_u16_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_PROTONAT_PORT_MIN: u16 = 1;
+const CTA_PROTONAT_PORT_MAX: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProtoNat {
+    PortMin(u16),
+    PortMax(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for ProtoNat {
+    fn value_len(&self) -> usize {
+        match self {
+            ProtoNat::PortMin(attr) => size_of_val(attr),
+            ProtoNat::PortMax(attr) => size_of_val(attr),
+            ProtoNat::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ProtoNat::PortMin(_) => CTA_PROTONAT_PORT_MIN,
+            ProtoNat::PortMax(_) => CTA_PROTONAT_PORT_MAX,
+            ProtoNat::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ProtoNat::PortMin(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            ProtoNat::PortMax(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            ProtoNat::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ProtoNat
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_PROTONAT_PORT_MIN => ProtoNat::PortMin(
+                parse_u16_be(payload)
+                    .context("invalid CTA_PROTONAT_PORT_MIN value")?,
+            ),
+            CTA_PROTONAT_PORT_MAX => ProtoNat::PortMax(
+                parse_u16_be(payload)
+                    .context("invalid CTA_PROTONAT_PORT_MAX value")?,
+            ),
+            _ => ProtoNat::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
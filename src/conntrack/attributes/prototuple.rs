@@ -75,6 +75,12 @@ impl From<u8> for Protocol {
 const CTA_PROTO_NUM: u16 = 1;
 const CTA_PROTO_SRC_PORT: u16 = 2;
 const CTA_PROTO_DST_PORT: u16 = 3;
+const CTA_PROTO_ICMP_ID: u16 = 4;
+const CTA_PROTO_ICMP_TYPE: u16 = 5;
+const CTA_PROTO_ICMP_CODE: u16 = 6;
+const CTA_PROTO_ICMPV6_ID: u16 = 7;
+const CTA_PROTO_ICMPV6_TYPE: u16 = 8;
+const CTA_PROTO_ICMPV6_CODE: u16 = 9;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -82,6 +88,12 @@ pub enum ProtoTuple {
     Protocol(Protocol),
     SourcePort(u16),
     DestinationPort(u16),
+    IcmpId(u16),
+    IcmpType(u8),
+    IcmpCode(u8),
+    Icmpv6Id(u16),
+    Icmpv6Type(u8),
+    Icmpv6Code(u8),
     Other(DefaultNla),
 }
 
@@ -91,6 +103,12 @@ impl Nla for ProtoTuple {
             ProtoTuple::Protocol(_) => size_of::<u8>(),
             ProtoTuple::SourcePort(attr) => size_of_val(attr),
             ProtoTuple::DestinationPort(attr) => size_of_val(attr),
+            ProtoTuple::IcmpId(attr) => size_of_val(attr),
+            ProtoTuple::IcmpType(attr) => size_of_val(attr),
+            ProtoTuple::IcmpCode(attr) => size_of_val(attr),
+            ProtoTuple::Icmpv6Id(attr) => size_of_val(attr),
+            ProtoTuple::Icmpv6Type(attr) => size_of_val(attr),
+            ProtoTuple::Icmpv6Code(attr) => size_of_val(attr),
             ProtoTuple::Other(attr) => attr.value_len(),
         }
     }
@@ -100,6 +118,12 @@ impl Nla for ProtoTuple {
             ProtoTuple::Protocol(_) => CTA_PROTO_NUM,
             ProtoTuple::SourcePort(_) => CTA_PROTO_SRC_PORT,
             ProtoTuple::DestinationPort(_) => CTA_PROTO_DST_PORT,
+            ProtoTuple::IcmpId(_) => CTA_PROTO_ICMP_ID,
+            ProtoTuple::IcmpType(_) => CTA_PROTO_ICMP_TYPE,
+            ProtoTuple::IcmpCode(_) => CTA_PROTO_ICMP_CODE,
+            ProtoTuple::Icmpv6Id(_) => CTA_PROTO_ICMPV6_ID,
+            ProtoTuple::Icmpv6Type(_) => CTA_PROTO_ICMPV6_TYPE,
+            ProtoTuple::Icmpv6Code(_) => CTA_PROTO_ICMPV6_CODE,
             ProtoTuple::Other(attr) => attr.kind(),
         }
     }
@@ -111,6 +135,12 @@ impl Nla for ProtoTuple {
             ProtoTuple::DestinationPort(attr) => {
                 emit_u16_be(buffer, *attr).unwrap()
             }
+            ProtoTuple::IcmpId(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            ProtoTuple::IcmpType(attr) => buffer[0] = *attr,
+            ProtoTuple::IcmpCode(attr) => buffer[0] = *attr,
+            ProtoTuple::Icmpv6Id(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            ProtoTuple::Icmpv6Type(attr) => buffer[0] = *attr,
+            ProtoTuple::Icmpv6Code(attr) => buffer[0] = *attr,
             ProtoTuple::Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -136,6 +166,30 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
                 parse_u16_be(payload)
                     .context("invalid CTA_PROTO_DST_PORT value")?,
             ),
+            CTA_PROTO_ICMP_ID => ProtoTuple::IcmpId(
+                parse_u16_be(payload)
+                    .context("invalid CTA_PROTO_ICMP_ID value")?,
+            ),
+            CTA_PROTO_ICMP_TYPE => ProtoTuple::IcmpType(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTO_ICMP_TYPE value")?,
+            ),
+            CTA_PROTO_ICMP_CODE => ProtoTuple::IcmpCode(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTO_ICMP_CODE value")?,
+            ),
+            CTA_PROTO_ICMPV6_ID => ProtoTuple::Icmpv6Id(
+                parse_u16_be(payload)
+                    .context("invalid CTA_PROTO_ICMPV6_ID value")?,
+            ),
+            CTA_PROTO_ICMPV6_TYPE => ProtoTuple::Icmpv6Type(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTO_ICMPV6_TYPE value")?,
+            ),
+            CTA_PROTO_ICMPV6_CODE => ProtoTuple::Icmpv6Code(
+                parse_u8(payload)
+                    .context("invalid CTA_PROTO_ICMPV6_CODE value")?,
+            ),
             _ => ProtoTuple::Other(DefaultNla::parse(buf)?),
         };
         Ok(nla)
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+// SCTP conntrack states from uapi/linux/netfilter/nf_conntrack_sctp.h
+const SCTP_CONNTRACK_NONE: u8 = 0;
+const SCTP_CONNTRACK_CLOSED: u8 = 1;
+const SCTP_CONNTRACK_COOKIE_WAIT: u8 = 2;
+const SCTP_CONNTRACK_COOKIE_ECHOED: u8 = 3;
+const SCTP_CONNTRACK_ESTABLISHED: u8 = 4;
+const SCTP_CONNTRACK_SHUTDOWN_SENT: u8 = 5;
+const SCTP_CONNTRACK_SHUTDOWN_RECD: u8 = 6;
+const SCTP_CONNTRACK_SHUTDOWN_ACK_SENT: u8 = 7;
+const SCTP_CONNTRACK_HEARTBEAT_SENT: u8 = 8;
+const SCTP_CONNTRACK_HEARTBEAT_ACKED: u8 = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SctpState {
+    None,
+    Closed,
+    CookieWait,
+    CookieEchoed,
+    Established,
+    ShutdownSent,
+    ShutdownRecd,
+    ShutdownAckSent,
+    HeartbeatSent,
+    HeartbeatAcked,
+    Other(u8),
+}
+
+impl From<SctpState> for u8 {
+    fn from(state: SctpState) -> Self {
+        match state {
+            SctpState::None => SCTP_CONNTRACK_NONE,
+            SctpState::Closed => SCTP_CONNTRACK_CLOSED,
+            SctpState::CookieWait => SCTP_CONNTRACK_COOKIE_WAIT,
+            SctpState::CookieEchoed => SCTP_CONNTRACK_COOKIE_ECHOED,
+            SctpState::Established => SCTP_CONNTRACK_ESTABLISHED,
+            SctpState::ShutdownSent => SCTP_CONNTRACK_SHUTDOWN_SENT,
+            SctpState::ShutdownRecd => SCTP_CONNTRACK_SHUTDOWN_RECD,
+            SctpState::ShutdownAckSent => SCTP_CONNTRACK_SHUTDOWN_ACK_SENT,
+            SctpState::HeartbeatSent => SCTP_CONNTRACK_HEARTBEAT_SENT,
+            SctpState::HeartbeatAcked => SCTP_CONNTRACK_HEARTBEAT_ACKED,
+            SctpState::Other(s) => s,
+        }
+    }
+}
+
+impl From<u8> for SctpState {
+    fn from(state: u8) -> Self {
+        match state {
+            SCTP_CONNTRACK_NONE => SctpState::None,
+            SCTP_CONNTRACK_CLOSED => SctpState::Closed,
+            SCTP_CONNTRACK_COOKIE_WAIT => SctpState::CookieWait,
+            SCTP_CONNTRACK_COOKIE_ECHOED => SctpState::CookieEchoed,
+            SCTP_CONNTRACK_ESTABLISHED => SctpState::Established,
+            SCTP_CONNTRACK_SHUTDOWN_SENT => SctpState::ShutdownSent,
+            SCTP_CONNTRACK_SHUTDOWN_RECD => SctpState::ShutdownRecd,
+            SCTP_CONNTRACK_SHUTDOWN_ACK_SENT => SctpState::ShutdownAckSent,
+            SCTP_CONNTRACK_HEARTBEAT_SENT => SctpState::HeartbeatSent,
+            SCTP_CONNTRACK_HEARTBEAT_ACKED => SctpState::HeartbeatAcked,
+            _ => SctpState::Other(state),
+        }
+    }
+}
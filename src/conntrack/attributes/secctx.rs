@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_string, DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer,
+    Parseable,
+};
+
+const CTA_SECCTX_NAME: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecCtx {
+    // Security context, e.g. "system_u:object_r:unlabeled_t:s0" on SELinux
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for SecCtx {
+    fn value_len(&self) -> usize {
+        match self {
+            SecCtx::Name(attr) => attr.len() + 1,
+            SecCtx::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SecCtx::Name(_) => CTA_SECCTX_NAME,
+            SecCtx::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SecCtx::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SecCtx::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SecCtx
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_SECCTX_NAME => SecCtx::Name(
+                parse_string(payload)
+                    .context("invalid CTA_SECCTX_NAME value")?,
+            ),
+            _ => SecCtx::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_i32_be, emit_u32_be, parse_i32_be, parse_u32_be, DecodeError,
+    DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+const CTA_SEQADJ_CORRECTION_POS: u16 = 1;
+const CTA_SEQADJ_OFFSET_BEFORE: u16 = 2;
+const CTA_SEQADJ_OFFSET_AFTER: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeqAdj {
+    CorrectionPos(u32),
+    OffsetBefore(i32),
+    OffsetAfter(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for SeqAdj {
+    fn value_len(&self) -> usize {
+        match self {
+            SeqAdj::CorrectionPos(attr) => size_of_val(attr),
+            SeqAdj::OffsetBefore(attr) => size_of_val(attr),
+            SeqAdj::OffsetAfter(attr) => size_of_val(attr),
+            SeqAdj::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SeqAdj::CorrectionPos(_) => CTA_SEQADJ_CORRECTION_POS,
+            SeqAdj::OffsetBefore(_) => CTA_SEQADJ_OFFSET_BEFORE,
+            SeqAdj::OffsetAfter(_) => CTA_SEQADJ_OFFSET_AFTER,
+            SeqAdj::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SeqAdj::CorrectionPos(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SeqAdj::OffsetBefore(attr) => emit_i32_be(buffer, *attr).unwrap(),
+            SeqAdj::OffsetAfter(attr) => emit_i32_be(buffer, *attr).unwrap(),
+            SeqAdj::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SeqAdj
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_SEQADJ_CORRECTION_POS => SeqAdj::CorrectionPos(
+                parse_u32_be(payload)
+                    .context("invalid CTA_SEQADJ_CORRECTION_POS value")?,
+            ),
+            CTA_SEQADJ_OFFSET_BEFORE => SeqAdj::OffsetBefore(
+                parse_i32_be(payload)
+                    .context("invalid CTA_SEQADJ_OFFSET_BEFORE value")?,
+            ),
+            CTA_SEQADJ_OFFSET_AFTER => SeqAdj::OffsetAfter(
+                parse_i32_be(payload)
+                    .context("invalid CTA_SEQADJ_OFFSET_AFTER value")?,
+            ),
+            _ => SeqAdj::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+// Per-CPU statistics, sent by the kernel in reply to
+// IPCTNL_MSG_CT_GET_STATS_CPU with the CPU number in the res_id field of the
+// netfilter header. Some counters are no longer updated by recent kernels.
+const CTA_STATS_SEARCHED: u16 = 1;
+const CTA_STATS_FOUND: u16 = 2;
+const CTA_STATS_NEW: u16 = 3;
+const CTA_STATS_INVALID: u16 = 4;
+const CTA_STATS_IGNORE: u16 = 5;
+const CTA_STATS_DELETE: u16 = 6;
+const CTA_STATS_DELETE_LIST: u16 = 7;
+const CTA_STATS_INSERT: u16 = 8;
+const CTA_STATS_INSERT_FAILED: u16 = 9;
+const CTA_STATS_DROP: u16 = 10;
+const CTA_STATS_EARLY_DROP: u16 = 11;
+const CTA_STATS_ERROR: u16 = 12;
+const CTA_STATS_SEARCH_RESTART: u16 = 13;
+const CTA_STATS_CLASH_RESOLVE: u16 = 14;
+const CTA_STATS_CHAIN_TOOLONG: u16 = 15;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatsCpu {
+    Searched(u32),
+    Found(u32),
+    New(u32),
+    Invalid(u32),
+    Ignore(u32),
+    Delete(u32),
+    DeleteList(u32),
+    Insert(u32),
+    InsertFailed(u32),
+    Drop(u32),
+    EarlyDrop(u32),
+    Error(u32),
+    SearchRestart(u32),
+    ClashResolve(u32),
+    ChainTooLong(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsCpu {
+    fn value_len(&self) -> usize {
+        match self {
+            StatsCpu::Other(attr) => attr.value_len(),
+            _ => size_of::<u32>(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            StatsCpu::Searched(_) => CTA_STATS_SEARCHED,
+            StatsCpu::Found(_) => CTA_STATS_FOUND,
+            StatsCpu::New(_) => CTA_STATS_NEW,
+            StatsCpu::Invalid(_) => CTA_STATS_INVALID,
+            StatsCpu::Ignore(_) => CTA_STATS_IGNORE,
+            StatsCpu::Delete(_) => CTA_STATS_DELETE,
+            StatsCpu::DeleteList(_) => CTA_STATS_DELETE_LIST,
+            StatsCpu::Insert(_) => CTA_STATS_INSERT,
+            StatsCpu::InsertFailed(_) => CTA_STATS_INSERT_FAILED,
+            StatsCpu::Drop(_) => CTA_STATS_DROP,
+            StatsCpu::EarlyDrop(_) => CTA_STATS_EARLY_DROP,
+            StatsCpu::Error(_) => CTA_STATS_ERROR,
+            StatsCpu::SearchRestart(_) => CTA_STATS_SEARCH_RESTART,
+            StatsCpu::ClashResolve(_) => CTA_STATS_CLASH_RESOLVE,
+            StatsCpu::ChainTooLong(_) => CTA_STATS_CHAIN_TOOLONG,
+            StatsCpu::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            StatsCpu::Searched(attr)
+            | StatsCpu::Found(attr)
+            | StatsCpu::New(attr)
+            | StatsCpu::Invalid(attr)
+            | StatsCpu::Ignore(attr)
+            | StatsCpu::Delete(attr)
+            | StatsCpu::DeleteList(attr)
+            | StatsCpu::Insert(attr)
+            | StatsCpu::InsertFailed(attr)
+            | StatsCpu::Drop(attr)
+            | StatsCpu::EarlyDrop(attr)
+            | StatsCpu::Error(attr)
+            | StatsCpu::SearchRestart(attr)
+            | StatsCpu::ClashResolve(attr)
+            | StatsCpu::ChainTooLong(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            StatsCpu::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for StatsCpu
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_STATS_SEARCHED => StatsCpu::Searched(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_SEARCHED value")?,
+            ),
+            CTA_STATS_FOUND => StatsCpu::Found(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_FOUND value")?,
+            ),
+            CTA_STATS_NEW => StatsCpu::New(
+                parse_u32_be(payload).context("invalid CTA_STATS_NEW value")?,
+            ),
+            CTA_STATS_INVALID => StatsCpu::Invalid(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_INVALID value")?,
+            ),
+            CTA_STATS_IGNORE => StatsCpu::Ignore(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_IGNORE value")?,
+            ),
+            CTA_STATS_DELETE => StatsCpu::Delete(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_DELETE value")?,
+            ),
+            CTA_STATS_DELETE_LIST => StatsCpu::DeleteList(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_DELETE_LIST value")?,
+            ),
+            CTA_STATS_INSERT => StatsCpu::Insert(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_INSERT value")?,
+            ),
+            CTA_STATS_INSERT_FAILED => StatsCpu::InsertFailed(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_INSERT_FAILED value")?,
+            ),
+            CTA_STATS_DROP => StatsCpu::Drop(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_DROP value")?,
+            ),
+            CTA_STATS_EARLY_DROP => StatsCpu::EarlyDrop(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_EARLY_DROP value")?,
+            ),
+            CTA_STATS_ERROR => StatsCpu::Error(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_ERROR value")?,
+            ),
+            CTA_STATS_SEARCH_RESTART => StatsCpu::SearchRestart(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_SEARCH_RESTART value")?,
+            ),
+            CTA_STATS_CLASH_RESOLVE => StatsCpu::ClashResolve(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_CLASH_RESOLVE value")?,
+            ),
+            CTA_STATS_CHAIN_TOOLONG => StatsCpu::ChainTooLong(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_CHAIN_TOOLONG value")?,
+            ),
+            _ => StatsCpu::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const CTA_STATS_GLOBAL_ENTRIES: u16 = 1;
+const CTA_STATS_GLOBAL_MAX_ENTRIES: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatsGlobal {
+    Entries(u32),
+    MaxEntries(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsGlobal {
+    fn value_len(&self) -> usize {
+        match self {
+            StatsGlobal::Other(attr) => attr.value_len(),
+            _ => size_of::<u32>(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            StatsGlobal::Entries(_) => CTA_STATS_GLOBAL_ENTRIES,
+            StatsGlobal::MaxEntries(_) => CTA_STATS_GLOBAL_MAX_ENTRIES,
+            StatsGlobal::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            StatsGlobal::Entries(attr) | StatsGlobal::MaxEntries(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            StatsGlobal::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for StatsGlobal
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_STATS_GLOBAL_ENTRIES => StatsGlobal::Entries(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_GLOBAL_ENTRIES value")?,
+            ),
+            CTA_STATS_GLOBAL_MAX_ENTRIES => StatsGlobal::MaxEntries(
+                parse_u32_be(payload)
+                    .context("invalid CTA_STATS_GLOBAL_MAX_ENTRIES value")?,
+            ),
+            _ => StatsGlobal::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_SYNPROXY_ISN: u16 = 1;
+const CTA_SYNPROXY_ITS: u16 = 2;
+const CTA_SYNPROXY_TSOFF: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Synproxy {
+    // Initial sequence number of the SYN cookie sent to the client
+    Isn(u32),
+    // Timestamp of the SYN cookie sent to the client
+    Its(u32),
+    // Timestamp offset between the client and the server connections
+    Tsoff(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Synproxy {
+    fn value_len(&self) -> usize {
+        match self {
+            Synproxy::Isn(attr) => size_of_val(attr),
+            Synproxy::Its(attr) => size_of_val(attr),
+            Synproxy::Tsoff(attr) => size_of_val(attr),
+            Synproxy::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Synproxy::Isn(_) => CTA_SYNPROXY_ISN,
+            Synproxy::Its(_) => CTA_SYNPROXY_ITS,
+            Synproxy::Tsoff(_) => CTA_SYNPROXY_TSOFF,
+            Synproxy::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Synproxy::Isn(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            Synproxy::Its(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            Synproxy::Tsoff(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            Synproxy::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Synproxy
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_SYNPROXY_ISN => Synproxy::Isn(
+                parse_u32_be(payload)
+                    .context("invalid CTA_SYNPROXY_ISN value")?,
+            ),
+            CTA_SYNPROXY_ITS => Synproxy::Its(
+                parse_u32_be(payload)
+                    .context("invalid CTA_SYNPROXY_ITS value")?,
+            ),
+            CTA_SYNPROXY_TSOFF => Synproxy::Tsoff(
+                parse_u32_be(payload)
+                    .context("invalid CTA_SYNPROXY_TSOFF value")?,
+            ),
+            _ => Synproxy::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
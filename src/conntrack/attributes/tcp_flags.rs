@@ -1,16 +1,43 @@
 // SPDX-License-Identifier: MIT
 
+use bitflags::bitflags;
 use netlink_packet_core::{
     buffer, fields, getter, setter, DecodeError, Emitable, Parseable,
 };
 
+// TCP window flags from uapi/linux/netfilter/nf_conntrack_tcp.h
+const IP_CT_TCP_FLAG_WINDOW_SCALE: u8 = 0x01;
+const IP_CT_TCP_FLAG_SACK_PERM: u8 = 0x02;
+const IP_CT_TCP_FLAG_CLOSE_INIT: u8 = 0x04;
+const IP_CT_TCP_FLAG_BE_LIBERAL: u8 = 0x08;
+const IP_CT_TCP_FLAG_DATA_UNACKNOWLEDGED: u8 = 0x10;
+const IP_CT_TCP_FLAG_MAXACK_SET: u8 = 0x20;
+const IP_CT_EXP_CHALLENGE_ACK: u8 = 0x40;
+const IP_CT_TCP_SIMULTANEOUS_OPEN: u8 = 0x80;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct TCPWindowFlags: u8 {
+        const WindowScale           = IP_CT_TCP_FLAG_WINDOW_SCALE;
+        const SackPerm              = IP_CT_TCP_FLAG_SACK_PERM;
+        const CloseInit             = IP_CT_TCP_FLAG_CLOSE_INIT;
+        const BeLiberal             = IP_CT_TCP_FLAG_BE_LIBERAL;
+        const DataUnacknowledged    = IP_CT_TCP_FLAG_DATA_UNACKNOWLEDGED;
+        const MaxackSet             = IP_CT_TCP_FLAG_MAXACK_SET;
+        const ChallengeAck          = IP_CT_EXP_CHALLENGE_ACK;
+        const SimultaneousOpen      = IP_CT_TCP_SIMULTANEOUS_OPEN;
+        const _ = !0;
+    }
+}
+
 const TCP_FLAGS_LEN: usize = 2;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct TCPFlags {
-    pub flags: u8,
-    pub mask: u8,
+    pub flags: TCPWindowFlags,
+    pub mask: TCPWindowFlags,
 }
 
 buffer!(TCPFlagsBuffer(TCP_FLAGS_LEN) {
@@ -21,8 +48,8 @@ buffer!(TCPFlagsBuffer(TCP_FLAGS_LEN) {
 impl<T: AsRef<[u8]>> Parseable<TCPFlagsBuffer<T>> for TCPFlags {
     fn parse(buf: &TCPFlagsBuffer<T>) -> Result<Self, DecodeError> {
         Ok(TCPFlags {
-            flags: buf.flags(),
-            mask: buf.mask(),
+            flags: TCPWindowFlags::from_bits_retain(buf.flags()),
+            mask: TCPWindowFlags::from_bits_retain(buf.mask()),
         })
     }
 }
@@ -34,7 +61,7 @@ impl Emitable for TCPFlags {
 
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = TCPFlagsBuffer::new(buffer);
-        buffer.set_flags(self.flags);
-        buffer.set_mask(self.mask);
+        buffer.set_flags(self.flags.bits());
+        buffer.set_mask(self.mask.bits());
     }
 }
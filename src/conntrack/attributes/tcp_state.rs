@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+// TCP conntrack states from uapi/linux/netfilter/nf_conntrack_tcp.h
+const TCP_CONNTRACK_NONE: u8 = 0;
+const TCP_CONNTRACK_SYN_SENT: u8 = 1;
+const TCP_CONNTRACK_SYN_RECV: u8 = 2;
+const TCP_CONNTRACK_ESTABLISHED: u8 = 3;
+const TCP_CONNTRACK_FIN_WAIT: u8 = 4;
+const TCP_CONNTRACK_CLOSE_WAIT: u8 = 5;
+const TCP_CONNTRACK_LAST_ACK: u8 = 6;
+const TCP_CONNTRACK_TIME_WAIT: u8 = 7;
+const TCP_CONNTRACK_CLOSE: u8 = 8;
+// TCP_CONNTRACK_LISTEN is obsolete, the kernel reuses its value for
+// TCP_CONNTRACK_SYN_SENT2
+const TCP_CONNTRACK_LISTEN: u8 = 9;
+const TCP_CONNTRACK_SYN_SENT2: u8 = TCP_CONNTRACK_LISTEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TcpState {
+    None,
+    SynSent,
+    SynRecv,
+    Established,
+    FinWait,
+    CloseWait,
+    LastAck,
+    TimeWait,
+    Close,
+    SynSent2,
+    Other(u8),
+}
+
+impl TcpState {
+    // Legacy name of the value now used for SYN_SENT2
+    pub const LISTEN: TcpState = TcpState::SynSent2;
+
+    pub fn is_established(&self) -> bool {
+        matches!(self, TcpState::Established)
+    }
+
+    pub fn is_closing(&self) -> bool {
+        matches!(
+            self,
+            TcpState::FinWait
+                | TcpState::CloseWait
+                | TcpState::LastAck
+                | TcpState::TimeWait
+                | TcpState::Close
+        )
+    }
+}
+
+impl From<TcpState> for u8 {
+    fn from(state: TcpState) -> Self {
+        match state {
+            TcpState::None => TCP_CONNTRACK_NONE,
+            TcpState::SynSent => TCP_CONNTRACK_SYN_SENT,
+            TcpState::SynRecv => TCP_CONNTRACK_SYN_RECV,
+            TcpState::Established => TCP_CONNTRACK_ESTABLISHED,
+            TcpState::FinWait => TCP_CONNTRACK_FIN_WAIT,
+            TcpState::CloseWait => TCP_CONNTRACK_CLOSE_WAIT,
+            TcpState::LastAck => TCP_CONNTRACK_LAST_ACK,
+            TcpState::TimeWait => TCP_CONNTRACK_TIME_WAIT,
+            TcpState::Close => TCP_CONNTRACK_CLOSE,
+            TcpState::SynSent2 => TCP_CONNTRACK_SYN_SENT2,
+            TcpState::Other(s) => s,
+        }
+    }
+}
+
+impl From<u8> for TcpState {
+    fn from(state: u8) -> Self {
+        match state {
+            TCP_CONNTRACK_NONE => TcpState::None,
+            TCP_CONNTRACK_SYN_SENT => TcpState::SynSent,
+            TCP_CONNTRACK_SYN_RECV => TcpState::SynRecv,
+            TCP_CONNTRACK_ESTABLISHED => TcpState::Established,
+            TCP_CONNTRACK_FIN_WAIT => TcpState::FinWait,
+            TCP_CONNTRACK_CLOSE_WAIT => TcpState::CloseWait,
+            TCP_CONNTRACK_LAST_ACK => TcpState::LastAck,
+            TCP_CONNTRACK_TIME_WAIT => TcpState::TimeWait,
+            TCP_CONNTRACK_CLOSE => TcpState::Close,
+            TCP_CONNTRACK_SYN_SENT2 => TcpState::SynSent2,
+            _ => TcpState::Other(state),
+        }
+    }
+}
+
+// Same names as printed by `conntrack -L`
+impl fmt::Display for TcpState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TcpState::None => write!(f, "NONE"),
+            TcpState::SynSent => write!(f, "SYN_SENT"),
+            TcpState::SynRecv => write!(f, "SYN_RECV"),
+            TcpState::Established => write!(f, "ESTABLISHED"),
+            TcpState::FinWait => write!(f, "FIN_WAIT"),
+            TcpState::CloseWait => write!(f, "CLOSE_WAIT"),
+            TcpState::LastAck => write!(f, "LAST_ACK"),
+            TcpState::TimeWait => write!(f, "TIME_WAIT"),
+            TcpState::Close => write!(f, "CLOSE"),
+            TcpState::SynSent2 => write!(f, "SYN_SENT2"),
+            TcpState::Other(s) => write!(f, "UNKNOWN({s})"),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use netlink_packet_core::{
+    emit_u64_be, parse_u64_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMESTAMP_START: u16 = 1;
+const CTA_TIMESTAMP_STOP: u16 = 2;
+
+// Both values are nanoseconds since the UNIX epoch, as reported by the kernel
+// when net.netfilter.nf_conntrack_timestamp is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Timestamp {
+    Start(u64),
+    Stop(u64),
+    Other(DefaultNla),
+}
+
+impl Timestamp {
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Timestamp::Start(ns) | Timestamp::Stop(ns) => {
+                Some(Duration::from_nanos(*ns))
+            }
+            Timestamp::Other(_) => None,
+        }
+    }
+
+    pub fn as_system_time(&self) -> Option<SystemTime> {
+        self.as_duration()
+            .map(|since_epoch| UNIX_EPOCH + since_epoch)
+    }
+
+    // Returns the lifetime of a flow from the timestamps of a
+    // `CtaTimestamp` attribute. Entries that are still alive have no stop
+    // timestamp, in which case `None` is returned.
+    pub fn flow_duration(timestamps: &[Timestamp]) -> Option<Duration> {
+        let mut start = None;
+        let mut stop = None;
+        for timestamp in timestamps {
+            match timestamp {
+                Timestamp::Start(ns) => start = Some(*ns),
+                Timestamp::Stop(ns) => stop = Some(*ns),
+                Timestamp::Other(_) => {}
+            }
+        }
+        stop?.checked_sub(start?).map(Duration::from_nanos)
+    }
+}
+
+impl Nla for Timestamp {
+    fn value_len(&self) -> usize {
+        match self {
+            Timestamp::Start(attr) => size_of_val(attr),
+            Timestamp::Stop(attr) => size_of_val(attr),
+            Timestamp::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Timestamp::Start(_) => CTA_TIMESTAMP_START,
+            Timestamp::Stop(_) => CTA_TIMESTAMP_STOP,
+            Timestamp::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Timestamp::Start(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Timestamp::Stop(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Timestamp::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Timestamp
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMESTAMP_START => Timestamp::Start(
+                parse_u64_be(payload)
+                    .context("invalid CTA_TIMESTAMP_START value")?,
+            ),
+            CTA_TIMESTAMP_STOP => Timestamp::Stop(
+                parse_u64_be(payload)
+                    .context("invalid CTA_TIMESTAMP_STOP value")?,
+            ),
+            _ => Timestamp::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
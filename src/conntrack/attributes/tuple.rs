@@ -1,20 +1,22 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_core::{
-    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
-    NlasIterator, Parseable,
+    emit_u16_be, parse_u16_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
 };
 
 use crate::conntrack::attributes::{iptuple::IPTuple, prototuple::ProtoTuple};
 
 const CTA_TUPLE_IP: u16 = 1;
 const CTA_TUPLE_PROTO: u16 = 2;
+const CTA_TUPLE_ZONE: u16 = 3;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Tuple {
     Ip(Vec<IPTuple>),
     Proto(Vec<ProtoTuple>),
+    Zone(u16),
     Other(DefaultNla),
 }
 
@@ -23,6 +25,7 @@ impl Nla for Tuple {
         match self {
             Tuple::Ip(nlas) => nlas.iter().map(|op| op.buffer_len()).sum(),
             Tuple::Proto(nlas) => nlas.iter().map(|op| op.buffer_len()).sum(),
+            Tuple::Zone(attr) => size_of_val(attr),
             Tuple::Other(attr) => attr.value_len(),
         }
     }
@@ -31,6 +34,7 @@ impl Nla for Tuple {
         match self {
             Tuple::Ip(_) => CTA_TUPLE_IP,
             Tuple::Proto(_) => CTA_TUPLE_PROTO,
+            Tuple::Zone(_) => CTA_TUPLE_ZONE,
             Tuple::Other(attr) => attr.kind(),
         }
     }
@@ -50,6 +54,7 @@ impl Nla for Tuple {
                     len += op.buffer_len();
                 }
             }
+            Tuple::Zone(attr) => emit_u16_be(buffer, *attr).unwrap(),
             Tuple::Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -81,6 +86,10 @@ impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
                 }
                 Tuple::Proto(proto_tuples)
             }
+            CTA_TUPLE_ZONE => Tuple::Zone(
+                parse_u16_be(payload)
+                    .context("invalid CTA_TUPLE_ZONE value")?,
+            ),
             _ => Tuple::Other(DefaultNla::parse(buf)?),
         };
         Ok(nla)
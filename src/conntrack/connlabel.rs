@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::conntrack::ConntrackLabels;
+
+pub const CONNLABEL_CONF: &str = "/etc/xtables/connlabel.conf";
+
+// Bit number to name mapping of connlabel.conf, which is shared by iptables,
+// nftables and conntrack-tools. Each line holds a bit number followed by its
+// name, lines starting with '#' are comments:
+//
+//   0 eth0-in
+//   1 eth0-out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnlabelMap {
+    names: BTreeMap<u32, String>,
+}
+
+impl ConnlabelMap {
+    pub fn parse(content: &str) -> io::Result<Self> {
+        let mut names = BTreeMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {reason}: {line}", index + 1),
+                )
+            };
+            let mut fields = line.split_whitespace();
+            let bit = fields
+                .next()
+                .and_then(parse_bit)
+                .ok_or_else(|| invalid("invalid bit number"))?;
+            if bit >= ConntrackLabels::MAX_BITS {
+                return Err(invalid("bit number out of range"));
+            }
+            let name = fields.next().ok_or_else(|| invalid("missing name"))?;
+            // Like libnetfilter_conntrack, the first name given to a bit wins
+            names.entry(bit).or_insert_with(|| name.to_string());
+        }
+        Ok(Self { names })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn load_default() -> io::Result<Self> {
+        Self::load(CONNLABEL_CONF)
+    }
+
+    pub fn name(&self, bit: u32) -> Option<&str> {
+        self.names.get(&bit).map(String::as_str)
+    }
+
+    pub fn bit(&self, name: &str) -> Option<u32> {
+        self.names
+            .iter()
+            .find(|(_, n)| n.as_str() == name)
+            .map(|(bit, _)| *bit)
+    }
+
+    // Names of the labels set in `labels`, bits without a name are skipped
+    pub fn names<'a>(
+        &'a self,
+        labels: &'a ConntrackLabels,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        labels.iter().filter_map(move |bit| self.name(bit))
+    }
+
+    // Returns `None` if one of the names is not in the map
+    pub fn labels<'a, I>(&self, names: I) -> Option<ConntrackLabels>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        names.into_iter().map(|name| self.bit(name)).collect()
+    }
+}
+
+fn parse_bit(field: &str) -> Option<u32> {
+    match field
+        .strip_prefix("0x")
+        .or_else(|| field.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => field.parse().ok(),
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    NetlinkMessage, NetlinkPayload, NetlinkSerializable, NLM_F_CREATE,
+    NLM_F_EXCL,
+};
+
+use crate::{
+    conntrack::ConntrackMessageType,
+    constants::{
+        NFNLGRP_CONNTRACK_DESTROY, NFNLGRP_CONNTRACK_EXP_DESTROY,
+        NFNLGRP_CONNTRACK_EXP_NEW, NFNLGRP_CONNTRACK_EXP_UPDATE,
+        NFNLGRP_CONNTRACK_NEW, NFNLGRP_CONNTRACK_UPDATE,
+    },
+    expect::ExpectMessageType,
+    message::Subsystem,
+    NetfilterMessage,
+};
+
+// Kind of a conntrack or expectation event received on one of the
+// NFNLGRP_CONNTRACK_* multicast groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConntrackEvent {
+    New,
+    Update,
+    Destroy,
+    ExpNew,
+    ExpUpdate,
+    ExpDestroy,
+}
+
+impl ConntrackEvent {
+    // Multicast group to join in order to receive this kind of event
+    pub fn group(&self) -> u32 {
+        match self {
+            ConntrackEvent::New => NFNLGRP_CONNTRACK_NEW,
+            ConntrackEvent::Update => NFNLGRP_CONNTRACK_UPDATE,
+            ConntrackEvent::Destroy => NFNLGRP_CONNTRACK_DESTROY,
+            ConntrackEvent::ExpNew => NFNLGRP_CONNTRACK_EXP_NEW,
+            ConntrackEvent::ExpUpdate => NFNLGRP_CONNTRACK_EXP_UPDATE,
+            ConntrackEvent::ExpDestroy => NFNLGRP_CONNTRACK_EXP_DESTROY,
+        }
+    }
+
+    // The kernel reports both new and updated entries with a NEW message,
+    // new ones are told apart by the NLM_F_CREATE | NLM_F_EXCL flags. Returns
+    // `None` for messages that are not conntrack or expectation events.
+    pub fn from_message(
+        message: &NetlinkMessage<NetfilterMessage>,
+    ) -> Option<Self> {
+        let NetlinkPayload::InnerMessage(ref inner) = message.payload else {
+            return None;
+        };
+        let message_type = NetlinkSerializable::message_type(inner);
+        let subsys = Subsystem::from((message_type >> 8) as u8);
+        let message_type = message_type as u8;
+        let created = message.header.flags & (NLM_F_CREATE | NLM_F_EXCL)
+            == NLM_F_CREATE | NLM_F_EXCL;
+        match subsys {
+            Subsystem::Conntrack => {
+                match ConntrackMessageType::from(message_type) {
+                    ConntrackMessageType::New if created => {
+                        Some(ConntrackEvent::New)
+                    }
+                    ConntrackMessageType::New => Some(ConntrackEvent::Update),
+                    ConntrackMessageType::Delete => {
+                        Some(ConntrackEvent::Destroy)
+                    }
+                    _ => None,
+                }
+            }
+            Subsystem::ConntrackExpect => {
+                match ExpectMessageType::from(message_type) {
+                    ExpectMessageType::New if created => {
+                        Some(ConntrackEvent::ExpNew)
+                    }
+                    ExpectMessageType::New => Some(ConntrackEvent::ExpUpdate),
+                    ExpectMessageType::Delete => {
+                        Some(ConntrackEvent::ExpDestroy)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::conntrack::{
+    ConntrackAttribute, Filter, FilterFlags, IPTuple, ProtoTuple, Protocol,
+    Status, Tuple,
+};
+
+// Builds the attributes of a filtered conntrack dump (`ConntrackMessage::Get`
+// with NLM_F_DUMP) or of a flush-by-filter (`ConntrackMessage::Delete`
+// without a tuple). The CTA_FILTER flags are derived from the tuple fields
+// which have been set, so the kernel never sees a flag without the matching
+// tuple attribute, nor a port without the protocol it belongs to.
+//
+// Filtering on tuple fields requires the family of the netfilter header to be
+// set to the address family of the tuples, the kernel rejects
+// `ProtoFamily::Unspec` in that case.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConntrackFilter {
+    mark: Option<(u32, u32)>,
+    status: Option<(Status, Status)>,
+    orig: Option<(FilterFlags, Vec<Tuple>)>,
+    reply: Option<(FilterFlags, Vec<Tuple>)>,
+}
+
+impl ConntrackFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Matches the entries for which `entry_mark & mask == mark`
+    pub fn mark(mut self, mark: u32, mask: u32) -> Self {
+        self.mark = Some((mark, mask));
+        self
+    }
+
+    // Matches the entries for which `entry_status & mask == status`
+    pub fn status(mut self, status: Status, mask: Status) -> Self {
+        self.status = Some((status, mask));
+        self
+    }
+
+    pub fn orig<A: Into<IpAddr>>(mut self, tuple: TupleFilter<A>) -> Self {
+        self.orig = Some((tuple.flags(), tuple.build()));
+        self
+    }
+
+    pub fn reply<A: Into<IpAddr>>(mut self, tuple: TupleFilter<A>) -> Self {
+        self.reply = Some((tuple.flags(), tuple.build()));
+        self
+    }
+
+    pub fn build(self) -> Vec<ConntrackAttribute> {
+        let mut attributes = Vec::new();
+        let mut filter = Vec::new();
+        if let Some((flags, tuple)) = self.orig {
+            filter.push(Filter::OrigFlags(flags));
+            attributes.push(ConntrackAttribute::CtaTupleOrig(tuple));
+        }
+        if let Some((flags, tuple)) = self.reply {
+            filter.push(Filter::ReplyFlags(flags));
+            attributes.push(ConntrackAttribute::CtaTupleReply(tuple));
+        }
+        if let Some((status, mask)) = self.status {
+            attributes.push(ConntrackAttribute::CtaStatus(status));
+            attributes.push(ConntrackAttribute::CtaStatusMask(mask));
+        }
+        if let Some((mark, mask)) = self.mark {
+            attributes.push(ConntrackAttribute::CtaMark(mark));
+            attributes.push(ConntrackAttribute::CtaMarkMask(mask));
+        }
+        if !filter.is_empty() {
+            attributes.push(ConntrackAttribute::CtaFilter(filter));
+        }
+        attributes
+    }
+}
+
+// Tuple fields to match, the address family is chosen once by the
+// constructor so that both addresses and the ICMP variant always agree with
+// it. An ICMP filter is emitted as ICMPv6 in an IPv6 tuple.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TupleFilter<A> {
+    icmpv6: bool,
+    source: Option<A>,
+    destination: Option<A>,
+    proto: Option<ProtoFilter>,
+    zone: Option<u16>,
+}
+
+impl TupleFilter<Ipv4Addr> {
+    pub fn ipv4() -> Self {
+        Self {
+            icmpv6: false,
+            source: None,
+            destination: None,
+            proto: None,
+            zone: None,
+        }
+    }
+}
+
+impl TupleFilter<Ipv6Addr> {
+    pub fn ipv6() -> Self {
+        Self {
+            icmpv6: true,
+            source: None,
+            destination: None,
+            proto: None,
+            zone: None,
+        }
+    }
+}
+
+impl<A: Into<IpAddr>> TupleFilter<A> {
+    pub fn source(mut self, addr: A) -> Self {
+        self.source = Some(addr);
+        self
+    }
+
+    pub fn destination(mut self, addr: A) -> Self {
+        self.destination = Some(addr);
+        self
+    }
+
+    pub fn proto(mut self, proto: impl Into<ProtoFilter>) -> Self {
+        self.proto = Some(proto.into());
+        self
+    }
+
+    pub fn zone(mut self, zone: u16) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    fn flags(&self) -> FilterFlags {
+        let mut flags = FilterFlags::empty();
+        flags.set(FilterFlags::IpSrc, self.source.is_some());
+        flags.set(FilterFlags::IpDst, self.destination.is_some());
+        flags.set(FilterFlags::TupleZone, self.zone.is_some());
+        if let Some(proto) = &self.proto {
+            flags |= proto.flags(self.icmpv6);
+        }
+        flags
+    }
+
+    fn build(self) -> Vec<Tuple> {
+        let mut ip = Vec::new();
+        if let Some(addr) = self.source {
+            ip.push(IPTuple::SourceAddress(addr.into()));
+        }
+        if let Some(addr) = self.destination {
+            ip.push(IPTuple::DestinationAddress(addr.into()));
+        }
+        let mut tuple = Vec::new();
+        if !ip.is_empty() {
+            tuple.push(Tuple::Ip(ip));
+        }
+        if let Some(proto) = self.proto {
+            tuple.push(Tuple::Proto(proto.build(self.icmpv6)));
+        }
+        if let Some(zone) = self.zone {
+            tuple.push(Tuple::Zone(zone));
+        }
+        tuple
+    }
+}
+
+// The kernel stores the ports and the ICMP fields in the same union of the
+// tuple, so a protocol filter matches either ports or ICMP fields, never both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProtoFilter {
+    Ports(PortFilter),
+    Icmp(IcmpFilter),
+}
+
+impl From<PortFilter> for ProtoFilter {
+    fn from(filter: PortFilter) -> Self {
+        ProtoFilter::Ports(filter)
+    }
+}
+
+impl From<IcmpFilter> for ProtoFilter {
+    fn from(filter: IcmpFilter) -> Self {
+        ProtoFilter::Icmp(filter)
+    }
+}
+
+impl ProtoFilter {
+    fn flags(&self, icmpv6: bool) -> FilterFlags {
+        let mut flags = FilterFlags::ProtoNum;
+        match self {
+            ProtoFilter::Ports(ports) => {
+                flags.set(
+                    FilterFlags::ProtoSrcPort,
+                    ports.source_port.is_some(),
+                );
+                flags.set(
+                    FilterFlags::ProtoDstPort,
+                    ports.destination_port.is_some(),
+                );
+            }
+            ProtoFilter::Icmp(icmp) if icmpv6 => {
+                flags.set(FilterFlags::Icmpv6Type, icmp.icmp_type.is_some());
+                flags.set(FilterFlags::Icmpv6Code, icmp.icmp_code.is_some());
+                flags.set(FilterFlags::Icmpv6Id, icmp.icmp_id.is_some());
+            }
+            ProtoFilter::Icmp(icmp) => {
+                flags.set(FilterFlags::IcmpType, icmp.icmp_type.is_some());
+                flags.set(FilterFlags::IcmpCode, icmp.icmp_code.is_some());
+                flags.set(FilterFlags::IcmpId, icmp.icmp_id.is_some());
+            }
+        }
+        flags
+    }
+
+    fn build(self, icmpv6: bool) -> Vec<ProtoTuple> {
+        match self {
+            ProtoFilter::Ports(ports) => {
+                let mut proto = vec![ProtoTuple::Protocol(ports.protocol)];
+                if let Some(port) = ports.source_port {
+                    proto.push(ProtoTuple::SourcePort(port));
+                }
+                if let Some(port) = ports.destination_port {
+                    proto.push(ProtoTuple::DestinationPort(port));
+                }
+                proto
+            }
+            ProtoFilter::Icmp(icmp) if icmpv6 => {
+                let mut proto = vec![ProtoTuple::Protocol(Protocol::Ipv6Icmp)];
+                if let Some(id) = icmp.icmp_id {
+                    proto.push(ProtoTuple::Icmpv6Id(id));
+                }
+                if let Some(icmp_type) = icmp.icmp_type {
+                    proto.push(ProtoTuple::Icmpv6Type(icmp_type));
+                }
+                if let Some(code) = icmp.icmp_code {
+                    proto.push(ProtoTuple::Icmpv6Code(code));
+                }
+                proto
+            }
+            ProtoFilter::Icmp(icmp) => {
+                let mut proto = vec![ProtoTuple::Protocol(Protocol::Icmp)];
+                if let Some(id) = icmp.icmp_id {
+                    proto.push(ProtoTuple::IcmpId(id));
+                }
+                if let Some(icmp_type) = icmp.icmp_type {
+                    proto.push(ProtoTuple::IcmpType(icmp_type));
+                }
+                if let Some(code) = icmp.icmp_code {
+                    proto.push(ProtoTuple::IcmpCode(code));
+                }
+                proto
+            }
+        }
+    }
+}
+
+// Matches a port-based protocol, and optionally its ports
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortFilter {
+    protocol: Protocol,
+    source_port: Option<u16>,
+    destination_port: Option<u16>,
+}
+
+impl PortFilter {
+    fn new(protocol: Protocol) -> Self {
+        Self {
+            protocol,
+            source_port: None,
+            destination_port: None,
+        }
+    }
+
+    pub fn tcp() -> Self {
+        Self::new(Protocol::Tcp)
+    }
+
+    pub fn udp() -> Self {
+        Self::new(Protocol::Udp)
+    }
+
+    pub fn udplite() -> Self {
+        Self::new(Protocol::UdpLite)
+    }
+
+    pub fn sctp() -> Self {
+        Self::new(Protocol::Sctp)
+    }
+
+    pub fn dccp() -> Self {
+        Self::new(Protocol::Dccp)
+    }
+
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.source_port = Some(port);
+        self
+    }
+
+    pub fn destination_port(mut self, port: u16) -> Self {
+        self.destination_port = Some(port);
+        self
+    }
+}
+
+// Matches ICMP, or ICMPv6 in an IPv6 tuple, and optionally its fields
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IcmpFilter {
+    icmp_type: Option<u8>,
+    icmp_code: Option<u8>,
+    icmp_id: Option<u16>,
+}
+
+impl IcmpFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn icmp_type(mut self, icmp_type: u8) -> Self {
+        self.icmp_type = Some(icmp_type);
+        self
+    }
+
+    pub fn icmp_code(mut self, icmp_code: u8) -> Self {
+        self.icmp_code = Some(icmp_code);
+        self
+    }
+
+    pub fn icmp_id(mut self, icmp_id: u16) -> Self {
+        self.icmp_id = Some(icmp_id);
+        self
+    }
+}
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    buffer::NetfilterBuffer, conntrack::attributes::ConntrackAttribute,
+    buffer::NetfilterBuffer,
+    conntrack::attributes::{ConntrackAttribute, StatsCpu, StatsGlobal},
 };
 use netlink_packet_core::{
     DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
@@ -13,6 +14,11 @@ pub enum ConntrackMessage {
     New(Vec<ConntrackAttribute>),
     Get(Vec<ConntrackAttribute>),
     Delete(Vec<ConntrackAttribute>),
+    GetCtrZero(Vec<ConntrackAttribute>),
+    GetStatsCpu(Vec<StatsCpu>),
+    GetStats(Vec<StatsGlobal>),
+    GetDying(Vec<ConntrackAttribute>),
+    GetUnconfirmed(Vec<ConntrackAttribute>),
     Other {
         message_type: u8,
         attributes: Vec<DefaultNla>,
@@ -22,6 +28,11 @@ pub enum ConntrackMessage {
 const IPCTNL_MSG_CT_NEW: u8 = 0;
 const IPCTNL_MSG_CT_GET: u8 = 1;
 const IPCTNL_MSG_CT_DELETE: u8 = 2;
+const IPCTNL_MSG_CT_GET_CTRZERO: u8 = 3;
+const IPCTNL_MSG_CT_GET_STATS_CPU: u8 = 4;
+const IPCTNL_MSG_CT_GET_STATS: u8 = 5;
+const IPCTNL_MSG_CT_GET_DYING: u8 = 6;
+const IPCTNL_MSG_CT_GET_UNCONFIRMED: u8 = 7;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -29,6 +40,11 @@ pub enum ConntrackMessageType {
     New,
     Get,
     Delete,
+    GetCtrZero,
+    GetStatsCpu,
+    GetStats,
+    GetDying,
+    GetUnconfirmed,
     Other(u8),
 }
 
@@ -38,6 +54,11 @@ impl From<u8> for ConntrackMessageType {
             IPCTNL_MSG_CT_NEW => Self::New,
             IPCTNL_MSG_CT_GET => Self::Get,
             IPCTNL_MSG_CT_DELETE => Self::Delete,
+            IPCTNL_MSG_CT_GET_CTRZERO => Self::GetCtrZero,
+            IPCTNL_MSG_CT_GET_STATS_CPU => Self::GetStatsCpu,
+            IPCTNL_MSG_CT_GET_STATS => Self::GetStats,
+            IPCTNL_MSG_CT_GET_DYING => Self::GetDying,
+            IPCTNL_MSG_CT_GET_UNCONFIRMED => Self::GetUnconfirmed,
             v => Self::Other(v),
         }
     }
@@ -49,6 +70,13 @@ impl From<ConntrackMessageType> for u8 {
             ConntrackMessageType::New => IPCTNL_MSG_CT_NEW,
             ConntrackMessageType::Get => IPCTNL_MSG_CT_GET,
             ConntrackMessageType::Delete => IPCTNL_MSG_CT_DELETE,
+            ConntrackMessageType::GetCtrZero => IPCTNL_MSG_CT_GET_CTRZERO,
+            ConntrackMessageType::GetStatsCpu => IPCTNL_MSG_CT_GET_STATS_CPU,
+            ConntrackMessageType::GetStats => IPCTNL_MSG_CT_GET_STATS,
+            ConntrackMessageType::GetDying => IPCTNL_MSG_CT_GET_DYING,
+            ConntrackMessageType::GetUnconfirmed => {
+                IPCTNL_MSG_CT_GET_UNCONFIRMED
+            }
             ConntrackMessageType::Other(v) => v,
         }
     }
@@ -60,6 +88,15 @@ impl ConntrackMessage {
             ConntrackMessage::New(_) => ConntrackMessageType::New,
             ConntrackMessage::Get(_) => ConntrackMessageType::Get,
             ConntrackMessage::Delete(_) => ConntrackMessageType::Delete,
+            ConntrackMessage::GetCtrZero(_) => ConntrackMessageType::GetCtrZero,
+            ConntrackMessage::GetStatsCpu(_) => {
+                ConntrackMessageType::GetStatsCpu
+            }
+            ConntrackMessage::GetStats(_) => ConntrackMessageType::GetStats,
+            ConntrackMessage::GetDying(_) => ConntrackMessageType::GetDying,
+            ConntrackMessage::GetUnconfirmed(_) => {
+                ConntrackMessageType::GetUnconfirmed
+            }
             ConntrackMessage::Other { message_type, .. } => {
                 (*message_type).into()
             }
@@ -79,6 +116,21 @@ impl Emitable for ConntrackMessage {
             ConntrackMessage::Delete(attributes) => {
                 attributes.as_slice().buffer_len()
             }
+            ConntrackMessage::GetCtrZero(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ConntrackMessage::GetStatsCpu(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ConntrackMessage::GetStats(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ConntrackMessage::GetDying(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ConntrackMessage::GetUnconfirmed(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
             ConntrackMessage::Other { attributes, .. } => {
                 attributes.as_slice().buffer_len()
             }
@@ -96,6 +148,21 @@ impl Emitable for ConntrackMessage {
             ConntrackMessage::Delete(attributes) => {
                 attributes.as_slice().emit(buffer)
             }
+            ConntrackMessage::GetCtrZero(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ConntrackMessage::GetStatsCpu(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ConntrackMessage::GetStats(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ConntrackMessage::GetDying(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ConntrackMessage::GetUnconfirmed(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
             ConntrackMessage::Other { attributes, .. } => {
                 attributes.as_slice().emit(buffer)
             }
@@ -129,6 +196,34 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 })?;
                 ConntrackMessage::Delete(nlas)
             }
+            ConntrackMessageType::GetCtrZero => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ConntrackAttribute::parse(&nla_buf)
+                })?;
+                ConntrackMessage::GetCtrZero(attributes)
+            }
+            ConntrackMessageType::GetStatsCpu => {
+                let attributes =
+                    buf.parse_all_nlas(|nla_buf| StatsCpu::parse(&nla_buf))?;
+                ConntrackMessage::GetStatsCpu(attributes)
+            }
+            ConntrackMessageType::GetStats => {
+                let attributes =
+                    buf.parse_all_nlas(|nla_buf| StatsGlobal::parse(&nla_buf))?;
+                ConntrackMessage::GetStats(attributes)
+            }
+            ConntrackMessageType::GetDying => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ConntrackAttribute::parse(&nla_buf)
+                })?;
+                ConntrackMessage::GetDying(attributes)
+            }
+            ConntrackMessageType::GetUnconfirmed => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ConntrackAttribute::parse(&nla_buf)
+                })?;
+                ConntrackMessage::GetUnconfirmed(attributes)
+            }
             ConntrackMessageType::Other(message_type) => {
                 ConntrackMessage::Other {
                     message_type,
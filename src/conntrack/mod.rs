@@ -1,9 +1,22 @@
 // SPDX-License-Identifier: MIT
 
+mod connlabel;
+pub use connlabel::{ConnlabelMap, CONNLABEL_CONF};
+mod event;
+pub use event::ConntrackEvent;
+mod filter;
+pub use filter::{
+    ConntrackFilter, IcmpFilter, PortFilter, ProtoFilter, TupleFilter,
+};
 mod message;
 pub use message::{ConntrackMessage, ConntrackMessageType};
+mod related;
+pub use related::find_masters;
 mod attributes;
 pub use attributes::{
-    ConntrackAttribute, IPTuple, ProtoInfo, ProtoInfoTCP, ProtoTuple, Protocol,
-    Status, TCPFlags, Tuple,
+    ConntrackAttribute, ConntrackLabels, Counter, DccpRole, DccpState, Filter,
+    FilterFlags, Help, IPTuple, Nat, ProtoInfo, ProtoInfoDCCP, ProtoInfoSCTP,
+    ProtoInfoTCP, ProtoNat, ProtoTuple, Protocol, SctpState, SecCtx, SeqAdj,
+    StatsCpu, StatsGlobal, Status, Synproxy, TCPFlags, TCPWindowFlags,
+    TcpState, Timestamp, Tuple,
 };
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+
+use crate::conntrack::{ConntrackAttribute, Status, Tuple};
+
+// Links the entries of a conntrack dump which have been created from an
+// expectation (e.g. the data connection of a FTP session) to their master
+// entry. The returned vector has one item per entry, holding the index of
+// its master in `entries`, or `None` for entries which are not expected or
+// whose master is not part of the dump.
+pub fn find_masters(entries: &[Vec<ConntrackAttribute>]) -> Vec<Option<usize>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let master = master_tuple(entry)?;
+            entries.iter().position(|candidate| {
+                candidate.iter().any(|attr| match attr {
+                    ConntrackAttribute::CtaTupleOrig(tuple) => {
+                        same_tuple(tuple, master)
+                    }
+                    _ => false,
+                })
+            })
+        })
+        .collect()
+}
+
+fn master_tuple(entry: &[ConntrackAttribute]) -> Option<&[Tuple]> {
+    let expected = entry.iter().any(|attr| {
+        matches!(attr, ConntrackAttribute::CtaStatus(status)
+            if status.contains(Status::Expected))
+    });
+    if !expected {
+        return None;
+    }
+    entry.iter().find_map(|attr| match attr {
+        ConntrackAttribute::CtaTupleMaster(tuple) => Some(tuple.as_slice()),
+        _ => None,
+    })
+}
+
+// The kernel does not include the zone in CTA_TUPLE_MASTER, while it is part
+// of CTA_TUPLE_ORIG for entries outside of the default zone
+fn same_tuple(orig: &[Tuple], master: &[Tuple]) -> bool {
+    let without_zone = |tuple: &[Tuple]| -> Vec<Tuple> {
+        tuple
+            .iter()
+            .filter(|t| !matches!(t, Tuple::Zone(_)))
+            .cloned()
+            .collect()
+    };
+    without_zone(orig) == without_zone(master)
+}
@@ -54,6 +54,24 @@ pub const NFNL_SUBSYS_CTHELPER: u8 = libc::NFNL_SUBSYS_CTHELPER as u8;
 pub const NFNL_SUBSYS_NFTABLES: u8 = libc::NFNL_SUBSYS_NFTABLES as u8;
 pub const NFNL_SUBSYS_NFT_COMPAT: u8 = libc::NFNL_SUBSYS_NFT_COMPAT as u8;
 
+pub const NFNL_MSG_BATCH_BEGIN: u8 = libc::NFNL_MSG_BATCH_BEGIN as u8;
+pub const NFNL_MSG_BATCH_END: u8 = libc::NFNL_MSG_BATCH_END as u8;
+
+pub const NFNLGRP_NONE: u32 = libc::NFNLGRP_NONE as u32;
+pub const NFNLGRP_CONNTRACK_NEW: u32 = libc::NFNLGRP_CONNTRACK_NEW as u32;
+pub const NFNLGRP_CONNTRACK_UPDATE: u32 = libc::NFNLGRP_CONNTRACK_UPDATE as u32;
+pub const NFNLGRP_CONNTRACK_DESTROY: u32 =
+    libc::NFNLGRP_CONNTRACK_DESTROY as u32;
+pub const NFNLGRP_CONNTRACK_EXP_NEW: u32 =
+    libc::NFNLGRP_CONNTRACK_EXP_NEW as u32;
+pub const NFNLGRP_CONNTRACK_EXP_UPDATE: u32 =
+    libc::NFNLGRP_CONNTRACK_EXP_UPDATE as u32;
+pub const NFNLGRP_CONNTRACK_EXP_DESTROY: u32 =
+    libc::NFNLGRP_CONNTRACK_EXP_DESTROY as u32;
+pub const NFNLGRP_NFTABLES: u32 = libc::NFNLGRP_NFTABLES as u32;
+pub const NFNLGRP_ACCT_QUOTA: u32 = libc::NFNLGRP_ACCT_QUOTA as u32;
+pub const NFNLGRP_NFTRACE: u32 = libc::NFNLGRP_NFTRACE as u32;
+
 pub const NFULA_CFG_CMD: u16 = libc::NFULA_CFG_CMD as u16;
 pub const NFULA_CFG_MODE: u16 = libc::NFULA_CFG_MODE as u16;
 pub const NFULA_CFG_NLBUFSIZ: u16 = libc::NFULA_CFG_NLBUFSIZ as u16;
@@ -81,3 +99,31 @@ pub const NFULA_HWHEADER: u16 = libc::NFULA_HWHEADER as u16;
 pub const NFULA_HWLEN: u16 = libc::NFULA_HWLEN as u16;
 pub const NFULA_CT: u16 = libc::NFULA_CT as u16;
 pub const NFULA_CT_INFO: u16 = libc::NFULA_CT_INFO as u16;
+
+pub const NFQA_PACKET_HDR: u16 = libc::NFQA_PACKET_HDR as u16;
+pub const NFQA_VERDICT_HDR: u16 = libc::NFQA_VERDICT_HDR as u16;
+pub const NFQA_MARK: u16 = libc::NFQA_MARK as u16;
+pub const NFQA_TIMESTAMP: u16 = libc::NFQA_TIMESTAMP as u16;
+pub const NFQA_IFINDEX_INDEV: u16 = libc::NFQA_IFINDEX_INDEV as u16;
+pub const NFQA_IFINDEX_OUTDEV: u16 = libc::NFQA_IFINDEX_OUTDEV as u16;
+pub const NFQA_IFINDEX_PHYSINDEV: u16 = libc::NFQA_IFINDEX_PHYSINDEV as u16;
+pub const NFQA_IFINDEX_PHYSOUTDEV: u16 = libc::NFQA_IFINDEX_PHYSOUTDEV as u16;
+pub const NFQA_HWADDR: u16 = libc::NFQA_HWADDR as u16;
+pub const NFQA_PAYLOAD: u16 = libc::NFQA_PAYLOAD as u16;
+pub const NFQA_CT: u16 = libc::NFQA_CT as u16;
+pub const NFQA_CT_INFO: u16 = libc::NFQA_CT_INFO as u16;
+pub const NFQA_CAP_LEN: u16 = libc::NFQA_CAP_LEN as u16;
+pub const NFQA_SKB_INFO: u16 = libc::NFQA_SKB_INFO as u16;
+pub const NFQA_EXP: u16 = libc::NFQA_EXP as u16;
+pub const NFQA_UID: u16 = libc::NFQA_UID as u16;
+pub const NFQA_GID: u16 = libc::NFQA_GID as u16;
+pub const NFQA_SECCTX: u16 = libc::NFQA_SECCTX as u16;
+pub const NFQA_VLAN: u16 = libc::NFQA_VLAN as u16;
+pub const NFQA_L2HDR: u16 = libc::NFQA_L2HDR as u16;
+pub const NFQA_PRIORITY: u16 = libc::NFQA_PRIORITY as u16;
+
+pub const NFQA_CFG_CMD: u16 = libc::NFQA_CFG_CMD as u16;
+pub const NFQA_CFG_PARAMS: u16 = libc::NFQA_CFG_PARAMS as u16;
+pub const NFQA_CFG_QUEUE_MAXLEN: u16 = libc::NFQA_CFG_QUEUE_MAXLEN as u16;
+pub const NFQA_CFG_MASK: u16 = libc::NFQA_CFG_MASK as u16;
+pub const NFQA_CFG_FLAGS: u16 = libc::NFQA_CFG_FLAGS as u16;
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_string, parse_u32_be, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::cthelper::attributes::{
+    policy::HelperPolicySet, status::HelperStatus, tuple::HelperTuple,
+};
+
+const NFCTH_NAME: u16 = 1;
+const NFCTH_TUPLE: u16 = 2;
+const NFCTH_QUEUE_NUM: u16 = 3;
+const NFCTH_POLICY: u16 = 4;
+const NFCTH_PRIV_DATA_LEN: u16 = 5;
+const NFCTH_STATUS: u16 = 6;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperAttribute {
+    Name(String),
+    Tuple(Vec<HelperTuple>),
+    // nfqueue number the packets of the helped connections are sent to
+    QueueNum(u32),
+    Policy(Vec<HelperPolicySet>),
+    // Size of the private data the helper stores with each connection
+    PrivDataLen(u32),
+    Status(HelperStatus),
+    Other(DefaultNla),
+}
+
+impl Nla for HelperAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            HelperAttribute::Name(attr) => attr.len() + 1,
+            HelperAttribute::Tuple(attr) => attr.as_slice().buffer_len(),
+            HelperAttribute::QueueNum(attr) => size_of_val(attr),
+            HelperAttribute::Policy(attr) => attr.as_slice().buffer_len(),
+            HelperAttribute::PrivDataLen(attr) => size_of_val(attr),
+            HelperAttribute::Status(_) => size_of::<u32>(),
+            HelperAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HelperAttribute::Name(_) => NFCTH_NAME,
+            HelperAttribute::Tuple(_) => NFCTH_TUPLE,
+            HelperAttribute::QueueNum(_) => NFCTH_QUEUE_NUM,
+            HelperAttribute::Policy(_) => NFCTH_POLICY,
+            HelperAttribute::PrivDataLen(_) => NFCTH_PRIV_DATA_LEN,
+            HelperAttribute::Status(_) => NFCTH_STATUS,
+            HelperAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HelperAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            HelperAttribute::Tuple(attr) => attr.as_slice().emit(buffer),
+            HelperAttribute::QueueNum(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            HelperAttribute::Policy(attr) => attr.as_slice().emit(buffer),
+            HelperAttribute::PrivDataLen(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            HelperAttribute::Status(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            HelperAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, HelperAttribute::Tuple(_) | HelperAttribute::Policy(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HelperAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFCTH_NAME => HelperAttribute::Name(
+                parse_string(payload).context("invalid NFCTH_NAME value")?,
+            ),
+            NFCTH_TUPLE => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFCTH_TUPLE value")?;
+                    tuples.push(HelperTuple::parse(nlas)?);
+                }
+                HelperAttribute::Tuple(tuples)
+            }
+            NFCTH_QUEUE_NUM => HelperAttribute::QueueNum(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_QUEUE_NUM value")?,
+            ),
+            NFCTH_POLICY => {
+                let mut sets = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFCTH_POLICY value")?;
+                    sets.push(HelperPolicySet::parse(nlas)?);
+                }
+                HelperAttribute::Policy(sets)
+            }
+            NFCTH_PRIV_DATA_LEN => HelperAttribute::PrivDataLen(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_PRIV_DATA_LEN value")?,
+            ),
+            NFCTH_STATUS => HelperAttribute::Status(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_STATUS value")?
+                    .into(),
+            ),
+            _ => HelperAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod policy;
+mod status;
+mod tuple;
+
+pub use attribute::HelperAttribute;
+pub use policy::{HelperPolicy, HelperPolicySet};
+pub use status::HelperStatus;
+pub use tuple::HelperTuple;
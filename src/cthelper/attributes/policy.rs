@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_string, parse_u32_be, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+const NFCTH_POLICY_SET_NUM: u16 = 1;
+const NFCTH_POLICY_SET1: u16 = 2;
+const NFCTH_POLICY_SET2: u16 = 3;
+const NFCTH_POLICY_SET3: u16 = 4;
+const NFCTH_POLICY_SET4: u16 = 5;
+
+const NFCTH_POLICY_NAME: u16 = 1;
+const NFCTH_POLICY_EXPECT_MAX: u16 = 2;
+const NFCTH_POLICY_EXPECT_TIMEOUT: u16 = 3;
+
+// Expectation policies of a helper, one per expectation class. The kernel
+// reads SetNum and then as many sets, starting from Set1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperPolicySet {
+    SetNum(u32),
+    Set1(Vec<HelperPolicy>),
+    Set2(Vec<HelperPolicy>),
+    Set3(Vec<HelperPolicy>),
+    Set4(Vec<HelperPolicy>),
+    Other(DefaultNla),
+}
+
+impl Nla for HelperPolicySet {
+    fn value_len(&self) -> usize {
+        match self {
+            HelperPolicySet::SetNum(attr) => size_of_val(attr),
+            HelperPolicySet::Set1(attr)
+            | HelperPolicySet::Set2(attr)
+            | HelperPolicySet::Set3(attr)
+            | HelperPolicySet::Set4(attr) => attr.as_slice().buffer_len(),
+            HelperPolicySet::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HelperPolicySet::SetNum(_) => NFCTH_POLICY_SET_NUM,
+            HelperPolicySet::Set1(_) => NFCTH_POLICY_SET1,
+            HelperPolicySet::Set2(_) => NFCTH_POLICY_SET2,
+            HelperPolicySet::Set3(_) => NFCTH_POLICY_SET3,
+            HelperPolicySet::Set4(_) => NFCTH_POLICY_SET4,
+            HelperPolicySet::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HelperPolicySet::SetNum(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            HelperPolicySet::Set1(attr)
+            | HelperPolicySet::Set2(attr)
+            | HelperPolicySet::Set3(attr)
+            | HelperPolicySet::Set4(attr) => attr.as_slice().emit(buffer),
+            HelperPolicySet::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            HelperPolicySet::Set1(_)
+                | HelperPolicySet::Set2(_)
+                | HelperPolicySet::Set3(_)
+                | HelperPolicySet::Set4(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HelperPolicySet
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFCTH_POLICY_SET_NUM => HelperPolicySet::SetNum(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_POLICY_SET_NUM value")?,
+            ),
+            NFCTH_POLICY_SET1 => {
+                let mut policies = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFCTH_POLICY_SET1 value")?;
+                    policies.push(HelperPolicy::parse(nlas)?);
+                }
+                HelperPolicySet::Set1(policies)
+            }
+            NFCTH_POLICY_SET2 => {
+                let mut policies = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFCTH_POLICY_SET2 value")?;
+                    policies.push(HelperPolicy::parse(nlas)?);
+                }
+                HelperPolicySet::Set2(policies)
+            }
+            NFCTH_POLICY_SET3 => {
+                let mut policies = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFCTH_POLICY_SET3 value")?;
+                    policies.push(HelperPolicy::parse(nlas)?);
+                }
+                HelperPolicySet::Set3(policies)
+            }
+            NFCTH_POLICY_SET4 => {
+                let mut policies = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFCTH_POLICY_SET4 value")?;
+                    policies.push(HelperPolicy::parse(nlas)?);
+                }
+                HelperPolicySet::Set4(policies)
+            }
+            _ => HelperPolicySet::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperPolicy {
+    Name(String),
+    // Maximum number of expectations of this class per master connection
+    ExpectMax(u32),
+    // Lifetime in seconds of the expectations of this class
+    ExpectTimeout(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for HelperPolicy {
+    fn value_len(&self) -> usize {
+        match self {
+            HelperPolicy::Name(attr) => attr.len() + 1,
+            HelperPolicy::ExpectMax(attr) => size_of_val(attr),
+            HelperPolicy::ExpectTimeout(attr) => size_of_val(attr),
+            HelperPolicy::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HelperPolicy::Name(_) => NFCTH_POLICY_NAME,
+            HelperPolicy::ExpectMax(_) => NFCTH_POLICY_EXPECT_MAX,
+            HelperPolicy::ExpectTimeout(_) => NFCTH_POLICY_EXPECT_TIMEOUT,
+            HelperPolicy::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HelperPolicy::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            HelperPolicy::ExpectMax(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            HelperPolicy::ExpectTimeout(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            HelperPolicy::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HelperPolicy
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFCTH_POLICY_NAME => HelperPolicy::Name(
+                parse_string(payload)
+                    .context("invalid NFCTH_POLICY_NAME value")?,
+            ),
+            NFCTH_POLICY_EXPECT_MAX => HelperPolicy::ExpectMax(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_POLICY_EXPECT_MAX value")?,
+            ),
+            NFCTH_POLICY_EXPECT_TIMEOUT => HelperPolicy::ExpectTimeout(
+                parse_u32_be(payload)
+                    .context("invalid NFCTH_POLICY_EXPECT_TIMEOUT value")?,
+            ),
+            _ => HelperPolicy::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+const NFCT_HELPER_STATUS_DISABLED: u32 = 0;
+const NFCT_HELPER_STATUS_ENABLED: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperStatus {
+    Disabled,
+    Enabled,
+    Other(u32),
+}
+
+impl From<HelperStatus> for u32 {
+    fn from(status: HelperStatus) -> Self {
+        match status {
+            HelperStatus::Disabled => NFCT_HELPER_STATUS_DISABLED,
+            HelperStatus::Enabled => NFCT_HELPER_STATUS_ENABLED,
+            HelperStatus::Other(s) => s,
+        }
+    }
+}
+
+impl From<u32> for HelperStatus {
+    fn from(status: u32) -> Self {
+        match status {
+            NFCT_HELPER_STATUS_DISABLED => HelperStatus::Disabled,
+            NFCT_HELPER_STATUS_ENABLED => HelperStatus::Enabled,
+            _ => HelperStatus::Other(status),
+        }
+    }
+}
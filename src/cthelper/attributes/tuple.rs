@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, parse_u16_be, parse_u8, DecodeError, DefaultNla, ErrorContext,
+    Nla, NlaBuffer, Parseable,
+};
+
+use crate::{conntrack::Protocol, message::ProtoFamily};
+
+const NFCTH_TUPLE_L3PROTONUM: u16 = 1;
+const NFCTH_TUPLE_L4PROTONUM: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperTuple {
+    L3ProtoNum(ProtoFamily),
+    L4ProtoNum(Protocol),
+    Other(DefaultNla),
+}
+
+impl Nla for HelperTuple {
+    fn value_len(&self) -> usize {
+        match self {
+            HelperTuple::L3ProtoNum(_) => size_of::<u16>(),
+            HelperTuple::L4ProtoNum(_) => size_of::<u8>(),
+            HelperTuple::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HelperTuple::L3ProtoNum(_) => NFCTH_TUPLE_L3PROTONUM,
+            HelperTuple::L4ProtoNum(_) => NFCTH_TUPLE_L4PROTONUM,
+            HelperTuple::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HelperTuple::L3ProtoNum(attr) => {
+                emit_u16_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            HelperTuple::L4ProtoNum(attr) => buffer[0] = (*attr).into(),
+            HelperTuple::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HelperTuple
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFCTH_TUPLE_L3PROTONUM => {
+                HelperTuple::L3ProtoNum(ProtoFamily::from(
+                    parse_u16_be(payload)
+                        .context("invalid NFCTH_TUPLE_L3PROTONUM value")?
+                        as u8,
+                ))
+            }
+            NFCTH_TUPLE_L4PROTONUM => HelperTuple::L4ProtoNum(
+                parse_u8(payload)
+                    .context("invalid NFCTH_TUPLE_L4PROTONUM value")?
+                    .into(),
+            ),
+            _ => HelperTuple::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
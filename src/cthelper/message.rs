@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{buffer::NetfilterBuffer, cthelper::attributes::HelperAttribute};
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum HelperMessage {
+    New(Vec<HelperAttribute>),
+    Get(Vec<HelperAttribute>),
+    Delete(Vec<HelperAttribute>),
+    Other {
+        message_type: u8,
+        attributes: Vec<DefaultNla>,
+    },
+}
+
+const NFNL_MSG_CTHELPER_NEW: u8 = 0;
+const NFNL_MSG_CTHELPER_GET: u8 = 1;
+const NFNL_MSG_CTHELPER_DEL: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelperMessageType {
+    New,
+    Get,
+    Delete,
+    Other(u8),
+}
+
+impl From<u8> for HelperMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            NFNL_MSG_CTHELPER_NEW => Self::New,
+            NFNL_MSG_CTHELPER_GET => Self::Get,
+            NFNL_MSG_CTHELPER_DEL => Self::Delete,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<HelperMessageType> for u8 {
+    fn from(value: HelperMessageType) -> Self {
+        match value {
+            HelperMessageType::New => NFNL_MSG_CTHELPER_NEW,
+            HelperMessageType::Get => NFNL_MSG_CTHELPER_GET,
+            HelperMessageType::Delete => NFNL_MSG_CTHELPER_DEL,
+            HelperMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl HelperMessage {
+    pub fn message_type(&self) -> HelperMessageType {
+        match self {
+            HelperMessage::New(_) => HelperMessageType::New,
+            HelperMessage::Get(_) => HelperMessageType::Get,
+            HelperMessage::Delete(_) => HelperMessageType::Delete,
+            HelperMessage::Other { message_type, .. } => (*message_type).into(),
+        }
+    }
+}
+
+impl Emitable for HelperMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            HelperMessage::New(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            HelperMessage::Get(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            HelperMessage::Delete(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            HelperMessage::Other { attributes, .. } => {
+                attributes.as_slice().buffer_len()
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            HelperMessage::New(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            HelperMessage::Get(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            HelperMessage::Delete(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            HelperMessage::Other { attributes, .. } => {
+                attributes.as_slice().emit(buffer)
+            }
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for HelperMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        Ok(match HelperMessageType::from(message_type) {
+            HelperMessageType::New => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    HelperAttribute::parse(&nla_buf)
+                })?;
+                HelperMessage::New(attributes)
+            }
+            HelperMessageType::Get => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    HelperAttribute::parse(&nla_buf)
+                })?;
+                HelperMessage::Get(attributes)
+            }
+            HelperMessageType::Delete => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    HelperAttribute::parse(&nla_buf)
+                })?;
+                HelperMessage::Delete(attributes)
+            }
+            HelperMessageType::Other(message_type) => HelperMessage::Other {
+                message_type,
+                attributes: buf.default_nlas()?,
+            },
+        })
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{HelperMessage, HelperMessageType};
+mod attributes;
+pub use attributes::{
+    HelperAttribute, HelperPolicy, HelperPolicySet, HelperStatus, HelperTuple,
+};
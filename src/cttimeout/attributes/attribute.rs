@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_string, parse_u16_be, parse_u32_be,
+    parse_u8, DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    Parseable, ParseableParametrized,
+};
+
+use crate::{
+    conntrack::Protocol, cttimeout::attributes::data::TimeoutData,
+    message::ProtoFamily,
+};
+
+const CTA_TIMEOUT_NAME: u16 = 1;
+const CTA_TIMEOUT_L3PROTO: u16 = 2;
+const CTA_TIMEOUT_L4PROTO: u16 = 3;
+const CTA_TIMEOUT_DATA: u16 = 4;
+const CTA_TIMEOUT_USE: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutAttribute {
+    Name(String),
+    L3Proto(ProtoFamily),
+    L4Proto(Protocol),
+    Data(TimeoutData),
+    Use(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TimeoutAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            TimeoutAttribute::Name(attr) => attr.len() + 1,
+            TimeoutAttribute::L3Proto(_) => size_of::<u16>(),
+            TimeoutAttribute::L4Proto(_) => size_of::<u8>(),
+            TimeoutAttribute::Data(attr) => attr.buffer_len(),
+            TimeoutAttribute::Use(attr) => size_of_val(attr),
+            TimeoutAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            TimeoutAttribute::Name(_) => CTA_TIMEOUT_NAME,
+            TimeoutAttribute::L3Proto(_) => CTA_TIMEOUT_L3PROTO,
+            TimeoutAttribute::L4Proto(_) => CTA_TIMEOUT_L4PROTO,
+            TimeoutAttribute::Data(_) => CTA_TIMEOUT_DATA,
+            TimeoutAttribute::Use(_) => CTA_TIMEOUT_USE,
+            TimeoutAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            TimeoutAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            TimeoutAttribute::L3Proto(attr) => {
+                emit_u16_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            TimeoutAttribute::L4Proto(attr) => buffer[0] = (*attr).into(),
+            TimeoutAttribute::Data(attr) => attr.emit(buffer),
+            TimeoutAttribute::Use(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TimeoutAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, TimeoutAttribute::Data(_))
+    }
+}
+
+// The layout of CTA_TIMEOUT_DATA depends on CTA_TIMEOUT_L4PROTO, which has to
+// be looked up among the other attributes of the message first
+impl<'buffer, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'buffer T>, Option<Protocol>>
+    for TimeoutAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'buffer T>,
+        protocol: Option<Protocol>,
+    ) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_NAME => TimeoutAttribute::Name(
+                parse_string(payload)
+                    .context("invalid CTA_TIMEOUT_NAME value")?,
+            ),
+            CTA_TIMEOUT_L3PROTO => {
+                TimeoutAttribute::L3Proto(ProtoFamily::from(
+                    parse_u16_be(payload)
+                        .context("invalid CTA_TIMEOUT_L3PROTO value")?
+                        as u8,
+                ))
+            }
+            CTA_TIMEOUT_L4PROTO => TimeoutAttribute::L4Proto(
+                parse_u8(payload)
+                    .context("invalid CTA_TIMEOUT_L4PROTO value")?
+                    .into(),
+            ),
+            CTA_TIMEOUT_DATA => TimeoutAttribute::Data(
+                TimeoutData::parse(payload, protocol)
+                    .context("invalid CTA_TIMEOUT_DATA value")?,
+            ),
+            CTA_TIMEOUT_USE => TimeoutAttribute::Use(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_USE value")?,
+            ),
+            _ => TimeoutAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+pub(crate) fn find_l4proto<'a, I>(
+    nlas: I,
+) -> Result<Option<Protocol>, DecodeError>
+where
+    I: Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+{
+    for nla in nlas {
+        let nla = nla?;
+        if nla.kind() == CTA_TIMEOUT_L4PROTO {
+            return Ok(Some(
+                parse_u8(nla.value())
+                    .context("invalid CTA_TIMEOUT_L4PROTO value")?
+                    .into(),
+            ));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ErrorContext, NlaBuffer, NlasIterator,
+    Parseable,
+};
+
+use crate::{
+    conntrack::Protocol,
+    cttimeout::attributes::{
+        dccp::DccpTimeout, generic::GenericTimeout, gre::GreTimeout,
+        sctp::SctpTimeout, tcp::TcpTimeout, udp::UdpTimeout,
+    },
+};
+
+// Content of CTA_TIMEOUT_DATA, whose attributes depend on the layer 4
+// protocol given by CTA_TIMEOUT_L4PROTO. Protocols without a dedicated
+// tracker are handled by the generic one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutData {
+    Tcp(Vec<TcpTimeout>),
+    Udp(Vec<UdpTimeout>),
+    UdpLite(Vec<UdpTimeout>),
+    Sctp(Vec<SctpTimeout>),
+    Dccp(Vec<DccpTimeout>),
+    Icmp(Vec<GenericTimeout>),
+    Icmpv6(Vec<GenericTimeout>),
+    Gre(Vec<GreTimeout>),
+    Generic(Vec<GenericTimeout>),
+    // Data of a message without CTA_TIMEOUT_L4PROTO
+    Other(Vec<DefaultNla>),
+}
+
+fn parse_nlas<'a, T, F>(payload: &'a [u8], f: F) -> Result<Vec<T>, DecodeError>
+where
+    F: Fn(&NlaBuffer<&'a [u8]>) -> Result<T, DecodeError>,
+{
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context("invalid CTA_TIMEOUT_DATA value")?;
+        nlas.push(f(nla)?);
+    }
+    Ok(nlas)
+}
+
+impl TimeoutData {
+    pub(crate) fn parse(
+        payload: &[u8],
+        protocol: Option<Protocol>,
+    ) -> Result<Self, DecodeError> {
+        let Some(protocol) = protocol else {
+            return Ok(TimeoutData::Other(parse_nlas(
+                payload,
+                DefaultNla::parse,
+            )?));
+        };
+        Ok(match protocol {
+            Protocol::Tcp => {
+                TimeoutData::Tcp(parse_nlas(payload, TcpTimeout::parse)?)
+            }
+            Protocol::Udp => {
+                TimeoutData::Udp(parse_nlas(payload, UdpTimeout::parse)?)
+            }
+            Protocol::UdpLite => {
+                TimeoutData::UdpLite(parse_nlas(payload, UdpTimeout::parse)?)
+            }
+            Protocol::Sctp => {
+                TimeoutData::Sctp(parse_nlas(payload, SctpTimeout::parse)?)
+            }
+            Protocol::Dccp => {
+                TimeoutData::Dccp(parse_nlas(payload, DccpTimeout::parse)?)
+            }
+            Protocol::Icmp => {
+                TimeoutData::Icmp(parse_nlas(payload, GenericTimeout::parse)?)
+            }
+            Protocol::Ipv6Icmp => {
+                TimeoutData::Icmpv6(parse_nlas(payload, GenericTimeout::parse)?)
+            }
+            Protocol::Gre => {
+                TimeoutData::Gre(parse_nlas(payload, GreTimeout::parse)?)
+            }
+            _ => TimeoutData::Generic(parse_nlas(
+                payload,
+                GenericTimeout::parse,
+            )?),
+        })
+    }
+}
+
+impl Emitable for TimeoutData {
+    fn buffer_len(&self) -> usize {
+        match self {
+            TimeoutData::Tcp(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Udp(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::UdpLite(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Sctp(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Dccp(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Icmp(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Icmpv6(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Gre(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Generic(nlas) => nlas.as_slice().buffer_len(),
+            TimeoutData::Other(nlas) => nlas.as_slice().buffer_len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            TimeoutData::Tcp(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Udp(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::UdpLite(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Sctp(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Dccp(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Icmp(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Icmpv6(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Gre(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Generic(nlas) => nlas.as_slice().emit(buffer),
+            TimeoutData::Other(nlas) => nlas.as_slice().emit(buffer),
+        }
+    }
+}
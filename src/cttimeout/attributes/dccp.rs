@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_DCCP_REQUEST: u16 = 1;
+const CTA_TIMEOUT_DCCP_RESPOND: u16 = 2;
+const CTA_TIMEOUT_DCCP_PARTOPEN: u16 = 3;
+const CTA_TIMEOUT_DCCP_OPEN: u16 = 4;
+const CTA_TIMEOUT_DCCP_CLOSEREQ: u16 = 5;
+const CTA_TIMEOUT_DCCP_CLOSING: u16 = 6;
+const CTA_TIMEOUT_DCCP_TIMEWAIT: u16 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DccpTimeout {
+    Request(u32),
+    Respond(u32),
+    PartOpen(u32),
+    Open(u32),
+    CloseReq(u32),
+    Closing(u32),
+    TimeWait(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for DccpTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            DccpTimeout::Request(attr) => size_of_val(attr),
+            DccpTimeout::Respond(attr) => size_of_val(attr),
+            DccpTimeout::PartOpen(attr) => size_of_val(attr),
+            DccpTimeout::Open(attr) => size_of_val(attr),
+            DccpTimeout::CloseReq(attr) => size_of_val(attr),
+            DccpTimeout::Closing(attr) => size_of_val(attr),
+            DccpTimeout::TimeWait(attr) => size_of_val(attr),
+            DccpTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            DccpTimeout::Request(_) => CTA_TIMEOUT_DCCP_REQUEST,
+            DccpTimeout::Respond(_) => CTA_TIMEOUT_DCCP_RESPOND,
+            DccpTimeout::PartOpen(_) => CTA_TIMEOUT_DCCP_PARTOPEN,
+            DccpTimeout::Open(_) => CTA_TIMEOUT_DCCP_OPEN,
+            DccpTimeout::CloseReq(_) => CTA_TIMEOUT_DCCP_CLOSEREQ,
+            DccpTimeout::Closing(_) => CTA_TIMEOUT_DCCP_CLOSING,
+            DccpTimeout::TimeWait(_) => CTA_TIMEOUT_DCCP_TIMEWAIT,
+            DccpTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            DccpTimeout::Request(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::Respond(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::PartOpen(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::Open(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::CloseReq(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::Closing(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::TimeWait(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DccpTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for DccpTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_DCCP_REQUEST => DccpTimeout::Request(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_REQUEST value")?,
+            ),
+            CTA_TIMEOUT_DCCP_RESPOND => DccpTimeout::Respond(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_RESPOND value")?,
+            ),
+            CTA_TIMEOUT_DCCP_PARTOPEN => DccpTimeout::PartOpen(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_PARTOPEN value")?,
+            ),
+            CTA_TIMEOUT_DCCP_OPEN => DccpTimeout::Open(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_OPEN value")?,
+            ),
+            CTA_TIMEOUT_DCCP_CLOSEREQ => DccpTimeout::CloseReq(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_CLOSEREQ value")?,
+            ),
+            CTA_TIMEOUT_DCCP_CLOSING => DccpTimeout::Closing(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_CLOSING value")?,
+            ),
+            CTA_TIMEOUT_DCCP_TIMEWAIT => DccpTimeout::TimeWait(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_DCCP_TIMEWAIT value")?,
+            ),
+            _ => DccpTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_GENERIC_TIMEOUT: u16 = 1;
+
+// Single timeout in seconds of the generic tracker, also used for ICMP and
+// ICMPv6 as CTA_TIMEOUT_ICMP_TIMEOUT and CTA_TIMEOUT_ICMPV6_TIMEOUT share its
+// attribute type
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GenericTimeout {
+    Timeout(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for GenericTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            GenericTimeout::Timeout(attr) => size_of_val(attr),
+            GenericTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            GenericTimeout::Timeout(_) => CTA_TIMEOUT_GENERIC_TIMEOUT,
+            GenericTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            GenericTimeout::Timeout(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            GenericTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for GenericTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_GENERIC_TIMEOUT => GenericTimeout::Timeout(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_GENERIC_TIMEOUT value")?,
+            ),
+            _ => GenericTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
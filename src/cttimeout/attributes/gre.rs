@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_GRE_UNREPLIED: u16 = 1;
+const CTA_TIMEOUT_GRE_REPLIED: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GreTimeout {
+    Unreplied(u32),
+    Replied(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for GreTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            GreTimeout::Unreplied(attr) => size_of_val(attr),
+            GreTimeout::Replied(attr) => size_of_val(attr),
+            GreTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            GreTimeout::Unreplied(_) => CTA_TIMEOUT_GRE_UNREPLIED,
+            GreTimeout::Replied(_) => CTA_TIMEOUT_GRE_REPLIED,
+            GreTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            GreTimeout::Unreplied(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            GreTimeout::Replied(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            GreTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for GreTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_GRE_UNREPLIED => GreTimeout::Unreplied(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_GRE_UNREPLIED value")?,
+            ),
+            CTA_TIMEOUT_GRE_REPLIED => GreTimeout::Replied(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_GRE_REPLIED value")?,
+            ),
+            _ => GreTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod data;
+mod dccp;
+mod generic;
+mod gre;
+mod sctp;
+mod tcp;
+mod udp;
+
+pub(crate) use attribute::find_l4proto;
+pub use attribute::TimeoutAttribute;
+pub use data::TimeoutData;
+pub use dccp::DccpTimeout;
+pub use generic::GenericTimeout;
+pub use gre::GreTimeout;
+pub use sctp::SctpTimeout;
+pub use tcp::TcpTimeout;
+pub use udp::UdpTimeout;
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_SCTP_CLOSED: u16 = 1;
+const CTA_TIMEOUT_SCTP_COOKIE_WAIT: u16 = 2;
+const CTA_TIMEOUT_SCTP_COOKIE_ECHOED: u16 = 3;
+const CTA_TIMEOUT_SCTP_ESTABLISHED: u16 = 4;
+const CTA_TIMEOUT_SCTP_SHUTDOWN_SENT: u16 = 5;
+const CTA_TIMEOUT_SCTP_SHUTDOWN_RECD: u16 = 6;
+const CTA_TIMEOUT_SCTP_SHUTDOWN_ACK_SENT: u16 = 7;
+const CTA_TIMEOUT_SCTP_HEARTBEAT_SENT: u16 = 8;
+const CTA_TIMEOUT_SCTP_HEARTBEAT_ACKED: u16 = 9;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SctpTimeout {
+    Closed(u32),
+    CookieWait(u32),
+    CookieEchoed(u32),
+    Established(u32),
+    ShutdownSent(u32),
+    ShutdownRecd(u32),
+    ShutdownAckSent(u32),
+    HeartbeatSent(u32),
+    HeartbeatAcked(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for SctpTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            SctpTimeout::Closed(attr) => size_of_val(attr),
+            SctpTimeout::CookieWait(attr) => size_of_val(attr),
+            SctpTimeout::CookieEchoed(attr) => size_of_val(attr),
+            SctpTimeout::Established(attr) => size_of_val(attr),
+            SctpTimeout::ShutdownSent(attr) => size_of_val(attr),
+            SctpTimeout::ShutdownRecd(attr) => size_of_val(attr),
+            SctpTimeout::ShutdownAckSent(attr) => size_of_val(attr),
+            SctpTimeout::HeartbeatSent(attr) => size_of_val(attr),
+            SctpTimeout::HeartbeatAcked(attr) => size_of_val(attr),
+            SctpTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SctpTimeout::Closed(_) => CTA_TIMEOUT_SCTP_CLOSED,
+            SctpTimeout::CookieWait(_) => CTA_TIMEOUT_SCTP_COOKIE_WAIT,
+            SctpTimeout::CookieEchoed(_) => CTA_TIMEOUT_SCTP_COOKIE_ECHOED,
+            SctpTimeout::Established(_) => CTA_TIMEOUT_SCTP_ESTABLISHED,
+            SctpTimeout::ShutdownSent(_) => CTA_TIMEOUT_SCTP_SHUTDOWN_SENT,
+            SctpTimeout::ShutdownRecd(_) => CTA_TIMEOUT_SCTP_SHUTDOWN_RECD,
+            SctpTimeout::ShutdownAckSent(_) => {
+                CTA_TIMEOUT_SCTP_SHUTDOWN_ACK_SENT
+            }
+            SctpTimeout::HeartbeatSent(_) => CTA_TIMEOUT_SCTP_HEARTBEAT_SENT,
+            SctpTimeout::HeartbeatAcked(_) => CTA_TIMEOUT_SCTP_HEARTBEAT_ACKED,
+            SctpTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SctpTimeout::Closed(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SctpTimeout::CookieWait(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::CookieEchoed(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::Established(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::ShutdownSent(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::ShutdownRecd(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::ShutdownAckSent(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::HeartbeatSent(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::HeartbeatAcked(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SctpTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SctpTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_SCTP_CLOSED => SctpTimeout::Closed(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_CLOSED value")?,
+            ),
+            CTA_TIMEOUT_SCTP_COOKIE_WAIT => SctpTimeout::CookieWait(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_COOKIE_WAIT value")?,
+            ),
+            CTA_TIMEOUT_SCTP_COOKIE_ECHOED => SctpTimeout::CookieEchoed(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_COOKIE_ECHOED value")?,
+            ),
+            CTA_TIMEOUT_SCTP_ESTABLISHED => SctpTimeout::Established(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_ESTABLISHED value")?,
+            ),
+            CTA_TIMEOUT_SCTP_SHUTDOWN_SENT => SctpTimeout::ShutdownSent(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_SHUTDOWN_SENT value")?,
+            ),
+            CTA_TIMEOUT_SCTP_SHUTDOWN_RECD => SctpTimeout::ShutdownRecd(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_SHUTDOWN_RECD value")?,
+            ),
+            CTA_TIMEOUT_SCTP_SHUTDOWN_ACK_SENT => {
+                SctpTimeout::ShutdownAckSent(parse_u32_be(payload).context(
+                    "invalid CTA_TIMEOUT_SCTP_SHUTDOWN_ACK_SENT value",
+                )?)
+            }
+            CTA_TIMEOUT_SCTP_HEARTBEAT_SENT => SctpTimeout::HeartbeatSent(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_SCTP_HEARTBEAT_SENT value")?,
+            ),
+            CTA_TIMEOUT_SCTP_HEARTBEAT_ACKED => {
+                SctpTimeout::HeartbeatAcked(parse_u32_be(payload).context(
+                    "invalid CTA_TIMEOUT_SCTP_HEARTBEAT_ACKED value",
+                )?)
+            }
+            _ => SctpTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
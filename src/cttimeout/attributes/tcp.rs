@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_TCP_SYN_SENT: u16 = 1;
+const CTA_TIMEOUT_TCP_SYN_RECV: u16 = 2;
+const CTA_TIMEOUT_TCP_ESTABLISHED: u16 = 3;
+const CTA_TIMEOUT_TCP_FIN_WAIT: u16 = 4;
+const CTA_TIMEOUT_TCP_CLOSE_WAIT: u16 = 5;
+const CTA_TIMEOUT_TCP_LAST_ACK: u16 = 6;
+const CTA_TIMEOUT_TCP_TIME_WAIT: u16 = 7;
+const CTA_TIMEOUT_TCP_CLOSE: u16 = 8;
+const CTA_TIMEOUT_TCP_SYN_SENT2: u16 = 9;
+const CTA_TIMEOUT_TCP_RETRANS: u16 = 10;
+const CTA_TIMEOUT_TCP_UNACK: u16 = 11;
+
+// Timeouts in seconds of each TCP conntrack state, Retrans and Unack apply
+// to connections with retransmitted or unacknowledged data
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TcpTimeout {
+    SynSent(u32),
+    SynRecv(u32),
+    Established(u32),
+    FinWait(u32),
+    CloseWait(u32),
+    LastAck(u32),
+    TimeWait(u32),
+    Close(u32),
+    SynSent2(u32),
+    Retrans(u32),
+    Unack(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcpTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            TcpTimeout::SynSent(attr) => size_of_val(attr),
+            TcpTimeout::SynRecv(attr) => size_of_val(attr),
+            TcpTimeout::Established(attr) => size_of_val(attr),
+            TcpTimeout::FinWait(attr) => size_of_val(attr),
+            TcpTimeout::CloseWait(attr) => size_of_val(attr),
+            TcpTimeout::LastAck(attr) => size_of_val(attr),
+            TcpTimeout::TimeWait(attr) => size_of_val(attr),
+            TcpTimeout::Close(attr) => size_of_val(attr),
+            TcpTimeout::SynSent2(attr) => size_of_val(attr),
+            TcpTimeout::Retrans(attr) => size_of_val(attr),
+            TcpTimeout::Unack(attr) => size_of_val(attr),
+            TcpTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            TcpTimeout::SynSent(_) => CTA_TIMEOUT_TCP_SYN_SENT,
+            TcpTimeout::SynRecv(_) => CTA_TIMEOUT_TCP_SYN_RECV,
+            TcpTimeout::Established(_) => CTA_TIMEOUT_TCP_ESTABLISHED,
+            TcpTimeout::FinWait(_) => CTA_TIMEOUT_TCP_FIN_WAIT,
+            TcpTimeout::CloseWait(_) => CTA_TIMEOUT_TCP_CLOSE_WAIT,
+            TcpTimeout::LastAck(_) => CTA_TIMEOUT_TCP_LAST_ACK,
+            TcpTimeout::TimeWait(_) => CTA_TIMEOUT_TCP_TIME_WAIT,
+            TcpTimeout::Close(_) => CTA_TIMEOUT_TCP_CLOSE,
+            TcpTimeout::SynSent2(_) => CTA_TIMEOUT_TCP_SYN_SENT2,
+            TcpTimeout::Retrans(_) => CTA_TIMEOUT_TCP_RETRANS,
+            TcpTimeout::Unack(_) => CTA_TIMEOUT_TCP_UNACK,
+            TcpTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            TcpTimeout::SynSent(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::SynRecv(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::Established(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            TcpTimeout::FinWait(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::CloseWait(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::LastAck(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::TimeWait(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::Close(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::SynSent2(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::Retrans(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::Unack(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TcpTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for TcpTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_TCP_SYN_SENT => TcpTimeout::SynSent(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_SYN_SENT value")?,
+            ),
+            CTA_TIMEOUT_TCP_SYN_RECV => TcpTimeout::SynRecv(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_SYN_RECV value")?,
+            ),
+            CTA_TIMEOUT_TCP_ESTABLISHED => TcpTimeout::Established(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_ESTABLISHED value")?,
+            ),
+            CTA_TIMEOUT_TCP_FIN_WAIT => TcpTimeout::FinWait(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_FIN_WAIT value")?,
+            ),
+            CTA_TIMEOUT_TCP_CLOSE_WAIT => TcpTimeout::CloseWait(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_CLOSE_WAIT value")?,
+            ),
+            CTA_TIMEOUT_TCP_LAST_ACK => TcpTimeout::LastAck(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_LAST_ACK value")?,
+            ),
+            CTA_TIMEOUT_TCP_TIME_WAIT => TcpTimeout::TimeWait(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_TIME_WAIT value")?,
+            ),
+            CTA_TIMEOUT_TCP_CLOSE => TcpTimeout::Close(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_CLOSE value")?,
+            ),
+            CTA_TIMEOUT_TCP_SYN_SENT2 => TcpTimeout::SynSent2(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_SYN_SENT2 value")?,
+            ),
+            CTA_TIMEOUT_TCP_RETRANS => TcpTimeout::Retrans(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_RETRANS value")?,
+            ),
+            CTA_TIMEOUT_TCP_UNACK => TcpTimeout::Unack(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_TCP_UNACK value")?,
+            ),
+            _ => TcpTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const CTA_TIMEOUT_UDP_UNREPLIED: u16 = 1;
+const CTA_TIMEOUT_UDP_REPLIED: u16 = 2;
+
+// Timeouts in seconds of UDP and UDP-Lite connections, which share the same
+// attribute layout
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UdpTimeout {
+    Unreplied(u32),
+    Replied(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for UdpTimeout {
+    fn value_len(&self) -> usize {
+        match self {
+            UdpTimeout::Unreplied(attr) => size_of_val(attr),
+            UdpTimeout::Replied(attr) => size_of_val(attr),
+            UdpTimeout::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            UdpTimeout::Unreplied(_) => CTA_TIMEOUT_UDP_UNREPLIED,
+            UdpTimeout::Replied(_) => CTA_TIMEOUT_UDP_REPLIED,
+            UdpTimeout::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            UdpTimeout::Unreplied(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            UdpTimeout::Replied(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            UdpTimeout::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for UdpTimeout
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_TIMEOUT_UDP_UNREPLIED => UdpTimeout::Unreplied(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_UDP_UNREPLIED value")?,
+            ),
+            CTA_TIMEOUT_UDP_REPLIED => UdpTimeout::Replied(
+                parse_u32_be(payload)
+                    .context("invalid CTA_TIMEOUT_UDP_REPLIED value")?,
+            ),
+            _ => UdpTimeout::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    buffer::NetfilterBuffer,
+    cttimeout::attributes::{find_l4proto, TimeoutAttribute},
+};
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ParseableParametrized,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TimeoutMessage {
+    New(Vec<TimeoutAttribute>),
+    Get(Vec<TimeoutAttribute>),
+    Delete(Vec<TimeoutAttribute>),
+    DefaultSet(Vec<TimeoutAttribute>),
+    DefaultGet(Vec<TimeoutAttribute>),
+    Other {
+        message_type: u8,
+        attributes: Vec<DefaultNla>,
+    },
+}
+
+const IPCTNL_MSG_TIMEOUT_NEW: u8 = 0;
+const IPCTNL_MSG_TIMEOUT_GET: u8 = 1;
+const IPCTNL_MSG_TIMEOUT_DELETE: u8 = 2;
+const IPCTNL_MSG_TIMEOUT_DEFAULT_SET: u8 = 3;
+const IPCTNL_MSG_TIMEOUT_DEFAULT_GET: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutMessageType {
+    New,
+    Get,
+    Delete,
+    DefaultSet,
+    DefaultGet,
+    Other(u8),
+}
+
+impl From<u8> for TimeoutMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            IPCTNL_MSG_TIMEOUT_NEW => Self::New,
+            IPCTNL_MSG_TIMEOUT_GET => Self::Get,
+            IPCTNL_MSG_TIMEOUT_DELETE => Self::Delete,
+            IPCTNL_MSG_TIMEOUT_DEFAULT_SET => Self::DefaultSet,
+            IPCTNL_MSG_TIMEOUT_DEFAULT_GET => Self::DefaultGet,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<TimeoutMessageType> for u8 {
+    fn from(value: TimeoutMessageType) -> Self {
+        match value {
+            TimeoutMessageType::New => IPCTNL_MSG_TIMEOUT_NEW,
+            TimeoutMessageType::Get => IPCTNL_MSG_TIMEOUT_GET,
+            TimeoutMessageType::Delete => IPCTNL_MSG_TIMEOUT_DELETE,
+            TimeoutMessageType::DefaultSet => IPCTNL_MSG_TIMEOUT_DEFAULT_SET,
+            TimeoutMessageType::DefaultGet => IPCTNL_MSG_TIMEOUT_DEFAULT_GET,
+            TimeoutMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl TimeoutMessage {
+    pub fn message_type(&self) -> TimeoutMessageType {
+        match self {
+            TimeoutMessage::New(_) => TimeoutMessageType::New,
+            TimeoutMessage::Get(_) => TimeoutMessageType::Get,
+            TimeoutMessage::Delete(_) => TimeoutMessageType::Delete,
+            TimeoutMessage::DefaultSet(_) => TimeoutMessageType::DefaultSet,
+            TimeoutMessage::DefaultGet(_) => TimeoutMessageType::DefaultGet,
+            TimeoutMessage::Other { message_type, .. } => {
+                (*message_type).into()
+            }
+        }
+    }
+}
+
+impl Emitable for TimeoutMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            TimeoutMessage::New(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            TimeoutMessage::Get(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            TimeoutMessage::Delete(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            TimeoutMessage::DefaultSet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            TimeoutMessage::DefaultGet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            TimeoutMessage::Other { attributes, .. } => {
+                attributes.as_slice().buffer_len()
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            TimeoutMessage::New(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            TimeoutMessage::Get(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            TimeoutMessage::Delete(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            TimeoutMessage::DefaultSet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            TimeoutMessage::DefaultGet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            TimeoutMessage::Other { attributes, .. } => {
+                attributes.as_slice().emit(buffer)
+            }
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for TimeoutMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        let protocol = find_l4proto(buf.nlas())?;
+        Ok(match TimeoutMessageType::from(message_type) {
+            TimeoutMessageType::New => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TimeoutAttribute::parse_with_param(&nla_buf, protocol)
+                })?;
+                TimeoutMessage::New(attributes)
+            }
+            TimeoutMessageType::Get => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TimeoutAttribute::parse_with_param(&nla_buf, protocol)
+                })?;
+                TimeoutMessage::Get(attributes)
+            }
+            TimeoutMessageType::Delete => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TimeoutAttribute::parse_with_param(&nla_buf, protocol)
+                })?;
+                TimeoutMessage::Delete(attributes)
+            }
+            TimeoutMessageType::DefaultSet => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TimeoutAttribute::parse_with_param(&nla_buf, protocol)
+                })?;
+                TimeoutMessage::DefaultSet(attributes)
+            }
+            TimeoutMessageType::DefaultGet => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TimeoutAttribute::parse_with_param(&nla_buf, protocol)
+                })?;
+                TimeoutMessage::DefaultGet(attributes)
+            }
+            TimeoutMessageType::Other(message_type) => TimeoutMessage::Other {
+                message_type,
+                attributes: buf.default_nlas()?,
+            },
+        })
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{TimeoutMessage, TimeoutMessageType};
+mod attributes;
+pub use attributes::{
+    DccpTimeout, GenericTimeout, GreTimeout, SctpTimeout, TcpTimeout,
+    TimeoutAttribute, TimeoutData, UdpTimeout,
+};
@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_string, parse_u16_be, parse_u32_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::{
+    conntrack::Tuple,
+    expect::attributes::{flags::ExpectFlags, nat::ExpectNat},
+};
+
+const CTA_EXPECT_MASTER: u16 = 1;
+const CTA_EXPECT_TUPLE: u16 = 2;
+const CTA_EXPECT_MASK: u16 = 3;
+const CTA_EXPECT_TIMEOUT: u16 = 4;
+const CTA_EXPECT_ID: u16 = 5;
+const CTA_EXPECT_HELP_NAME: u16 = 6;
+const CTA_EXPECT_ZONE: u16 = 7;
+const CTA_EXPECT_FLAGS: u16 = 8;
+const CTA_EXPECT_CLASS: u16 = 9;
+const CTA_EXPECT_NAT: u16 = 10;
+const CTA_EXPECT_FN: u16 = 11;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpectAttribute {
+    // Original tuple of the connection which created the expectation
+    Master(Vec<Tuple>),
+    Tuple(Vec<Tuple>),
+    Mask(Vec<Tuple>),
+    Timeout(u32),
+    Id(u32),
+    HelpName(String),
+    Zone(u16),
+    Flags(ExpectFlags),
+    Class(u32),
+    Nat(Vec<ExpectNat>),
+    // Name of the function called when the expectation is matched, e.g.
+    // "nat-follow-master"
+    Fn(String),
+    Other(DefaultNla),
+}
+
+impl Nla for ExpectAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ExpectAttribute::Master(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ExpectAttribute::Tuple(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ExpectAttribute::Mask(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ExpectAttribute::Timeout(attr) => size_of_val(attr),
+            ExpectAttribute::Id(attr) => size_of_val(attr),
+            ExpectAttribute::HelpName(attr) => attr.len() + 1,
+            ExpectAttribute::Zone(attr) => size_of_val(attr),
+            ExpectAttribute::Flags(_) => size_of::<u32>(),
+            ExpectAttribute::Class(attr) => size_of_val(attr),
+            ExpectAttribute::Nat(attr) => {
+                attr.iter().map(|op| op.buffer_len()).sum()
+            }
+            ExpectAttribute::Fn(attr) => attr.len() + 1,
+            ExpectAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ExpectAttribute::Master(_) => CTA_EXPECT_MASTER,
+            ExpectAttribute::Tuple(_) => CTA_EXPECT_TUPLE,
+            ExpectAttribute::Mask(_) => CTA_EXPECT_MASK,
+            ExpectAttribute::Timeout(_) => CTA_EXPECT_TIMEOUT,
+            ExpectAttribute::Id(_) => CTA_EXPECT_ID,
+            ExpectAttribute::HelpName(_) => CTA_EXPECT_HELP_NAME,
+            ExpectAttribute::Zone(_) => CTA_EXPECT_ZONE,
+            ExpectAttribute::Flags(_) => CTA_EXPECT_FLAGS,
+            ExpectAttribute::Class(_) => CTA_EXPECT_CLASS,
+            ExpectAttribute::Nat(_) => CTA_EXPECT_NAT,
+            ExpectAttribute::Fn(_) => CTA_EXPECT_FN,
+            ExpectAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ExpectAttribute::Master(attr)
+            | ExpectAttribute::Tuple(attr)
+            | ExpectAttribute::Mask(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ExpectAttribute::Timeout(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ExpectAttribute::Id(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ExpectAttribute::HelpName(attr) | ExpectAttribute::Fn(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ExpectAttribute::Zone(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            ExpectAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            ExpectAttribute::Class(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ExpectAttribute::Nat(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ExpectAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            ExpectAttribute::Master(_)
+                | ExpectAttribute::Tuple(_)
+                | ExpectAttribute::Mask(_)
+                | ExpectAttribute::Nat(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ExpectAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_EXPECT_MASTER => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_EXPECT_MASTER value")?;
+                    tuples.push(Tuple::parse(nlas)?);
+                }
+                ExpectAttribute::Master(tuples)
+            }
+            CTA_EXPECT_TUPLE => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_EXPECT_TUPLE value")?;
+                    tuples.push(Tuple::parse(nlas)?);
+                }
+                ExpectAttribute::Tuple(tuples)
+            }
+            CTA_EXPECT_MASK => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_EXPECT_MASK value")?;
+                    tuples.push(Tuple::parse(nlas)?);
+                }
+                ExpectAttribute::Mask(tuples)
+            }
+            CTA_EXPECT_TIMEOUT => ExpectAttribute::Timeout(
+                parse_u32_be(payload)
+                    .context("invalid CTA_EXPECT_TIMEOUT value")?,
+            ),
+            CTA_EXPECT_ID => ExpectAttribute::Id(
+                parse_u32_be(payload).context("invalid CTA_EXPECT_ID value")?,
+            ),
+            CTA_EXPECT_HELP_NAME => ExpectAttribute::HelpName(
+                parse_string(payload)
+                    .context("invalid CTA_EXPECT_HELP_NAME value")?,
+            ),
+            CTA_EXPECT_ZONE => ExpectAttribute::Zone(
+                parse_u16_be(payload)
+                    .context("invalid CTA_EXPECT_ZONE value")?,
+            ),
+            CTA_EXPECT_FLAGS => {
+                ExpectAttribute::Flags(ExpectFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid CTA_EXPECT_FLAGS value")?,
+                ))
+            }
+            CTA_EXPECT_CLASS => ExpectAttribute::Class(
+                parse_u32_be(payload)
+                    .context("invalid CTA_EXPECT_CLASS value")?,
+            ),
+            CTA_EXPECT_NAT => {
+                let mut nats = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid CTA_EXPECT_NAT value")?;
+                    nats.push(ExpectNat::parse(nlas)?);
+                }
+                ExpectAttribute::Nat(nats)
+            }
+            CTA_EXPECT_FN => ExpectAttribute::Fn(
+                parse_string(payload).context("invalid CTA_EXPECT_FN value")?,
+            ),
+            _ => ExpectAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
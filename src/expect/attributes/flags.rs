@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+
+// Expectation flags from uapi/linux/netfilter/nf_conntrack_common.h
+const NF_CT_EXPECT_PERMANENT: u32 = 1;
+const NF_CT_EXPECT_INACTIVE: u32 = 1 << 1;
+const NF_CT_EXPECT_USERSPACE: u32 = 1 << 2;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ExpectFlags: u32 {
+        const Permanent = NF_CT_EXPECT_PERMANENT;
+        const Inactive  = NF_CT_EXPECT_INACTIVE;
+        const Userspace = NF_CT_EXPECT_USERSPACE;
+        const _ = !0;
+    }
+}
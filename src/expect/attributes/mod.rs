@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod flags;
+mod nat;
+
+pub use attribute::ExpectAttribute;
+pub use flags::ExpectFlags;
+pub use nat::{ExpectDirection, ExpectNat};
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::conntrack::Tuple;
+
+const CTA_EXPECT_NAT_DIR: u16 = 1;
+const CTA_EXPECT_NAT_TUPLE: u16 = 2;
+
+const IP_CT_DIR_ORIGINAL: u32 = 0;
+const IP_CT_DIR_REPLY: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpectDirection {
+    Original,
+    Reply,
+    Other(u32),
+}
+
+impl From<ExpectDirection> for u32 {
+    fn from(direction: ExpectDirection) -> Self {
+        match direction {
+            ExpectDirection::Original => IP_CT_DIR_ORIGINAL,
+            ExpectDirection::Reply => IP_CT_DIR_REPLY,
+            ExpectDirection::Other(d) => d,
+        }
+    }
+}
+
+impl From<u32> for ExpectDirection {
+    fn from(direction: u32) -> Self {
+        match direction {
+            IP_CT_DIR_ORIGINAL => ExpectDirection::Original,
+            IP_CT_DIR_REPLY => ExpectDirection::Reply,
+            _ => ExpectDirection::Other(direction),
+        }
+    }
+}
+
+// NAT setup of the connection created from the expectation, used by helpers
+// such as SIP whose expected connection is NATed differently from its master
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpectNat {
+    Dir(ExpectDirection),
+    Tuple(Vec<Tuple>),
+    Other(DefaultNla),
+}
+
+impl Nla for ExpectNat {
+    fn value_len(&self) -> usize {
+        match self {
+            ExpectNat::Dir(_) => size_of::<u32>(),
+            ExpectNat::Tuple(nlas) => {
+                nlas.iter().map(|op| op.buffer_len()).sum()
+            }
+            ExpectNat::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ExpectNat::Dir(_) => CTA_EXPECT_NAT_DIR,
+            ExpectNat::Tuple(_) => CTA_EXPECT_NAT_TUPLE,
+            ExpectNat::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ExpectNat::Dir(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ExpectNat::Tuple(attr) => {
+                let mut len = 0;
+                for op in attr {
+                    op.emit(&mut buffer[len..]);
+                    len += op.buffer_len();
+                }
+            }
+            ExpectNat::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ExpectNat::Tuple(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ExpectNat
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            CTA_EXPECT_NAT_DIR => ExpectNat::Dir(
+                parse_u32_be(payload)
+                    .context("invalid CTA_EXPECT_NAT_DIR value")?
+                    .into(),
+            ),
+            CTA_EXPECT_NAT_TUPLE => {
+                let mut tuples = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid CTA_EXPECT_NAT_TUPLE value")?;
+                    tuples.push(Tuple::parse(nlas)?);
+                }
+                ExpectNat::Tuple(tuples)
+            }
+            _ => ExpectNat::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{buffer::NetfilterBuffer, expect::attributes::ExpectAttribute};
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum ExpectMessage {
+    New(Vec<ExpectAttribute>),
+    Get(Vec<ExpectAttribute>),
+    Delete(Vec<ExpectAttribute>),
+    Other {
+        message_type: u8,
+        attributes: Vec<DefaultNla>,
+    },
+}
+
+const IPCTNL_MSG_EXP_NEW: u8 = 0;
+const IPCTNL_MSG_EXP_GET: u8 = 1;
+const IPCTNL_MSG_EXP_DELETE: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExpectMessageType {
+    New,
+    Get,
+    Delete,
+    Other(u8),
+}
+
+impl From<u8> for ExpectMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            IPCTNL_MSG_EXP_NEW => Self::New,
+            IPCTNL_MSG_EXP_GET => Self::Get,
+            IPCTNL_MSG_EXP_DELETE => Self::Delete,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<ExpectMessageType> for u8 {
+    fn from(value: ExpectMessageType) -> Self {
+        match value {
+            ExpectMessageType::New => IPCTNL_MSG_EXP_NEW,
+            ExpectMessageType::Get => IPCTNL_MSG_EXP_GET,
+            ExpectMessageType::Delete => IPCTNL_MSG_EXP_DELETE,
+            ExpectMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl ExpectMessage {
+    pub fn message_type(&self) -> ExpectMessageType {
+        match self {
+            ExpectMessage::New(_) => ExpectMessageType::New,
+            ExpectMessage::Get(_) => ExpectMessageType::Get,
+            ExpectMessage::Delete(_) => ExpectMessageType::Delete,
+            ExpectMessage::Other { message_type, .. } => (*message_type).into(),
+        }
+    }
+}
+
+impl Emitable for ExpectMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            ExpectMessage::New(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ExpectMessage::Get(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ExpectMessage::Delete(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            ExpectMessage::Other { attributes, .. } => {
+                attributes.as_slice().buffer_len()
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            ExpectMessage::New(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ExpectMessage::Get(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ExpectMessage::Delete(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            ExpectMessage::Other { attributes, .. } => {
+                attributes.as_slice().emit(buffer)
+            }
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for ExpectMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        Ok(match ExpectMessageType::from(message_type) {
+            ExpectMessageType::New => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ExpectAttribute::parse(&nla_buf)
+                })?;
+                ExpectMessage::New(attributes)
+            }
+            ExpectMessageType::Get => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ExpectAttribute::parse(&nla_buf)
+                })?;
+                ExpectMessage::Get(attributes)
+            }
+            ExpectMessageType::Delete => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ExpectAttribute::parse(&nla_buf)
+                })?;
+                ExpectMessage::Delete(attributes)
+            }
+            ExpectMessageType::Other(message_type) => ExpectMessage::Other {
+                message_type,
+                attributes: buf.default_nlas()?,
+            },
+        })
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{ExpectMessage, ExpectMessageType};
+mod attributes;
+pub use attributes::{
+    ExpectAttribute, ExpectDirection, ExpectFlags, ExpectNat,
+};
@@ -8,6 +8,12 @@ pub use message::{
     Subsystem,
 };
 pub mod conntrack;
+pub mod cthelper;
+pub mod cttimeout;
+pub mod expect;
+pub mod nfacct;
 pub mod nflog;
+pub mod nfqueue;
+pub mod nftables;
 #[cfg(test)]
 mod tests;
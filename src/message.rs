@@ -7,7 +7,19 @@ use netlink_packet_core::{
 };
 
 use crate::{
-    buffer::NetfilterBuffer, conntrack::ConntrackMessage, nflog::ULogMessage,
+    buffer::NetfilterBuffer,
+    conntrack::ConntrackMessage,
+    constants::{
+        NFNL_SUBSYS_ACCT, NFNL_SUBSYS_CTHELPER, NFNL_SUBSYS_CTNETLINK_EXP,
+        NFNL_SUBSYS_CTNETLINK_TIMEOUT, NFNL_SUBSYS_NFTABLES, NFNL_SUBSYS_QUEUE,
+    },
+    cthelper::HelperMessage,
+    cttimeout::TimeoutMessage,
+    expect::ExpectMessage,
+    nfacct::AcctMessage,
+    nflog::ULogMessage,
+    nfqueue::QueueMessage,
+    nftables::NftMessage,
 };
 
 // ProtoFamily represents a protocol family in the Netfilter header (nfgenmsg).
@@ -125,6 +137,12 @@ pub const NFNL_SUBSYS_ULOG: u8 = 4;
 pub enum Subsystem {
     ULog,
     Conntrack,
+    ConntrackExpect,
+    ConntrackTimeout,
+    ConntrackHelper,
+    Acct,
+    Queue,
+    Nftables,
     Other(u8),
 }
 
@@ -133,6 +151,12 @@ impl From<u8> for Subsystem {
         match value {
             NFNL_SUBSYS_ULOG => Self::ULog,
             NFNL_SUBSYS_CTNETLINK => Self::Conntrack,
+            NFNL_SUBSYS_CTNETLINK_EXP => Self::ConntrackExpect,
+            NFNL_SUBSYS_CTNETLINK_TIMEOUT => Self::ConntrackTimeout,
+            NFNL_SUBSYS_CTHELPER => Self::ConntrackHelper,
+            NFNL_SUBSYS_ACCT => Self::Acct,
+            NFNL_SUBSYS_QUEUE => Self::Queue,
+            NFNL_SUBSYS_NFTABLES => Self::Nftables,
             v => Self::Other(v),
         }
     }
@@ -143,6 +167,12 @@ impl From<Subsystem> for u8 {
         match value {
             Subsystem::ULog => NFNL_SUBSYS_ULOG,
             Subsystem::Conntrack => NFNL_SUBSYS_CTNETLINK,
+            Subsystem::ConntrackExpect => NFNL_SUBSYS_CTNETLINK_EXP,
+            Subsystem::ConntrackTimeout => NFNL_SUBSYS_CTNETLINK_TIMEOUT,
+            Subsystem::ConntrackHelper => NFNL_SUBSYS_CTHELPER,
+            Subsystem::Acct => NFNL_SUBSYS_ACCT,
+            Subsystem::Queue => NFNL_SUBSYS_QUEUE,
+            Subsystem::Nftables => NFNL_SUBSYS_NFTABLES,
             Subsystem::Other(v) => v,
         }
     }
@@ -153,6 +183,12 @@ impl From<Subsystem> for u8 {
 pub enum NetfilterMessageInner {
     ULog(ULogMessage),
     Conntrack(ConntrackMessage),
+    ConntrackExpect(ExpectMessage),
+    ConntrackTimeout(TimeoutMessage),
+    ConntrackHelper(HelperMessage),
+    Acct(AcctMessage),
+    Queue(QueueMessage),
+    Nftables(NftMessage),
     Other {
         subsys: Subsystem,
         message_type: u8,
@@ -170,12 +206,54 @@ impl From<ConntrackMessage> for NetfilterMessageInner {
         Self::Conntrack(message)
     }
 }
+impl From<ExpectMessage> for NetfilterMessageInner {
+    fn from(message: ExpectMessage) -> Self {
+        Self::ConntrackExpect(message)
+    }
+}
+impl From<TimeoutMessage> for NetfilterMessageInner {
+    fn from(message: TimeoutMessage) -> Self {
+        Self::ConntrackTimeout(message)
+    }
+}
+impl From<HelperMessage> for NetfilterMessageInner {
+    fn from(message: HelperMessage) -> Self {
+        Self::ConntrackHelper(message)
+    }
+}
+impl From<AcctMessage> for NetfilterMessageInner {
+    fn from(message: AcctMessage) -> Self {
+        Self::Acct(message)
+    }
+}
+impl From<QueueMessage> for NetfilterMessageInner {
+    fn from(message: QueueMessage) -> Self {
+        Self::Queue(message)
+    }
+}
+impl From<NftMessage> for NetfilterMessageInner {
+    fn from(message: NftMessage) -> Self {
+        Self::Nftables(message)
+    }
+}
 
 impl Emitable for NetfilterMessageInner {
     fn buffer_len(&self) -> usize {
         match self {
             NetfilterMessageInner::ULog(message) => message.buffer_len(),
             NetfilterMessageInner::Conntrack(message) => message.buffer_len(),
+            NetfilterMessageInner::ConntrackExpect(message) => {
+                message.buffer_len()
+            }
+            NetfilterMessageInner::ConntrackTimeout(message) => {
+                message.buffer_len()
+            }
+            NetfilterMessageInner::ConntrackHelper(message) => {
+                message.buffer_len()
+            }
+            NetfilterMessageInner::Acct(message) => message.buffer_len(),
+            NetfilterMessageInner::Queue(message) => message.buffer_len(),
+            NetfilterMessageInner::Nftables(message) => message.buffer_len(),
             NetfilterMessageInner::Other { attributes, .. } => {
                 attributes.as_slice().buffer_len()
             }
@@ -186,6 +264,18 @@ impl Emitable for NetfilterMessageInner {
         match self {
             NetfilterMessageInner::ULog(message) => message.emit(buffer),
             NetfilterMessageInner::Conntrack(message) => message.emit(buffer),
+            NetfilterMessageInner::ConntrackExpect(message) => {
+                message.emit(buffer)
+            }
+            NetfilterMessageInner::ConntrackTimeout(message) => {
+                message.emit(buffer)
+            }
+            NetfilterMessageInner::ConntrackHelper(message) => {
+                message.emit(buffer)
+            }
+            NetfilterMessageInner::Acct(message) => message.emit(buffer),
+            NetfilterMessageInner::Queue(message) => message.emit(buffer),
+            NetfilterMessageInner::Nftables(message) => message.emit(buffer),
             NetfilterMessageInner::Other { attributes, .. } => {
                 attributes.as_slice().emit(buffer)
             }
@@ -215,6 +305,18 @@ impl NetfilterMessage {
         match self.inner {
             NetfilterMessageInner::ULog(_) => Subsystem::ULog,
             NetfilterMessageInner::Conntrack(_) => Subsystem::Conntrack,
+            NetfilterMessageInner::ConntrackExpect(_) => {
+                Subsystem::ConntrackExpect
+            }
+            NetfilterMessageInner::ConntrackTimeout(_) => {
+                Subsystem::ConntrackTimeout
+            }
+            NetfilterMessageInner::ConntrackHelper(_) => {
+                Subsystem::ConntrackHelper
+            }
+            NetfilterMessageInner::Acct(_) => Subsystem::Acct,
+            NetfilterMessageInner::Queue(_) => Subsystem::Queue,
+            NetfilterMessageInner::Nftables(_) => Subsystem::Nftables,
             NetfilterMessageInner::Other { subsys, .. } => subsys,
         }
     }
@@ -227,6 +329,24 @@ impl NetfilterMessage {
             NetfilterMessageInner::Conntrack(ref message) => {
                 message.message_type().into()
             }
+            NetfilterMessageInner::ConntrackExpect(ref message) => {
+                message.message_type().into()
+            }
+            NetfilterMessageInner::ConntrackTimeout(ref message) => {
+                message.message_type().into()
+            }
+            NetfilterMessageInner::ConntrackHelper(ref message) => {
+                message.message_type().into()
+            }
+            NetfilterMessageInner::Acct(ref message) => {
+                message.message_type().into()
+            }
+            NetfilterMessageInner::Queue(ref message) => {
+                message.message_type().into()
+            }
+            NetfilterMessageInner::Nftables(ref message) => {
+                message.message_type().into()
+            }
             NetfilterMessageInner::Other { message_type, .. } => message_type,
         }
     }
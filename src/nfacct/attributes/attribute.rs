@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::nfacct::attributes::{filter::AcctFilter, flags::AcctFlags};
+
+const NFACCT_NAME: u16 = 1;
+const NFACCT_PKTS: u16 = 2;
+const NFACCT_BYTES: u16 = 3;
+const NFACCT_USE: u16 = 4;
+const NFACCT_FLAGS: u16 = 5;
+const NFACCT_QUOTA: u16 = 6;
+const NFACCT_FILTER: u16 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcctAttribute {
+    Name(String),
+    Pkts(u64),
+    Bytes(u64),
+    Use(u32),
+    Flags(AcctFlags),
+    // Packets or bytes quota, depending on the AcctFlags::Quota* flag set
+    Quota(u64),
+    Filter(Vec<AcctFilter>),
+    Other(DefaultNla),
+}
+
+impl Nla for AcctAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            AcctAttribute::Name(attr) => attr.len() + 1,
+            AcctAttribute::Pkts(attr) => size_of_val(attr),
+            AcctAttribute::Bytes(attr) => size_of_val(attr),
+            AcctAttribute::Use(attr) => size_of_val(attr),
+            AcctAttribute::Flags(_) => size_of::<u32>(),
+            AcctAttribute::Quota(attr) => size_of_val(attr),
+            AcctAttribute::Filter(attr) => attr.as_slice().buffer_len(),
+            AcctAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            AcctAttribute::Name(_) => NFACCT_NAME,
+            AcctAttribute::Pkts(_) => NFACCT_PKTS,
+            AcctAttribute::Bytes(_) => NFACCT_BYTES,
+            AcctAttribute::Use(_) => NFACCT_USE,
+            AcctAttribute::Flags(_) => NFACCT_FLAGS,
+            AcctAttribute::Quota(_) => NFACCT_QUOTA,
+            AcctAttribute::Filter(_) => NFACCT_FILTER,
+            AcctAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            AcctAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            AcctAttribute::Pkts(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            AcctAttribute::Bytes(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            AcctAttribute::Use(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            AcctAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            AcctAttribute::Quota(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            AcctAttribute::Filter(attr) => attr.as_slice().emit(buffer),
+            AcctAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, AcctAttribute::Filter(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for AcctAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFACCT_NAME => AcctAttribute::Name(
+                parse_string(payload).context("invalid NFACCT_NAME value")?,
+            ),
+            NFACCT_PKTS => AcctAttribute::Pkts(
+                parse_u64_be(payload).context("invalid NFACCT_PKTS value")?,
+            ),
+            NFACCT_BYTES => AcctAttribute::Bytes(
+                parse_u64_be(payload).context("invalid NFACCT_BYTES value")?,
+            ),
+            NFACCT_USE => AcctAttribute::Use(
+                parse_u32_be(payload).context("invalid NFACCT_USE value")?,
+            ),
+            NFACCT_FLAGS => AcctAttribute::Flags(AcctFlags::from_bits_retain(
+                parse_u32_be(payload).context("invalid NFACCT_FLAGS value")?,
+            )),
+            NFACCT_QUOTA => AcctAttribute::Quota(
+                parse_u64_be(payload).context("invalid NFACCT_QUOTA value")?,
+            ),
+            NFACCT_FILTER => {
+                let mut filters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFACCT_FILTER value")?;
+                    filters.push(AcctFilter::parse(nlas)?);
+                }
+                AcctAttribute::Filter(filters)
+            }
+            _ => AcctAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nfacct::attributes::flags::AcctFlags;
+
+const NFACCT_FILTER_MASK: u16 = 1;
+const NFACCT_FILTER_VALUE: u16 = 2;
+
+// Restricts a dump to the objects whose flags match `flags & mask == value`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcctFilter {
+    Mask(AcctFlags),
+    Value(AcctFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for AcctFilter {
+    fn value_len(&self) -> usize {
+        match self {
+            AcctFilter::Mask(_) => size_of::<u32>(),
+            AcctFilter::Value(_) => size_of::<u32>(),
+            AcctFilter::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            AcctFilter::Mask(_) => NFACCT_FILTER_MASK,
+            AcctFilter::Value(_) => NFACCT_FILTER_VALUE,
+            AcctFilter::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            AcctFilter::Mask(attr) => emit_u32_be(buffer, attr.bits()).unwrap(),
+            AcctFilter::Value(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            AcctFilter::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for AcctFilter
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFACCT_FILTER_MASK => {
+                AcctFilter::Mask(AcctFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFACCT_FILTER_MASK value")?,
+                ))
+            }
+            NFACCT_FILTER_VALUE => {
+                AcctFilter::Value(AcctFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFACCT_FILTER_VALUE value")?,
+                ))
+            }
+            _ => AcctFilter::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+
+const NFACCT_F_QUOTA_PKTS: u32 = 1;
+const NFACCT_F_QUOTA_BYTES: u32 = 1 << 1;
+const NFACCT_F_OVERQUOTA: u32 = 1 << 2;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct AcctFlags: u32 {
+        const QuotaPkts  = NFACCT_F_QUOTA_PKTS;
+        const QuotaBytes = NFACCT_F_QUOTA_BYTES;
+        // Set by the kernel once the quota is reached, can't be set from
+        // userspace
+        const OverQuota  = NFACCT_F_OVERQUOTA;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod filter;
+mod flags;
+
+pub use attribute::AcctAttribute;
+pub use filter::AcctFilter;
+pub use flags::AcctFlags;
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{buffer::NetfilterBuffer, nfacct::attributes::AcctAttribute};
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum AcctMessage {
+    New(Vec<AcctAttribute>),
+    Get(Vec<AcctAttribute>),
+    GetCtrZero(Vec<AcctAttribute>),
+    Delete(Vec<AcctAttribute>),
+    // Sent by the kernel to the NFNLGRP_ACCT_QUOTA multicast group when an
+    // object reaches its quota
+    OverQuota(Vec<AcctAttribute>),
+    Other {
+        message_type: u8,
+        attributes: Vec<DefaultNla>,
+    },
+}
+
+const NFNL_MSG_ACCT_NEW: u8 = 0;
+const NFNL_MSG_ACCT_GET: u8 = 1;
+const NFNL_MSG_ACCT_GET_CTRZERO: u8 = 2;
+const NFNL_MSG_ACCT_DEL: u8 = 3;
+const NFNL_MSG_ACCT_OVERQUOTA: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcctMessageType {
+    New,
+    Get,
+    GetCtrZero,
+    Delete,
+    OverQuota,
+    Other(u8),
+}
+
+impl From<u8> for AcctMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            NFNL_MSG_ACCT_NEW => Self::New,
+            NFNL_MSG_ACCT_GET => Self::Get,
+            NFNL_MSG_ACCT_GET_CTRZERO => Self::GetCtrZero,
+            NFNL_MSG_ACCT_DEL => Self::Delete,
+            NFNL_MSG_ACCT_OVERQUOTA => Self::OverQuota,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<AcctMessageType> for u8 {
+    fn from(value: AcctMessageType) -> Self {
+        match value {
+            AcctMessageType::New => NFNL_MSG_ACCT_NEW,
+            AcctMessageType::Get => NFNL_MSG_ACCT_GET,
+            AcctMessageType::GetCtrZero => NFNL_MSG_ACCT_GET_CTRZERO,
+            AcctMessageType::Delete => NFNL_MSG_ACCT_DEL,
+            AcctMessageType::OverQuota => NFNL_MSG_ACCT_OVERQUOTA,
+            AcctMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl AcctMessage {
+    pub fn message_type(&self) -> AcctMessageType {
+        match self {
+            AcctMessage::New(_) => AcctMessageType::New,
+            AcctMessage::Get(_) => AcctMessageType::Get,
+            AcctMessage::GetCtrZero(_) => AcctMessageType::GetCtrZero,
+            AcctMessage::Delete(_) => AcctMessageType::Delete,
+            AcctMessage::OverQuota(_) => AcctMessageType::OverQuota,
+            AcctMessage::Other { message_type, .. } => (*message_type).into(),
+        }
+    }
+}
+
+impl Emitable for AcctMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            AcctMessage::New(attributes) => attributes.as_slice().buffer_len(),
+            AcctMessage::Get(attributes) => attributes.as_slice().buffer_len(),
+            AcctMessage::GetCtrZero(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            AcctMessage::Delete(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            AcctMessage::OverQuota(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            AcctMessage::Other { attributes, .. } => {
+                attributes.as_slice().buffer_len()
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            AcctMessage::New(attributes) => attributes.as_slice().emit(buffer),
+            AcctMessage::Get(attributes) => attributes.as_slice().emit(buffer),
+            AcctMessage::GetCtrZero(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            AcctMessage::Delete(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            AcctMessage::OverQuota(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            AcctMessage::Other { attributes, .. } => {
+                attributes.as_slice().emit(buffer)
+            }
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for AcctMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        Ok(match AcctMessageType::from(message_type) {
+            AcctMessageType::New => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| AcctAttribute::parse(&nla_buf))?;
+                AcctMessage::New(attributes)
+            }
+            AcctMessageType::Get => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| AcctAttribute::parse(&nla_buf))?;
+                AcctMessage::Get(attributes)
+            }
+            AcctMessageType::GetCtrZero => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| AcctAttribute::parse(&nla_buf))?;
+                AcctMessage::GetCtrZero(attributes)
+            }
+            AcctMessageType::Delete => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| AcctAttribute::parse(&nla_buf))?;
+                AcctMessage::Delete(attributes)
+            }
+            AcctMessageType::OverQuota => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| AcctAttribute::parse(&nla_buf))?;
+                AcctMessage::OverQuota(attributes)
+            }
+            AcctMessageType::Other(message_type) => AcctMessage::Other {
+                message_type,
+                attributes: buf.default_nlas()?,
+            },
+        })
+    }
+}
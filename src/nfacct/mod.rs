@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{AcctMessage, AcctMessageType};
+mod attributes;
+pub use attributes::{AcctAttribute, AcctFilter, AcctFlags};
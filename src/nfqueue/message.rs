@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
+};
+
+use crate::{
+    buffer::NetfilterBuffer,
+    nfqueue::nlas::{
+        config::ConfigNla, packet::PacketNla, verdict::VerdictNla,
+    },
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum QueueMessage {
+    Packet(Vec<PacketNla>),
+    Verdict(Vec<VerdictNla>),
+    Config(Vec<ConfigNla>),
+    VerdictBatch(Vec<VerdictNla>),
+    Other {
+        message_type: u8,
+        nlas: Vec<DefaultNla>,
+    },
+}
+
+const NFQNL_MSG_PACKET: u8 = libc::NFQNL_MSG_PACKET as u8;
+const NFQNL_MSG_VERDICT: u8 = libc::NFQNL_MSG_VERDICT as u8;
+const NFQNL_MSG_CONFIG: u8 = libc::NFQNL_MSG_CONFIG as u8;
+const NFQNL_MSG_VERDICT_BATCH: u8 = libc::NFQNL_MSG_VERDICT_BATCH as u8;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum QueueMessageType {
+    Packet,
+    Verdict,
+    Config,
+    VerdictBatch,
+    Other(u8),
+}
+
+impl From<u8> for QueueMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            NFQNL_MSG_PACKET => Self::Packet,
+            NFQNL_MSG_VERDICT => Self::Verdict,
+            NFQNL_MSG_CONFIG => Self::Config,
+            NFQNL_MSG_VERDICT_BATCH => Self::VerdictBatch,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<QueueMessageType> for u8 {
+    fn from(value: QueueMessageType) -> Self {
+        match value {
+            QueueMessageType::Packet => NFQNL_MSG_PACKET,
+            QueueMessageType::Verdict => NFQNL_MSG_VERDICT,
+            QueueMessageType::Config => NFQNL_MSG_CONFIG,
+            QueueMessageType::VerdictBatch => NFQNL_MSG_VERDICT_BATCH,
+            QueueMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl QueueMessage {
+    pub fn message_type(&self) -> QueueMessageType {
+        match self {
+            QueueMessage::Packet(_) => QueueMessageType::Packet,
+            QueueMessage::Verdict(_) => QueueMessageType::Verdict,
+            QueueMessage::Config(_) => QueueMessageType::Config,
+            QueueMessage::VerdictBatch(_) => QueueMessageType::VerdictBatch,
+            QueueMessage::Other { message_type, .. } => (*message_type).into(),
+        }
+    }
+}
+
+impl Emitable for QueueMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            QueueMessage::Packet(nlas) => nlas.as_slice().buffer_len(),
+            QueueMessage::Verdict(nlas) => nlas.as_slice().buffer_len(),
+            QueueMessage::Config(nlas) => nlas.as_slice().buffer_len(),
+            QueueMessage::VerdictBatch(nlas) => nlas.as_slice().buffer_len(),
+            QueueMessage::Other { nlas, .. } => nlas.as_slice().buffer_len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            QueueMessage::Packet(nlas) => nlas.as_slice().emit(buffer),
+            QueueMessage::Verdict(nlas) => nlas.as_slice().emit(buffer),
+            QueueMessage::Config(nlas) => nlas.as_slice().emit(buffer),
+            QueueMessage::VerdictBatch(nlas) => nlas.as_slice().emit(buffer),
+            QueueMessage::Other { nlas, .. } => nlas.as_slice().emit(buffer),
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for QueueMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        Ok(match QueueMessageType::from(message_type) {
+            QueueMessageType::Packet => {
+                let nlas =
+                    buf.parse_all_nlas(|nla_buf| PacketNla::parse(&nla_buf))?;
+                QueueMessage::Packet(nlas)
+            }
+            QueueMessageType::Verdict => {
+                let nlas =
+                    buf.parse_all_nlas(|nla_buf| VerdictNla::parse(&nla_buf))?;
+                QueueMessage::Verdict(nlas)
+            }
+            QueueMessageType::Config => {
+                let nlas =
+                    buf.parse_all_nlas(|nla_buf| ConfigNla::parse(&nla_buf))?;
+                QueueMessage::Config(nlas)
+            }
+            QueueMessageType::VerdictBatch => {
+                let nlas =
+                    buf.parse_all_nlas(|nla_buf| VerdictNla::parse(&nla_buf))?;
+                QueueMessage::VerdictBatch(nlas)
+            }
+            QueueMessageType::Other(message_type) => QueueMessage::Other {
+                message_type,
+                nlas: buf.default_nlas()?,
+            },
+        })
+    }
+}
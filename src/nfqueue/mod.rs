@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{QueueMessage, QueueMessageType};
+pub mod nlas;
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_REQUEST,
+};
+
+use crate::{
+    constants::NFNETLINK_V0,
+    message::ProtoFamily,
+    nfqueue::nlas::{
+        config::ConfigNla,
+        verdict::{Verdict, VerdictHdr, VerdictNla},
+    },
+    NetfilterHeader, NetfilterMessage,
+};
+
+// Configures `queue_num`, e.g. binds to it with a `ConfigNla::Cmd` holding
+// `ConfigCommand::Bind` then sets how much of the packets is copied with
+// `ConfigNla::Params`
+pub fn config_request(
+    queue_num: u16,
+    nlas: Vec<ConfigNla>,
+) -> NetlinkMessage<NetfilterMessage> {
+    let mut message = queue_request(queue_num, QueueMessage::Config(nlas));
+    message.header.flags |= NLM_F_ACK;
+    message
+}
+
+// Sets the verdict of the packet `packet_id` received on `queue_num`, more
+// attributes (e.g. `VerdictNla::Mark`) can be appended to `nlas`
+pub fn verdict_request(
+    queue_num: u16,
+    packet_id: u32,
+    verdict: Verdict,
+    nlas: Vec<VerdictNla>,
+) -> NetlinkMessage<NetfilterMessage> {
+    queue_request(
+        queue_num,
+        QueueMessage::Verdict(verdict_nlas(packet_id, verdict, nlas)),
+    )
+}
+
+// Sets the verdict of all the packets of `queue_num` up to `packet_id`
+pub fn verdict_batch_request(
+    queue_num: u16,
+    packet_id: u32,
+    verdict: Verdict,
+    nlas: Vec<VerdictNla>,
+) -> NetlinkMessage<NetfilterMessage> {
+    queue_request(
+        queue_num,
+        QueueMessage::VerdictBatch(verdict_nlas(packet_id, verdict, nlas)),
+    )
+}
+
+fn verdict_nlas(
+    packet_id: u32,
+    verdict: Verdict,
+    nlas: Vec<VerdictNla>,
+) -> Vec<VerdictNla> {
+    let mut verdict_nlas = vec![VerdictHdr::new(verdict, packet_id).into()];
+    verdict_nlas.extend(nlas);
+    verdict_nlas
+}
+
+fn queue_request(
+    queue_num: u16,
+    message: QueueMessage,
+) -> NetlinkMessage<NetfilterMessage> {
+    let mut hdr = NetlinkHeader::default();
+    hdr.flags = NLM_F_REQUEST;
+    let mut message = NetlinkMessage::new(
+        hdr,
+        NetlinkPayload::from(NetfilterMessage::new(
+            NetfilterHeader::new(ProtoFamily::Unspec, NFNETLINK_V0, queue_num),
+            message,
+        )),
+    );
+    message.finalize();
+    message
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    buffer, fields, getter, setter, DecodeError, Nla, Parseable,
+};
+
+use crate::{constants::NFQA_CFG_CMD, message::ProtoFamily};
+
+const NFQNL_CFG_CMD_NONE: u8 = libc::NFQNL_CFG_CMD_NONE as u8;
+const NFQNL_CFG_CMD_BIND: u8 = libc::NFQNL_CFG_CMD_BIND as u8;
+const NFQNL_CFG_CMD_UNBIND: u8 = libc::NFQNL_CFG_CMD_UNBIND as u8;
+const NFQNL_CFG_CMD_PF_BIND: u8 = libc::NFQNL_CFG_CMD_PF_BIND as u8;
+const NFQNL_CFG_CMD_PF_UNBIND: u8 = libc::NFQNL_CFG_CMD_PF_UNBIND as u8;
+
+const CONFIG_CMD_LEN: usize = 4;
+
+buffer!(ConfigCmdBuffer(CONFIG_CMD_LEN) {
+    command: (u8, 0),
+    pad: (u8, 1),
+    pf: (u16, 2..4),
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigCommand {
+    None,
+    Bind,
+    Unbind,
+    // PfBind and PfUnbind are no-ops since Linux 3.8
+    PfBind,
+    PfUnbind,
+    Other(u8),
+}
+
+impl From<ConfigCommand> for u8 {
+    fn from(cmd: ConfigCommand) -> Self {
+        match cmd {
+            ConfigCommand::None => NFQNL_CFG_CMD_NONE,
+            ConfigCommand::Bind => NFQNL_CFG_CMD_BIND,
+            ConfigCommand::Unbind => NFQNL_CFG_CMD_UNBIND,
+            ConfigCommand::PfBind => NFQNL_CFG_CMD_PF_BIND,
+            ConfigCommand::PfUnbind => NFQNL_CFG_CMD_PF_UNBIND,
+            ConfigCommand::Other(cmd) => cmd,
+        }
+    }
+}
+
+impl From<u8> for ConfigCommand {
+    fn from(cmd: u8) -> Self {
+        match cmd {
+            NFQNL_CFG_CMD_NONE => ConfigCommand::None,
+            NFQNL_CFG_CMD_BIND => ConfigCommand::Bind,
+            NFQNL_CFG_CMD_UNBIND => ConfigCommand::Unbind,
+            NFQNL_CFG_CMD_PF_BIND => ConfigCommand::PfBind,
+            NFQNL_CFG_CMD_PF_UNBIND => ConfigCommand::PfUnbind,
+            cmd => ConfigCommand::Other(cmd),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigCmd {
+    pub command: ConfigCommand,
+    pub family: ProtoFamily,
+}
+
+impl ConfigCmd {
+    pub fn new(command: ConfigCommand, family: ProtoFamily) -> Self {
+        Self { command, family }
+    }
+}
+
+impl Nla for ConfigCmd {
+    fn value_len(&self) -> usize {
+        CONFIG_CMD_LEN
+    }
+
+    fn kind(&self) -> u16 {
+        NFQA_CFG_CMD
+    }
+
+    fn emit_value(&self, buf: &mut [u8]) {
+        let mut buf = ConfigCmdBuffer::new(buf);
+        buf.set_command(self.command.into());
+        buf.set_pad(0);
+        buf.set_pf((u8::from(self.family) as u16).to_be())
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<ConfigCmdBuffer<T>> for ConfigCmd {
+    fn parse(buf: &ConfigCmdBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(ConfigCmd {
+            command: buf.command().into(),
+            family: (u16::from_be(buf.pf()) as u8).into(),
+        })
+    }
+}
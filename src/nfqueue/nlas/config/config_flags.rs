@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+
+const NFQA_CFG_F_FAIL_OPEN: u32 = libc::NFQA_CFG_F_FAIL_OPEN as u32;
+const NFQA_CFG_F_CONNTRACK: u32 = libc::NFQA_CFG_F_CONNTRACK as u32;
+const NFQA_CFG_F_GSO: u32 = libc::NFQA_CFG_F_GSO as u32;
+const NFQA_CFG_F_UID_GID: u32 = libc::NFQA_CFG_F_UID_GID as u32;
+const NFQA_CFG_F_SECCTX: u32 = libc::NFQA_CFG_F_SECCTX as u32;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ConfigFlags: u32 {
+        // Accepts the packets instead of dropping them when the queue is full
+        const FAIL_OPEN = NFQA_CFG_F_FAIL_OPEN;
+        const CONNTRACK = NFQA_CFG_F_CONNTRACK;
+        const GSO = NFQA_CFG_F_GSO;
+        const UID_GID = NFQA_CFG_F_UID_GID;
+        const SECCTX = NFQA_CFG_F_SECCTX;
+        const _ = !0;
+    }
+}
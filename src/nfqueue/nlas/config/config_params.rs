@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    buffer, fields, getter, setter, DecodeError, Nla, Parseable,
+};
+
+use crate::{constants::NFQA_CFG_PARAMS, nflog::nlas::config::CopyMode};
+
+// struct nfqnl_msg_config_params is packed
+const CONFIG_PARAMS_LEN: usize = 5;
+
+buffer!(ConfigParamsBuffer(CONFIG_PARAMS_LEN) {
+    copy_range: (u32, 0..4),
+    copy_mode: (u8, 4),
+});
+
+// The copy modes are the same as the nflog ones
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigParams {
+    pub copy_range: u32,
+    pub copy_mode: CopyMode,
+}
+
+impl ConfigParams {
+    pub fn new(copy_range: u32, copy_mode: CopyMode) -> Self {
+        Self {
+            copy_range,
+            copy_mode,
+        }
+    }
+
+    // Copies at most `copy_range` bytes of each packet, the kernel caps it
+    // to the maximum packet size
+    pub fn new_packet(copy_range: u32) -> Self {
+        Self::new(copy_range, CopyMode::Packet)
+    }
+}
+
+impl Nla for ConfigParams {
+    fn value_len(&self) -> usize {
+        CONFIG_PARAMS_LEN
+    }
+
+    fn kind(&self) -> u16 {
+        NFQA_CFG_PARAMS
+    }
+
+    fn emit_value(&self, buf: &mut [u8]) {
+        let mut buf = ConfigParamsBuffer::new(buf);
+        buf.set_copy_range(self.copy_range.to_be());
+        buf.set_copy_mode(self.copy_mode.into())
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<ConfigParamsBuffer<T>> for ConfigParams {
+    fn parse(buf: &ConfigParamsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(ConfigParams {
+            copy_range: u32::from_be(buf.copy_range()),
+            copy_mode: buf.copy_mode().into(),
+        })
+    }
+}
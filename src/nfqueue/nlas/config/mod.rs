@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod config_cmd;
+mod config_flags;
+mod config_params;
+mod nla;
+
+pub use config_cmd::{ConfigCmd, ConfigCmdBuffer, ConfigCommand};
+pub use config_flags::ConfigFlags;
+pub use config_params::{ConfigParams, ConfigParamsBuffer};
+pub use nla::ConfigNla;
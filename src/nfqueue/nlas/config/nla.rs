@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use derive_more::{From, IsVariant};
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::{
+    constants::{
+        NFQA_CFG_CMD, NFQA_CFG_FLAGS, NFQA_CFG_MASK, NFQA_CFG_PARAMS,
+        NFQA_CFG_QUEUE_MAXLEN,
+    },
+    nfqueue::nlas::config::{
+        config_cmd::ConfigCmdBuffer, config_params::ConfigParamsBuffer,
+        ConfigCmd, ConfigFlags, ConfigParams,
+    },
+};
+
+// The kernel only applies `Flags` along with a `Mask` selecting the flags to
+// change
+#[derive(Clone, Debug, PartialEq, Eq, From, IsVariant)]
+#[non_exhaustive]
+pub enum ConfigNla {
+    Cmd(ConfigCmd),
+    Params(ConfigParams),
+    #[from(ignore)]
+    QueueMaxLen(u32),
+    #[from(ignore)]
+    Mask(ConfigFlags),
+    #[from(ignore)]
+    Flags(ConfigFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for ConfigNla {
+    fn value_len(&self) -> usize {
+        match self {
+            ConfigNla::Cmd(attr) => attr.value_len(),
+            ConfigNla::Params(attr) => attr.value_len(),
+            ConfigNla::QueueMaxLen(_) => 4,
+            ConfigNla::Mask(_) => 4,
+            ConfigNla::Flags(_) => 4,
+            ConfigNla::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ConfigNla::Cmd(attr) => attr.kind(),
+            ConfigNla::Params(attr) => attr.kind(),
+            ConfigNla::QueueMaxLen(_) => NFQA_CFG_QUEUE_MAXLEN,
+            ConfigNla::Mask(_) => NFQA_CFG_MASK,
+            ConfigNla::Flags(_) => NFQA_CFG_FLAGS,
+            ConfigNla::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ConfigNla::Cmd(attr) => attr.emit_value(buffer),
+            ConfigNla::Params(attr) => attr.emit_value(buffer),
+            ConfigNla::QueueMaxLen(max_len) => {
+                emit_u32_be(buffer, *max_len).unwrap();
+            }
+            ConfigNla::Mask(mask) => {
+                emit_u32_be(buffer, mask.bits()).unwrap();
+            }
+            ConfigNla::Flags(flags) => {
+                emit_u32_be(buffer, flags.bits()).unwrap();
+            }
+            ConfigNla::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ConfigNla
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFQA_CFG_CMD => {
+                let buf = ConfigCmdBuffer::new_checked(payload)
+                    .context("invalid NFQA_CFG_CMD value")?;
+                ConfigCmd::parse(&buf)?.into()
+            }
+            NFQA_CFG_PARAMS => {
+                let buf = ConfigParamsBuffer::new_checked(payload)
+                    .context("invalid NFQA_CFG_PARAMS value")?;
+                ConfigParams::parse(&buf)?.into()
+            }
+            NFQA_CFG_QUEUE_MAXLEN => ConfigNla::QueueMaxLen(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_CFG_QUEUE_MAXLEN value")?,
+            ),
+            NFQA_CFG_MASK => ConfigNla::Mask(ConfigFlags::from_bits_retain(
+                parse_u32_be(payload).context("invalid NFQA_CFG_MASK value")?,
+            )),
+            NFQA_CFG_FLAGS => ConfigNla::Flags(ConfigFlags::from_bits_retain(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_CFG_FLAGS value")?,
+            )),
+            _ => ConfigNla::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+
+pub mod config;
+pub mod packet;
+pub mod verdict;
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    buffer, fields, getter, setter, DecodeError, Nla, Parseable,
+};
+
+use crate::constants::NFQA_HWADDR;
+
+const HW_ADDR_LEN: usize = 12;
+const HW_ADDR_MAX: usize = 8;
+
+buffer!(HwAddrBuffer(HW_ADDR_LEN) {
+    hw_addr_len: (u16, 0..2),
+    pad: (u16, 2..4),
+    hw_addr: (slice, 4..HW_ADDR_LEN),
+});
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HwAddr {
+    pub len: u16,
+    pub address: [u8; HW_ADDR_MAX],
+}
+
+impl HwAddr {
+    pub fn new(address: &[u8]) -> Self {
+        let len = address.len().min(HW_ADDR_MAX);
+        let mut hw_addr = Self {
+            len: len as u16,
+            address: [0; HW_ADDR_MAX],
+        };
+        hw_addr.address[..len].copy_from_slice(&address[..len]);
+        hw_addr
+    }
+
+    // The meaningful part of `address`
+    pub fn as_slice(&self) -> &[u8] {
+        &self.address[..(self.len as usize).min(HW_ADDR_MAX)]
+    }
+}
+
+impl Nla for HwAddr {
+    fn value_len(&self) -> usize {
+        HW_ADDR_LEN
+    }
+
+    fn kind(&self) -> u16 {
+        NFQA_HWADDR
+    }
+
+    fn emit_value(&self, buf: &mut [u8]) {
+        let mut buf = HwAddrBuffer::new(buf);
+        buf.set_hw_addr_len(self.len.to_be());
+        buf.set_pad(0);
+        buf.hw_addr_mut().copy_from_slice(&self.address);
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<HwAddrBuffer<&T>> for HwAddr {
+    fn parse(buf: &HwAddrBuffer<&T>) -> Result<Self, DecodeError> {
+        let mut address = [0; HW_ADDR_MAX];
+        address.copy_from_slice(buf.hw_addr());
+        Ok(HwAddr {
+            len: u16::from_be(buf.hw_addr_len()),
+            address,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod hw_addr;
+mod nla;
+mod packet_hdr;
+mod skb_info;
+mod timestamp;
+mod vlan;
+
+pub use hw_addr::{HwAddr, HwAddrBuffer};
+pub use nla::PacketNla;
+pub use packet_hdr::{PacketHdr, PacketHdrBuffer};
+pub use skb_info::SkbInfo;
+pub use timestamp::{TimeStamp, TimeStampBuffer};
+pub use vlan::VlanNla;
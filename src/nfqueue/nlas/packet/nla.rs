@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: MIT
+
+use derive_more::{From, IsVariant};
+use netlink_packet_core::{
+    emit_u32_be, parse_string, parse_u32_be, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::{
+    constants::{
+        NFQA_CAP_LEN, NFQA_GID, NFQA_HWADDR, NFQA_IFINDEX_INDEV,
+        NFQA_IFINDEX_OUTDEV, NFQA_IFINDEX_PHYSINDEV, NFQA_IFINDEX_PHYSOUTDEV,
+        NFQA_L2HDR, NFQA_MARK, NFQA_PACKET_HDR, NFQA_PAYLOAD, NFQA_PRIORITY,
+        NFQA_SECCTX, NFQA_SKB_INFO, NFQA_TIMESTAMP, NFQA_UID, NFQA_VLAN,
+    },
+    nfqueue::nlas::packet::{
+        hw_addr::{HwAddr, HwAddrBuffer},
+        packet_hdr::{PacketHdr, PacketHdrBuffer},
+        skb_info::SkbInfo,
+        timestamp::{TimeStamp, TimeStampBuffer},
+        vlan::VlanNla,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, From, IsVariant)]
+#[non_exhaustive]
+pub enum PacketNla {
+    #[from]
+    PacketHdr(PacketHdr),
+    Mark(u32),
+    #[from]
+    Timestamp(TimeStamp),
+    IfIndexInDev(u32),
+    IfIndexOutDev(u32),
+    IfIndexPhysInDev(u32),
+    IfIndexPhysOutDev(u32),
+    #[from]
+    HwAddr(HwAddr),
+    Payload(Vec<u8>),
+    // Length of the packet before it was truncated to the copy range
+    CapLen(u32),
+    #[from]
+    SkbInfo(SkbInfo),
+    Uid(u32),
+    Gid(u32),
+    SecCtx(String),
+    Vlan(Vec<VlanNla>),
+    L2Hdr(Vec<u8>),
+    Priority(u32),
+    #[from]
+    Other(DefaultNla),
+}
+
+impl Nla for PacketNla {
+    fn value_len(&self) -> usize {
+        match self {
+            PacketNla::PacketHdr(attr) => attr.value_len(),
+            PacketNla::Mark(_) => 4,
+            PacketNla::Timestamp(attr) => attr.value_len(),
+            PacketNla::IfIndexInDev(_) => 4,
+            PacketNla::IfIndexOutDev(_) => 4,
+            PacketNla::IfIndexPhysInDev(_) => 4,
+            PacketNla::IfIndexPhysOutDev(_) => 4,
+            PacketNla::HwAddr(attr) => attr.value_len(),
+            PacketNla::Payload(vec) => vec.len(),
+            PacketNla::CapLen(_) => 4,
+            PacketNla::SkbInfo(_) => 4,
+            PacketNla::Uid(_) => 4,
+            PacketNla::Gid(_) => 4,
+            PacketNla::SecCtx(secctx) => secctx.len() + 1,
+            PacketNla::Vlan(nlas) => nlas.as_slice().buffer_len(),
+            PacketNla::L2Hdr(vec) => vec.len(),
+            PacketNla::Priority(_) => 4,
+            PacketNla::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            PacketNla::PacketHdr(attr) => attr.kind(),
+            PacketNla::Mark(_) => NFQA_MARK,
+            PacketNla::Timestamp(attr) => attr.kind(),
+            PacketNla::IfIndexInDev(_) => NFQA_IFINDEX_INDEV,
+            PacketNla::IfIndexOutDev(_) => NFQA_IFINDEX_OUTDEV,
+            PacketNla::IfIndexPhysInDev(_) => NFQA_IFINDEX_PHYSINDEV,
+            PacketNla::IfIndexPhysOutDev(_) => NFQA_IFINDEX_PHYSOUTDEV,
+            PacketNla::HwAddr(attr) => attr.kind(),
+            PacketNla::Payload(_) => NFQA_PAYLOAD,
+            PacketNla::CapLen(_) => NFQA_CAP_LEN,
+            PacketNla::SkbInfo(_) => NFQA_SKB_INFO,
+            PacketNla::Uid(_) => NFQA_UID,
+            PacketNla::Gid(_) => NFQA_GID,
+            PacketNla::SecCtx(_) => NFQA_SECCTX,
+            PacketNla::Vlan(_) => NFQA_VLAN,
+            PacketNla::L2Hdr(_) => NFQA_L2HDR,
+            PacketNla::Priority(_) => NFQA_PRIORITY,
+            PacketNla::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            PacketNla::PacketHdr(attr) => attr.emit_value(buffer),
+            PacketNla::Mark(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::Timestamp(attr) => attr.emit_value(buffer),
+            PacketNla::IfIndexInDev(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::IfIndexOutDev(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::IfIndexPhysInDev(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::IfIndexPhysOutDev(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::HwAddr(attr) => attr.emit_value(buffer),
+            PacketNla::Payload(vec) => buffer.copy_from_slice(vec),
+            PacketNla::CapLen(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::SkbInfo(flags) => {
+                emit_u32_be(buffer, flags.bits()).unwrap();
+            }
+            PacketNla::Uid(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::Gid(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::SecCtx(secctx) => {
+                buffer[..secctx.len()].copy_from_slice(secctx.as_bytes());
+                buffer[secctx.len()] = 0;
+            }
+            PacketNla::Vlan(nlas) => nlas.as_slice().emit(buffer),
+            PacketNla::L2Hdr(vec) => buffer.copy_from_slice(vec),
+            PacketNla::Priority(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            PacketNla::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, PacketNla::Vlan(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for PacketNla
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFQA_PACKET_HDR => {
+                let buf = PacketHdrBuffer::new_checked(payload)
+                    .context("invalid NFQA_PACKET_HDR value")?;
+                PacketHdr::parse(&buf)?.into()
+            }
+            NFQA_MARK => PacketNla::Mark(
+                parse_u32_be(payload).context("invalid NFQA_MARK value")?,
+            ),
+            NFQA_TIMESTAMP => {
+                let buf = TimeStampBuffer::new_checked(&payload)
+                    .context("invalid NFQA_TIMESTAMP value")?;
+                PacketNla::Timestamp(TimeStamp::parse(&buf)?)
+            }
+            NFQA_IFINDEX_INDEV => PacketNla::IfIndexInDev(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_IFINDEX_INDEV value")?,
+            ),
+            NFQA_IFINDEX_OUTDEV => PacketNla::IfIndexOutDev(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_IFINDEX_OUTDEV value")?,
+            ),
+            NFQA_IFINDEX_PHYSINDEV => PacketNla::IfIndexPhysInDev(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_IFINDEX_PHYSINDEV value")?,
+            ),
+            NFQA_IFINDEX_PHYSOUTDEV => PacketNla::IfIndexPhysOutDev(
+                parse_u32_be(payload)
+                    .context("invalid NFQA_IFINDEX_PHYSOUTDEV value")?,
+            ),
+            NFQA_HWADDR => {
+                let buf = HwAddrBuffer::new_checked(payload)
+                    .context("invalid NFQA_HWADDR value")?;
+                PacketNla::HwAddr(HwAddr::parse(&buf)?)
+            }
+            NFQA_PAYLOAD => PacketNla::Payload(payload.to_vec()),
+            NFQA_CAP_LEN => PacketNla::CapLen(
+                parse_u32_be(payload).context("invalid NFQA_CAP_LEN value")?,
+            ),
+            NFQA_SKB_INFO => PacketNla::SkbInfo(SkbInfo::from_bits_retain(
+                parse_u32_be(payload).context("invalid NFQA_SKB_INFO value")?,
+            )),
+            NFQA_UID => PacketNla::Uid(
+                parse_u32_be(payload).context("invalid NFQA_UID value")?,
+            ),
+            NFQA_GID => PacketNla::Gid(
+                parse_u32_be(payload).context("invalid NFQA_GID value")?,
+            ),
+            NFQA_SECCTX => PacketNla::SecCtx(
+                parse_string(payload).context("invalid NFQA_SECCTX value")?,
+            ),
+            NFQA_VLAN => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid NFQA_VLAN value")?;
+                    nlas.push(VlanNla::parse(nla)?);
+                }
+                PacketNla::Vlan(nlas)
+            }
+            NFQA_L2HDR => PacketNla::L2Hdr(payload.to_vec()),
+            NFQA_PRIORITY => PacketNla::Priority(
+                parse_u32_be(payload).context("invalid NFQA_PRIORITY value")?,
+            ),
+            _ => PacketNla::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
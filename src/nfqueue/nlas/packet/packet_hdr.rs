@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    buffer, fields, getter, setter, DecodeError, Nla, Parseable,
+};
+
+use crate::constants::NFQA_PACKET_HDR;
+
+// struct nfqnl_msg_packet_hdr is packed
+const PACKET_HDR_LEN: usize = 7;
+
+buffer!(PacketHdrBuffer(PACKET_HDR_LEN) {
+    packet_id: (u32, 0..4),
+    hw_protocol: (u16, 4..6),
+    hook: (u8, 6),
+});
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketHdr {
+    // Identifier to give back in the verdict
+    pub packet_id: u32,
+    pub hw_protocol: u16,
+    pub hook: u8,
+}
+
+impl Nla for PacketHdr {
+    fn value_len(&self) -> usize {
+        PACKET_HDR_LEN
+    }
+
+    fn kind(&self) -> u16 {
+        NFQA_PACKET_HDR
+    }
+
+    fn emit_value(&self, buf: &mut [u8]) {
+        let mut buf = PacketHdrBuffer::new(buf);
+        buf.set_packet_id(self.packet_id.to_be());
+        buf.set_hw_protocol(self.hw_protocol.to_be());
+        buf.set_hook(self.hook)
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<PacketHdrBuffer<T>> for PacketHdr {
+    fn parse(buf: &PacketHdrBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(PacketHdr {
+            packet_id: u32::from_be(buf.packet_id()),
+            hw_protocol: u16::from_be(buf.hw_protocol()),
+            hook: buf.hook(),
+        })
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+
+const NFQA_SKB_CSUMNOTREADY: u32 = libc::NFQA_SKB_CSUMNOTREADY as u32;
+const NFQA_SKB_GSO: u32 = libc::NFQA_SKB_GSO as u32;
+const NFQA_SKB_CSUM_NOTVERIFIED: u32 = libc::NFQA_SKB_CSUM_NOTVERIFIED as u32;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SkbInfo: u32 {
+        // The checksums look wrong but are fine, e.g. checksum offloading
+        // on output
+        const CsumNotReady = NFQA_SKB_CSUMNOTREADY;
+        // The packet exceeds the device MTU
+        const Gso = NFQA_SKB_GSO;
+        const CsumNotVerified = NFQA_SKB_CSUM_NOTVERIFIED;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, parse_u16_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const NFQA_VLAN_PROTO: u16 = libc::NFQA_VLAN_PROTO as u16;
+const NFQA_VLAN_TCI: u16 = libc::NFQA_VLAN_TCI as u16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VlanNla {
+    // Ethertype of the tag, e.g. 0x8100 for 802.1Q
+    Proto(u16),
+    Tci(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for VlanNla {
+    fn value_len(&self) -> usize {
+        match self {
+            VlanNla::Proto(_) => 2,
+            VlanNla::Tci(_) => 2,
+            VlanNla::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            VlanNla::Proto(_) => NFQA_VLAN_PROTO,
+            VlanNla::Tci(_) => NFQA_VLAN_TCI,
+            VlanNla::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            VlanNla::Proto(value) => emit_u16_be(buffer, *value).unwrap(),
+            VlanNla::Tci(value) => emit_u16_be(buffer, *value).unwrap(),
+            VlanNla::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for VlanNla
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFQA_VLAN_PROTO => VlanNla::Proto(
+                parse_u16_be(payload)
+                    .context("invalid NFQA_VLAN_PROTO value")?,
+            ),
+            NFQA_VLAN_TCI => VlanNla::Tci(
+                parse_u16_be(payload).context("invalid NFQA_VLAN_TCI value")?,
+            ),
+            _ => VlanNla::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
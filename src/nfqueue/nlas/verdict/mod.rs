@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+mod nla;
+mod verdict_hdr;
+
+pub use nla::VerdictNla;
+pub use verdict_hdr::{Verdict, VerdictHdr, VerdictHdrBuffer};
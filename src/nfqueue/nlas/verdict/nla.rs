@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+use derive_more::{From, IsVariant};
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::{
+    constants::{NFQA_MARK, NFQA_PAYLOAD, NFQA_VERDICT_HDR},
+    nfqueue::nlas::verdict::verdict_hdr::{VerdictHdr, VerdictHdrBuffer},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, From, IsVariant)]
+#[non_exhaustive]
+pub enum VerdictNla {
+    #[from]
+    VerdictHdr(VerdictHdr),
+    Mark(u32),
+    // Replaces the packet content, not allowed in a verdict batch
+    Payload(Vec<u8>),
+    #[from]
+    Other(DefaultNla),
+}
+
+impl Nla for VerdictNla {
+    fn value_len(&self) -> usize {
+        match self {
+            VerdictNla::VerdictHdr(attr) => attr.value_len(),
+            VerdictNla::Mark(_) => 4,
+            VerdictNla::Payload(vec) => vec.len(),
+            VerdictNla::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            VerdictNla::VerdictHdr(attr) => attr.kind(),
+            VerdictNla::Mark(_) => NFQA_MARK,
+            VerdictNla::Payload(_) => NFQA_PAYLOAD,
+            VerdictNla::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            VerdictNla::VerdictHdr(attr) => attr.emit_value(buffer),
+            VerdictNla::Mark(value) => {
+                emit_u32_be(buffer, *value).unwrap();
+            }
+            VerdictNla::Payload(vec) => buffer.copy_from_slice(vec),
+            VerdictNla::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for VerdictNla
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFQA_VERDICT_HDR => {
+                let buf = VerdictHdrBuffer::new_checked(payload)
+                    .context("invalid NFQA_VERDICT_HDR value")?;
+                VerdictHdr::parse(&buf)?.into()
+            }
+            NFQA_MARK => VerdictNla::Mark(
+                parse_u32_be(payload).context("invalid NFQA_MARK value")?,
+            ),
+            NFQA_PAYLOAD => VerdictNla::Payload(payload.to_vec()),
+            _ => VerdictNla::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    buffer, fields, getter, setter, DecodeError, Nla, Parseable,
+};
+
+use crate::constants::NFQA_VERDICT_HDR;
+
+// Defined in Linux kernel: include/uapi/linux/netfilter.h
+const NF_DROP: u32 = 0;
+const NF_ACCEPT: u32 = 1;
+const NF_QUEUE: u32 = 3;
+const NF_REPEAT: u32 = 4;
+const NF_STOP: u32 = 5;
+const NF_VERDICT_MASK: u32 = 0x0000_00ff;
+const NF_VERDICT_FLAGMASK: u32 = 0x0000_ff00;
+const NF_VERDICT_FLAG_QUEUE_BYPASS: u32 = 0x0000_8000;
+const NF_VERDICT_QBITS: u32 = 16;
+
+const VERDICT_HDR_LEN: usize = 8;
+
+buffer!(VerdictHdrBuffer(VERDICT_HDR_LEN) {
+    verdict: (u32, 0..4),
+    packet_id: (u32, 4..8),
+});
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Verdict {
+    Drop,
+    Accept,
+    // Sends the packet to queue `num`. With `bypass`, the packet is accepted
+    // instead of dropped if no program listens on that queue.
+    Queue { num: u16, bypass: bool },
+    // Re-injects the packet at the start of the hook
+    Repeat,
+    Stop,
+    Other(u32),
+}
+
+impl From<Verdict> for u32 {
+    fn from(verdict: Verdict) -> Self {
+        match verdict {
+            Verdict::Drop => NF_DROP,
+            Verdict::Accept => NF_ACCEPT,
+            Verdict::Queue { num, bypass } => {
+                let mut verdict = ((num as u32) << NF_VERDICT_QBITS) | NF_QUEUE;
+                if bypass {
+                    verdict |= NF_VERDICT_FLAG_QUEUE_BYPASS;
+                }
+                verdict
+            }
+            Verdict::Repeat => NF_REPEAT,
+            Verdict::Stop => NF_STOP,
+            Verdict::Other(verdict) => verdict,
+        }
+    }
+}
+
+impl From<u32> for Verdict {
+    fn from(verdict: u32) -> Self {
+        match verdict & NF_VERDICT_MASK {
+            NF_QUEUE
+                if verdict
+                    & NF_VERDICT_FLAGMASK
+                    & !NF_VERDICT_FLAG_QUEUE_BYPASS
+                    == 0 =>
+            {
+                Verdict::Queue {
+                    num: (verdict >> NF_VERDICT_QBITS) as u16,
+                    bypass: verdict & NF_VERDICT_FLAG_QUEUE_BYPASS != 0,
+                }
+            }
+            _ if verdict & !NF_VERDICT_MASK != 0 => Verdict::Other(verdict),
+            NF_DROP => Verdict::Drop,
+            NF_ACCEPT => Verdict::Accept,
+            NF_REPEAT => Verdict::Repeat,
+            NF_STOP => Verdict::Stop,
+            _ => Verdict::Other(verdict),
+        }
+    }
+}
+
+// With `NFQNL_MSG_VERDICT_BATCH`, the verdict applies to all the packets
+// whose identifier is lower than or equal to `packet_id`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerdictHdr {
+    pub verdict: Verdict,
+    pub packet_id: u32,
+}
+
+impl VerdictHdr {
+    pub fn new(verdict: Verdict, packet_id: u32) -> Self {
+        Self { verdict, packet_id }
+    }
+}
+
+impl Nla for VerdictHdr {
+    fn value_len(&self) -> usize {
+        VERDICT_HDR_LEN
+    }
+
+    fn kind(&self) -> u16 {
+        NFQA_VERDICT_HDR
+    }
+
+    fn emit_value(&self, buf: &mut [u8]) {
+        let mut buf = VerdictHdrBuffer::new(buf);
+        buf.set_verdict(u32::from(self.verdict).to_be());
+        buf.set_packet_id(self.packet_id.to_be())
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<VerdictHdrBuffer<T>> for VerdictHdr {
+    fn parse(buf: &VerdictHdrBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(VerdictHdr {
+            verdict: u32::from_be(buf.verdict()).into(),
+            packet_id: u32::from_be(buf.packet_id()),
+        })
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::nftables::attributes::{
+    counter::Counter, flags::ChainFlags, hook::ChainHook,
+};
+
+const NFTA_CHAIN_TABLE: u16 = 1;
+const NFTA_CHAIN_HANDLE: u16 = 2;
+const NFTA_CHAIN_NAME: u16 = 3;
+const NFTA_CHAIN_HOOK: u16 = 4;
+const NFTA_CHAIN_POLICY: u16 = 5;
+const NFTA_CHAIN_USE: u16 = 6;
+const NFTA_CHAIN_TYPE: u16 = 7;
+const NFTA_CHAIN_COUNTERS: u16 = 8;
+const NFTA_CHAIN_FLAGS: u16 = 10;
+const NFTA_CHAIN_ID: u16 = 11;
+const NFTA_CHAIN_USERDATA: u16 = 12;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChainAttribute {
+    Table(String),
+    Handle(u64),
+    Name(String),
+    // Only for base chains
+    Hook(Vec<ChainHook>),
+    Policy(ChainPolicy),
+    Use(u32),
+    // "filter", "nat" or "route"
+    Type(String),
+    Counters(Vec<Counter>),
+    Flags(ChainFlags),
+    // Identifies a chain created in the same batch, before it has a handle
+    Id(u32),
+    Userdata(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for ChainAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ChainAttribute::Table(attr) => attr.len() + 1,
+            ChainAttribute::Handle(attr) => size_of_val(attr),
+            ChainAttribute::Name(attr) => attr.len() + 1,
+            ChainAttribute::Hook(attr) => attr.as_slice().buffer_len(),
+            ChainAttribute::Policy(_) => size_of::<u32>(),
+            ChainAttribute::Use(attr) => size_of_val(attr),
+            ChainAttribute::Type(attr) => attr.len() + 1,
+            ChainAttribute::Counters(attr) => attr.as_slice().buffer_len(),
+            ChainAttribute::Flags(_) => size_of::<u32>(),
+            ChainAttribute::Id(attr) => size_of_val(attr),
+            ChainAttribute::Userdata(attr) => attr.len(),
+            ChainAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ChainAttribute::Table(_) => NFTA_CHAIN_TABLE,
+            ChainAttribute::Handle(_) => NFTA_CHAIN_HANDLE,
+            ChainAttribute::Name(_) => NFTA_CHAIN_NAME,
+            ChainAttribute::Hook(_) => NFTA_CHAIN_HOOK,
+            ChainAttribute::Policy(_) => NFTA_CHAIN_POLICY,
+            ChainAttribute::Use(_) => NFTA_CHAIN_USE,
+            ChainAttribute::Type(_) => NFTA_CHAIN_TYPE,
+            ChainAttribute::Counters(_) => NFTA_CHAIN_COUNTERS,
+            ChainAttribute::Flags(_) => NFTA_CHAIN_FLAGS,
+            ChainAttribute::Id(_) => NFTA_CHAIN_ID,
+            ChainAttribute::Userdata(_) => NFTA_CHAIN_USERDATA,
+            ChainAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ChainAttribute::Table(attr)
+            | ChainAttribute::Name(attr)
+            | ChainAttribute::Type(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ChainAttribute::Handle(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            ChainAttribute::Hook(attr) => attr.as_slice().emit(buffer),
+            ChainAttribute::Policy(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ChainAttribute::Use(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ChainAttribute::Counters(attr) => attr.as_slice().emit(buffer),
+            ChainAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            ChainAttribute::Id(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ChainAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            ChainAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ChainAttribute::Hook(_) | ChainAttribute::Counters(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ChainAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CHAIN_TABLE => ChainAttribute::Table(
+                parse_string(payload)
+                    .context("invalid NFTA_CHAIN_TABLE value")?,
+            ),
+            NFTA_CHAIN_HANDLE => ChainAttribute::Handle(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_CHAIN_HANDLE value")?,
+            ),
+            NFTA_CHAIN_NAME => ChainAttribute::Name(
+                parse_string(payload)
+                    .context("invalid NFTA_CHAIN_NAME value")?,
+            ),
+            NFTA_CHAIN_HOOK => {
+                let mut chain_hooks = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_CHAIN_HOOK value")?;
+                    chain_hooks.push(ChainHook::parse(nlas)?);
+                }
+                ChainAttribute::Hook(chain_hooks)
+            }
+            NFTA_CHAIN_POLICY => ChainAttribute::Policy(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CHAIN_POLICY value")?
+                    .into(),
+            ),
+            NFTA_CHAIN_USE => ChainAttribute::Use(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CHAIN_USE value")?,
+            ),
+            NFTA_CHAIN_TYPE => ChainAttribute::Type(
+                parse_string(payload)
+                    .context("invalid NFTA_CHAIN_TYPE value")?,
+            ),
+            NFTA_CHAIN_COUNTERS => {
+                let mut counters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_CHAIN_COUNTERS value")?;
+                    counters.push(Counter::parse(nlas)?);
+                }
+                ChainAttribute::Counters(counters)
+            }
+            NFTA_CHAIN_FLAGS => {
+                ChainAttribute::Flags(ChainFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_CHAIN_FLAGS value")?,
+                ))
+            }
+            NFTA_CHAIN_ID => ChainAttribute::Id(
+                parse_u32_be(payload).context("invalid NFTA_CHAIN_ID value")?,
+            ),
+            NFTA_CHAIN_USERDATA => ChainAttribute::Userdata(payload.to_vec()),
+            _ => ChainAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NF_DROP: u32 = 0;
+const NF_ACCEPT: u32 = 1;
+
+// Verdict of the packets reaching the end of a base chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChainPolicy {
+    Drop,
+    Accept,
+    Other(u32),
+}
+
+impl From<u32> for ChainPolicy {
+    fn from(value: u32) -> Self {
+        match value {
+            NF_DROP => Self::Drop,
+            NF_ACCEPT => Self::Accept,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<ChainPolicy> for u32 {
+    fn from(value: ChainPolicy) -> Self {
+        match value {
+            ChainPolicy::Drop => NF_DROP,
+            ChainPolicy::Accept => NF_ACCEPT,
+            ChainPolicy::Other(v) => v,
+        }
+    }
+}
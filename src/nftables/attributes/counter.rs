@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u64_be, parse_u64_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+const NFTA_COUNTER_BYTES: u16 = 1;
+const NFTA_COUNTER_PACKETS: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Counter {
+    Bytes(u64),
+    Packets(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for Counter {
+    fn value_len(&self) -> usize {
+        match self {
+            Counter::Bytes(attr) => size_of_val(attr),
+            Counter::Packets(attr) => size_of_val(attr),
+            Counter::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Counter::Bytes(_) => NFTA_COUNTER_BYTES,
+            Counter::Packets(_) => NFTA_COUNTER_PACKETS,
+            Counter::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Counter::Bytes(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Counter::Packets(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            Counter::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Counter
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_COUNTER_BYTES => Counter::Bytes(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_COUNTER_BYTES value")?,
+            ),
+            NFTA_COUNTER_PACKETS => Counter::Packets(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_COUNTER_PACKETS value")?,
+            ),
+            _ => Counter::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
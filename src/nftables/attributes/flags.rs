@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+
+use bitflags::bitflags;
+
+const NFT_TABLE_F_DORMANT: u32 = 1;
+const NFT_TABLE_F_OWNER: u32 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct TableFlags: u32 {
+        // The chains of the table are not registered to their hooks
+        const Dormant = NFT_TABLE_F_DORMANT;
+        // The table is removed when the netlink socket which created it is
+        // closed
+        const Owner = NFT_TABLE_F_OWNER;
+        const _ = !0;
+    }
+}
+
+const NFT_CHAIN_BASE: u32 = 1;
+const NFT_CHAIN_HW_OFFLOAD: u32 = 1 << 1;
+const NFT_CHAIN_BINDING: u32 = 1 << 2;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ChainFlags: u32 {
+        const Base = NFT_CHAIN_BASE;
+        const HwOffload = NFT_CHAIN_HW_OFFLOAD;
+        // Anonymous chain bound to the rule which references it
+        const Binding = NFT_CHAIN_BINDING;
+        const _ = !0;
+    }
+}
+
+const NFT_SET_ANONYMOUS: u32 = 1;
+const NFT_SET_CONSTANT: u32 = 1 << 1;
+const NFT_SET_INTERVAL: u32 = 1 << 2;
+const NFT_SET_MAP: u32 = 1 << 3;
+const NFT_SET_TIMEOUT: u32 = 1 << 4;
+const NFT_SET_EVAL: u32 = 1 << 5;
+const NFT_SET_OBJECT: u32 = 1 << 6;
+const NFT_SET_CONCAT: u32 = 1 << 7;
+const NFT_SET_EXPR: u32 = 1 << 8;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SetFlags: u32 {
+        // Set bound to the rule which references it
+        const Anonymous = NFT_SET_ANONYMOUS;
+        const Constant = NFT_SET_CONSTANT;
+        const Interval = NFT_SET_INTERVAL;
+        const Map = NFT_SET_MAP;
+        const Timeout = NFT_SET_TIMEOUT;
+        // Updated from the packet path, e.g. by the dynset expression
+        const Eval = NFT_SET_EVAL;
+        const Object = NFT_SET_OBJECT;
+        const Concat = NFT_SET_CONCAT;
+        const Expr = NFT_SET_EXPR;
+        const _ = !0;
+    }
+}
+
+const NFT_SET_ELEM_INTERVAL_END: u32 = 1;
+const NFT_SET_ELEM_CATCHALL: u32 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SetElementFlags: u32 {
+        const IntervalEnd = NFT_SET_ELEM_INTERVAL_END;
+        // Matches every key not in the set
+        const Catchall = NFT_SET_ELEM_CATCHALL;
+        const _ = !0;
+    }
+}
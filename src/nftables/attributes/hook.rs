@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_i32_be, emit_u32_be, parse_i32_be, parse_string, parse_u32_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+const NFTA_HOOK_HOOKNUM: u16 = 1;
+const NFTA_HOOK_PRIORITY: u16 = 2;
+const NFTA_HOOK_DEV: u16 = 3;
+const NFTA_HOOK_DEVS: u16 = 4;
+
+const NFTA_DEVICE_NAME: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChainHook {
+    // The meaning of the hook number depends on the family of the table,
+    // e.g. NF_INET_LOCAL_IN (1) for inet tables or NF_NETDEV_INGRESS (0) for
+    // netdev tables
+    HookNum(u32),
+    Priority(i32),
+    // Single device of a netdev chain, see Devs for several devices
+    Dev(String),
+    Devs(Vec<HookDevice>),
+    Other(DefaultNla),
+}
+
+impl Nla for ChainHook {
+    fn value_len(&self) -> usize {
+        match self {
+            ChainHook::HookNum(attr) => size_of_val(attr),
+            ChainHook::Priority(attr) => size_of_val(attr),
+            ChainHook::Dev(attr) => attr.len() + 1,
+            ChainHook::Devs(attr) => attr.as_slice().buffer_len(),
+            ChainHook::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ChainHook::HookNum(_) => NFTA_HOOK_HOOKNUM,
+            ChainHook::Priority(_) => NFTA_HOOK_PRIORITY,
+            ChainHook::Dev(_) => NFTA_HOOK_DEV,
+            ChainHook::Devs(_) => NFTA_HOOK_DEVS,
+            ChainHook::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ChainHook::HookNum(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ChainHook::Priority(attr) => emit_i32_be(buffer, *attr).unwrap(),
+            ChainHook::Dev(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ChainHook::Devs(attr) => attr.as_slice().emit(buffer),
+            ChainHook::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ChainHook::Devs(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ChainHook
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_HOOK_HOOKNUM => ChainHook::HookNum(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HOOK_HOOKNUM value")?,
+            ),
+            NFTA_HOOK_PRIORITY => ChainHook::Priority(
+                parse_i32_be(payload)
+                    .context("invalid NFTA_HOOK_PRIORITY value")?,
+            ),
+            NFTA_HOOK_DEV => ChainHook::Dev(
+                parse_string(payload).context("invalid NFTA_HOOK_DEV value")?,
+            ),
+            NFTA_HOOK_DEVS => {
+                let mut hook_devices = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_HOOK_DEVS value")?;
+                    hook_devices.push(HookDevice::parse(nlas)?);
+                }
+                ChainHook::Devs(hook_devices)
+            }
+            _ => ChainHook::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HookDevice {
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for HookDevice {
+    fn value_len(&self) -> usize {
+        match self {
+            HookDevice::Name(attr) => attr.len() + 1,
+            HookDevice::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HookDevice::Name(_) => NFTA_DEVICE_NAME,
+            HookDevice::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HookDevice::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            HookDevice::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HookDevice
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_DEVICE_NAME => HookDevice::Name(
+                parse_string(payload)
+                    .context("invalid NFTA_DEVICE_NAME value")?,
+            ),
+            _ => HookDevice::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
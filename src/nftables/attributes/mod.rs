@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+
+mod chain;
+mod counter;
+mod flags;
+mod hook;
+mod object;
+mod rule;
+mod set;
+mod set_element;
+mod table;
+
+pub use chain::{ChainAttribute, ChainPolicy};
+pub use counter::Counter;
+pub use flags::{ChainFlags, SetElementFlags, SetFlags, TableFlags};
+pub use hook::{ChainHook, HookDevice};
+pub(crate) use object::find_object_type;
+pub use object::{ObjectAttribute, ObjectType};
+pub use rule::RuleAttribute;
+pub use set::{
+    SetAttribute, SetConcatField, SetDescAttribute, SetFieldAttribute,
+    SetPolicy,
+};
+pub use set_element::{
+    SetElement, SetElementAttribute, SetElementListAttribute,
+};
+pub use table::TableAttribute;
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, Parseable,
+    ParseableParametrized,
+};
+
+use crate::nftables::objects::ObjectData;
+
+const NFTA_OBJ_TABLE: u16 = 1;
+const NFTA_OBJ_NAME: u16 = 2;
+const NFTA_OBJ_TYPE: u16 = 3;
+const NFTA_OBJ_DATA: u16 = 4;
+const NFTA_OBJ_USE: u16 = 5;
+const NFTA_OBJ_HANDLE: u16 = 6;
+const NFTA_OBJ_USERDATA: u16 = 8;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ObjectAttribute {
+    Table(String),
+    Name(String),
+    Type(ObjectType),
+    Data(ObjectData),
+    // Number of rules and maps referencing the object
+    Use(u32),
+    Handle(u64),
+    Userdata(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for ObjectAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ObjectAttribute::Table(attr) => attr.len() + 1,
+            ObjectAttribute::Name(attr) => attr.len() + 1,
+            ObjectAttribute::Type(_) => size_of::<u32>(),
+            ObjectAttribute::Data(attr) => attr.buffer_len(),
+            ObjectAttribute::Use(attr) => size_of_val(attr),
+            ObjectAttribute::Handle(attr) => size_of_val(attr),
+            ObjectAttribute::Userdata(attr) => attr.len(),
+            ObjectAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ObjectAttribute::Table(_) => NFTA_OBJ_TABLE,
+            ObjectAttribute::Name(_) => NFTA_OBJ_NAME,
+            ObjectAttribute::Type(_) => NFTA_OBJ_TYPE,
+            ObjectAttribute::Data(_) => NFTA_OBJ_DATA,
+            ObjectAttribute::Use(_) => NFTA_OBJ_USE,
+            ObjectAttribute::Handle(_) => NFTA_OBJ_HANDLE,
+            ObjectAttribute::Userdata(_) => NFTA_OBJ_USERDATA,
+            ObjectAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ObjectAttribute::Table(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ObjectAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ObjectAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ObjectAttribute::Data(attr) => attr.emit(buffer),
+            ObjectAttribute::Use(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ObjectAttribute::Handle(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            ObjectAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            ObjectAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ObjectAttribute::Data(_))
+    }
+}
+
+// The layout of NFTA_OBJ_DATA depends on NFTA_OBJ_TYPE, which has to be looked
+// up among the other attributes of the message first
+impl<'buffer, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'buffer T>, Option<ObjectType>>
+    for ObjectAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'buffer T>,
+        object_type: Option<ObjectType>,
+    ) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_OBJ_TABLE => ObjectAttribute::Table(
+                parse_string(payload)
+                    .context("invalid NFTA_OBJ_TABLE value")?,
+            ),
+            NFTA_OBJ_NAME => ObjectAttribute::Name(
+                parse_string(payload).context("invalid NFTA_OBJ_NAME value")?,
+            ),
+            NFTA_OBJ_TYPE => ObjectAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_OBJ_TYPE value")?
+                    .into(),
+            ),
+            NFTA_OBJ_DATA => ObjectAttribute::Data(
+                ObjectData::parse(payload, object_type)
+                    .context("invalid NFTA_OBJ_DATA value")?,
+            ),
+            NFTA_OBJ_USE => ObjectAttribute::Use(
+                parse_u32_be(payload).context("invalid NFTA_OBJ_USE value")?,
+            ),
+            NFTA_OBJ_HANDLE => ObjectAttribute::Handle(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_OBJ_HANDLE value")?,
+            ),
+            NFTA_OBJ_USERDATA => ObjectAttribute::Userdata(payload.to_vec()),
+            _ => ObjectAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+pub(crate) fn find_object_type<'a, I>(
+    nlas: I,
+) -> Result<Option<ObjectType>, DecodeError>
+where
+    I: Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+{
+    for nla in nlas {
+        let nla = nla?;
+        if nla.kind() == NFTA_OBJ_TYPE {
+            return Ok(Some(
+                parse_u32_be(nla.value())
+                    .context("invalid NFTA_OBJ_TYPE value")?
+                    .into(),
+            ));
+        }
+    }
+    Ok(None)
+}
+
+const NFT_OBJECT_UNSPEC: u32 = 0;
+const NFT_OBJECT_COUNTER: u32 = 1;
+const NFT_OBJECT_QUOTA: u32 = 2;
+const NFT_OBJECT_CT_HELPER: u32 = 3;
+const NFT_OBJECT_LIMIT: u32 = 4;
+const NFT_OBJECT_CONNLIMIT: u32 = 5;
+const NFT_OBJECT_TUNNEL: u32 = 6;
+const NFT_OBJECT_CT_TIMEOUT: u32 = 7;
+const NFT_OBJECT_SECMARK: u32 = 8;
+const NFT_OBJECT_CT_EXPECT: u32 = 9;
+const NFT_OBJECT_SYNPROXY: u32 = 10;
+
+// Type of the stateful objects, named so that several rules can share them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ObjectType {
+    Unspec,
+    Counter,
+    Quota,
+    CtHelper,
+    Limit,
+    Connlimit,
+    Tunnel,
+    CtTimeout,
+    Secmark,
+    CtExpect,
+    Synproxy,
+    Other(u32),
+}
+
+impl From<u32> for ObjectType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_OBJECT_UNSPEC => Self::Unspec,
+            NFT_OBJECT_COUNTER => Self::Counter,
+            NFT_OBJECT_QUOTA => Self::Quota,
+            NFT_OBJECT_CT_HELPER => Self::CtHelper,
+            NFT_OBJECT_LIMIT => Self::Limit,
+            NFT_OBJECT_CONNLIMIT => Self::Connlimit,
+            NFT_OBJECT_TUNNEL => Self::Tunnel,
+            NFT_OBJECT_CT_TIMEOUT => Self::CtTimeout,
+            NFT_OBJECT_SECMARK => Self::Secmark,
+            NFT_OBJECT_CT_EXPECT => Self::CtExpect,
+            NFT_OBJECT_SYNPROXY => Self::Synproxy,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<ObjectType> for u32 {
+    fn from(value: ObjectType) -> Self {
+        match value {
+            ObjectType::Unspec => NFT_OBJECT_UNSPEC,
+            ObjectType::Counter => NFT_OBJECT_COUNTER,
+            ObjectType::Quota => NFT_OBJECT_QUOTA,
+            ObjectType::CtHelper => NFT_OBJECT_CT_HELPER,
+            ObjectType::Limit => NFT_OBJECT_LIMIT,
+            ObjectType::Connlimit => NFT_OBJECT_CONNLIMIT,
+            ObjectType::Tunnel => NFT_OBJECT_TUNNEL,
+            ObjectType::CtTimeout => NFT_OBJECT_CT_TIMEOUT,
+            ObjectType::Secmark => NFT_OBJECT_SECMARK,
+            ObjectType::CtExpect => NFT_OBJECT_CT_EXPECT,
+            ObjectType::Synproxy => NFT_OBJECT_SYNPROXY,
+            ObjectType::Other(v) => v,
+        }
+    }
+}
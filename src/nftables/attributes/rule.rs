@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::nftables::expressions::Expression;
+
+const NFTA_RULE_TABLE: u16 = 1;
+const NFTA_RULE_CHAIN: u16 = 2;
+const NFTA_RULE_HANDLE: u16 = 3;
+const NFTA_RULE_EXPRESSIONS: u16 = 4;
+const NFTA_RULE_POSITION: u16 = 6;
+const NFTA_RULE_USERDATA: u16 = 7;
+const NFTA_RULE_ID: u16 = 9;
+const NFTA_RULE_POSITION_ID: u16 = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RuleAttribute {
+    Table(String),
+    Chain(String),
+    Handle(u64),
+    Expressions(Vec<Expression>),
+    // Handle of the rule to insert the new rule after, or before when
+    // NLM_F_APPEND is unset
+    Position(u64),
+    Userdata(Vec<u8>),
+    // Identifies a rule created in the same batch
+    Id(u32),
+    // Same as Position for a rule created in the same batch, by its Id
+    PositionId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RuleAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            RuleAttribute::Table(attr) => attr.len() + 1,
+            RuleAttribute::Chain(attr) => attr.len() + 1,
+            RuleAttribute::Handle(attr) => size_of_val(attr),
+            RuleAttribute::Expressions(attr) => attr.as_slice().buffer_len(),
+            RuleAttribute::Position(attr) => size_of_val(attr),
+            RuleAttribute::Userdata(attr) => attr.len(),
+            RuleAttribute::Id(attr) => size_of_val(attr),
+            RuleAttribute::PositionId(attr) => size_of_val(attr),
+            RuleAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            RuleAttribute::Table(_) => NFTA_RULE_TABLE,
+            RuleAttribute::Chain(_) => NFTA_RULE_CHAIN,
+            RuleAttribute::Handle(_) => NFTA_RULE_HANDLE,
+            RuleAttribute::Expressions(_) => NFTA_RULE_EXPRESSIONS,
+            RuleAttribute::Position(_) => NFTA_RULE_POSITION,
+            RuleAttribute::Userdata(_) => NFTA_RULE_USERDATA,
+            RuleAttribute::Id(_) => NFTA_RULE_ID,
+            RuleAttribute::PositionId(_) => NFTA_RULE_POSITION_ID,
+            RuleAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            RuleAttribute::Table(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            RuleAttribute::Chain(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            RuleAttribute::Handle(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            RuleAttribute::Expressions(attr) => attr.as_slice().emit(buffer),
+            RuleAttribute::Position(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            RuleAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            RuleAttribute::Id(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            RuleAttribute::PositionId(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            RuleAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, RuleAttribute::Expressions(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for RuleAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_RULE_TABLE => RuleAttribute::Table(
+                parse_string(payload)
+                    .context("invalid NFTA_RULE_TABLE value")?,
+            ),
+            NFTA_RULE_CHAIN => RuleAttribute::Chain(
+                parse_string(payload)
+                    .context("invalid NFTA_RULE_CHAIN value")?,
+            ),
+            NFTA_RULE_HANDLE => RuleAttribute::Handle(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_RULE_HANDLE value")?,
+            ),
+            NFTA_RULE_EXPRESSIONS => {
+                let mut expressions = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_RULE_EXPRESSIONS value")?;
+                    expressions.push(Expression::parse(nlas)?);
+                }
+                RuleAttribute::Expressions(expressions)
+            }
+            NFTA_RULE_POSITION => RuleAttribute::Position(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_RULE_POSITION value")?,
+            ),
+            NFTA_RULE_USERDATA => RuleAttribute::Userdata(payload.to_vec()),
+            NFTA_RULE_ID => RuleAttribute::Id(
+                parse_u32_be(payload).context("invalid NFTA_RULE_ID value")?,
+            ),
+            NFTA_RULE_POSITION_ID => RuleAttribute::PositionId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_RULE_POSITION_ID value")?,
+            ),
+            _ => RuleAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,445 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::nftables::{
+    attributes::flags::SetFlags, expressions::Expression, ObjectType,
+};
+
+const NFTA_SET_TABLE: u16 = 1;
+const NFTA_SET_NAME: u16 = 2;
+const NFTA_SET_FLAGS: u16 = 3;
+const NFTA_SET_KEY_TYPE: u16 = 4;
+const NFTA_SET_KEY_LEN: u16 = 5;
+const NFTA_SET_DATA_TYPE: u16 = 6;
+const NFTA_SET_DATA_LEN: u16 = 7;
+const NFTA_SET_POLICY: u16 = 8;
+const NFTA_SET_DESC: u16 = 9;
+const NFTA_SET_ID: u16 = 10;
+const NFTA_SET_TIMEOUT: u16 = 11;
+const NFTA_SET_GC_INTERVAL: u16 = 12;
+const NFTA_SET_USERDATA: u16 = 13;
+const NFTA_SET_OBJ_TYPE: u16 = 15;
+const NFTA_SET_HANDLE: u16 = 16;
+const NFTA_SET_EXPR: u16 = 17;
+const NFTA_SET_EXPRESSIONS: u16 = 18;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetAttribute {
+    Table(String),
+    Name(String),
+    Flags(SetFlags),
+    // Data type of the key, only meaningful to userspace
+    KeyType(u32),
+    KeyLen(u32),
+    // NFT_DATA_VERDICT (0xffffff00) for verdict maps
+    DataType(u32),
+    DataLen(u32),
+    Policy(SetPolicy),
+    Desc(Vec<SetDescAttribute>),
+    // Identifies a set created in the same batch
+    Id(u32),
+    // Default timeout of the elements, in milliseconds
+    Timeout(u64),
+    // In milliseconds
+    GcInterval(u32),
+    Userdata(Vec<u8>),
+    // Type of the objects of an object map, see SetFlags::Object
+    ObjType(ObjectType),
+    Handle(u64),
+    // Single stateful expression added to the elements, see Expressions for
+    // several of them
+    Expr(Expression),
+    Expressions(Vec<Expression>),
+    Other(DefaultNla),
+}
+
+impl Nla for SetAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SetAttribute::Table(attr) => attr.len() + 1,
+            SetAttribute::Name(attr) => attr.len() + 1,
+            SetAttribute::Flags(_) => size_of::<u32>(),
+            SetAttribute::KeyType(attr) => size_of_val(attr),
+            SetAttribute::KeyLen(attr) => size_of_val(attr),
+            SetAttribute::DataType(attr) => size_of_val(attr),
+            SetAttribute::DataLen(attr) => size_of_val(attr),
+            SetAttribute::Policy(_) => size_of::<u32>(),
+            SetAttribute::Desc(attr) => attr.as_slice().buffer_len(),
+            SetAttribute::Id(attr) => size_of_val(attr),
+            SetAttribute::Timeout(attr) => size_of_val(attr),
+            SetAttribute::GcInterval(attr) => size_of_val(attr),
+            SetAttribute::Userdata(attr) => attr.len(),
+            SetAttribute::ObjType(_) => size_of::<u32>(),
+            SetAttribute::Handle(attr) => size_of_val(attr),
+            SetAttribute::Expr(attr) => attr.value_len(),
+            SetAttribute::Expressions(attr) => attr.as_slice().buffer_len(),
+            SetAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetAttribute::Table(_) => NFTA_SET_TABLE,
+            SetAttribute::Name(_) => NFTA_SET_NAME,
+            SetAttribute::Flags(_) => NFTA_SET_FLAGS,
+            SetAttribute::KeyType(_) => NFTA_SET_KEY_TYPE,
+            SetAttribute::KeyLen(_) => NFTA_SET_KEY_LEN,
+            SetAttribute::DataType(_) => NFTA_SET_DATA_TYPE,
+            SetAttribute::DataLen(_) => NFTA_SET_DATA_LEN,
+            SetAttribute::Policy(_) => NFTA_SET_POLICY,
+            SetAttribute::Desc(_) => NFTA_SET_DESC,
+            SetAttribute::Id(_) => NFTA_SET_ID,
+            SetAttribute::Timeout(_) => NFTA_SET_TIMEOUT,
+            SetAttribute::GcInterval(_) => NFTA_SET_GC_INTERVAL,
+            SetAttribute::Userdata(_) => NFTA_SET_USERDATA,
+            SetAttribute::ObjType(_) => NFTA_SET_OBJ_TYPE,
+            SetAttribute::Handle(_) => NFTA_SET_HANDLE,
+            SetAttribute::Expr(_) => NFTA_SET_EXPR,
+            SetAttribute::Expressions(_) => NFTA_SET_EXPRESSIONS,
+            SetAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetAttribute::Table(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SetAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SetAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            SetAttribute::KeyType(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetAttribute::KeyLen(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetAttribute::DataType(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetAttribute::DataLen(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetAttribute::Policy(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            SetAttribute::Desc(attr) => attr.as_slice().emit(buffer),
+            SetAttribute::Id(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetAttribute::Timeout(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            SetAttribute::GcInterval(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SetAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            SetAttribute::ObjType(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            SetAttribute::Handle(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            SetAttribute::Expr(attr) => attr.emit_value(buffer),
+            SetAttribute::Expressions(attr) => attr.as_slice().emit(buffer),
+            SetAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            SetAttribute::Desc(_)
+                | SetAttribute::Expr(_)
+                | SetAttribute::Expressions(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SET_TABLE => SetAttribute::Table(
+                parse_string(payload)
+                    .context("invalid NFTA_SET_TABLE value")?,
+            ),
+            NFTA_SET_NAME => SetAttribute::Name(
+                parse_string(payload).context("invalid NFTA_SET_NAME value")?,
+            ),
+            NFTA_SET_FLAGS => SetAttribute::Flags(SetFlags::from_bits_retain(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_FLAGS value")?,
+            )),
+            NFTA_SET_KEY_TYPE => SetAttribute::KeyType(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_KEY_TYPE value")?,
+            ),
+            NFTA_SET_KEY_LEN => SetAttribute::KeyLen(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_KEY_LEN value")?,
+            ),
+            NFTA_SET_DATA_TYPE => SetAttribute::DataType(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_DATA_TYPE value")?,
+            ),
+            NFTA_SET_DATA_LEN => SetAttribute::DataLen(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_DATA_LEN value")?,
+            ),
+            NFTA_SET_POLICY => SetAttribute::Policy(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_POLICY value")?
+                    .into(),
+            ),
+            NFTA_SET_DESC => {
+                let mut set_desc_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_SET_DESC value")?;
+                    set_desc_attributes.push(SetDescAttribute::parse(nlas)?);
+                }
+                SetAttribute::Desc(set_desc_attributes)
+            }
+            NFTA_SET_ID => SetAttribute::Id(
+                parse_u32_be(payload).context("invalid NFTA_SET_ID value")?,
+            ),
+            NFTA_SET_TIMEOUT => SetAttribute::Timeout(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_SET_TIMEOUT value")?,
+            ),
+            NFTA_SET_GC_INTERVAL => SetAttribute::GcInterval(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_GC_INTERVAL value")?,
+            ),
+            NFTA_SET_USERDATA => SetAttribute::Userdata(payload.to_vec()),
+            NFTA_SET_OBJ_TYPE => SetAttribute::ObjType(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_OBJ_TYPE value")?
+                    .into(),
+            ),
+            NFTA_SET_HANDLE => SetAttribute::Handle(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_SET_HANDLE value")?,
+            ),
+            NFTA_SET_EXPR => SetAttribute::Expr(Expression::parse(buf)?),
+            NFTA_SET_EXPRESSIONS => {
+                let mut expressions = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_SET_EXPRESSIONS value")?;
+                    expressions.push(Expression::parse(nlas)?);
+                }
+                SetAttribute::Expressions(expressions)
+            }
+            _ => SetAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_SET_POL_PERFORMANCE: u32 = 0;
+const NFT_SET_POL_MEMORY: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetPolicy {
+    Performance,
+    Memory,
+    Other(u32),
+}
+
+impl From<u32> for SetPolicy {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_SET_POL_PERFORMANCE => Self::Performance,
+            NFT_SET_POL_MEMORY => Self::Memory,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<SetPolicy> for u32 {
+    fn from(value: SetPolicy) -> Self {
+        match value {
+            SetPolicy::Performance => NFT_SET_POL_PERFORMANCE,
+            SetPolicy::Memory => NFT_SET_POL_MEMORY,
+            SetPolicy::Other(v) => v,
+        }
+    }
+}
+
+const NFTA_SET_DESC_SIZE: u16 = 1;
+const NFTA_SET_DESC_CONCAT: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetDescAttribute {
+    // Expected number of elements
+    Size(u32),
+    // Fields of a concatenated key, e.g. ip . port, in order
+    Concat(Vec<SetConcatField>),
+    Other(DefaultNla),
+}
+
+impl Nla for SetDescAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SetDescAttribute::Size(attr) => size_of_val(attr),
+            SetDescAttribute::Concat(attr) => attr.as_slice().buffer_len(),
+            SetDescAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetDescAttribute::Size(_) => NFTA_SET_DESC_SIZE,
+            SetDescAttribute::Concat(_) => NFTA_SET_DESC_CONCAT,
+            SetDescAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetDescAttribute::Size(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetDescAttribute::Concat(attr) => attr.as_slice().emit(buffer),
+            SetDescAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, SetDescAttribute::Concat(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetDescAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SET_DESC_SIZE => SetDescAttribute::Size(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_DESC_SIZE value")?,
+            ),
+            NFTA_SET_DESC_CONCAT => {
+                let mut set_concat_fields = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_SET_DESC_CONCAT value")?;
+                    set_concat_fields.push(SetConcatField::parse(nlas)?);
+                }
+                SetDescAttribute::Concat(set_concat_fields)
+            }
+            _ => SetDescAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFTA_LIST_ELEM: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetConcatField {
+    Field(Vec<SetFieldAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for SetConcatField {
+    fn value_len(&self) -> usize {
+        match self {
+            SetConcatField::Field(attr) => attr.as_slice().buffer_len(),
+            SetConcatField::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetConcatField::Field(_) => NFTA_LIST_ELEM,
+            SetConcatField::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetConcatField::Field(attr) => attr.as_slice().emit(buffer),
+            SetConcatField::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, SetConcatField::Field(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetConcatField
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_LIST_ELEM => {
+                let mut set_field_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_LIST_ELEM value")?;
+                    set_field_attributes.push(SetFieldAttribute::parse(nlas)?);
+                }
+                SetConcatField::Field(set_field_attributes)
+            }
+            _ => SetConcatField::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFTA_SET_FIELD_LEN: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetFieldAttribute {
+    // Length of the field in bytes, without its padding in the key
+    Len(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for SetFieldAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SetFieldAttribute::Len(attr) => size_of_val(attr),
+            SetFieldAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetFieldAttribute::Len(_) => NFTA_SET_FIELD_LEN,
+            SetFieldAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetFieldAttribute::Len(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            SetFieldAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetFieldAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SET_FIELD_LEN => SetFieldAttribute::Len(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_FIELD_LEN value")?,
+            ),
+            _ => SetFieldAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
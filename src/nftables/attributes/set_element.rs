@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use crate::nftables::{
+    attributes::flags::SetElementFlags,
+    expressions::{DataAttribute, Expression},
+};
+
+const NFTA_SET_ELEM_LIST_TABLE: u16 = 1;
+const NFTA_SET_ELEM_LIST_SET: u16 = 2;
+const NFTA_SET_ELEM_LIST_ELEMENTS: u16 = 3;
+const NFTA_SET_ELEM_LIST_SET_ID: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetElementListAttribute {
+    Table(String),
+    Set(String),
+    Elements(Vec<SetElement>),
+    // Identifies a set created in the same batch
+    SetId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for SetElementListAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SetElementListAttribute::Table(attr) => attr.len() + 1,
+            SetElementListAttribute::Set(attr) => attr.len() + 1,
+            SetElementListAttribute::Elements(attr) => {
+                attr.as_slice().buffer_len()
+            }
+            SetElementListAttribute::SetId(attr) => size_of_val(attr),
+            SetElementListAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetElementListAttribute::Table(_) => NFTA_SET_ELEM_LIST_TABLE,
+            SetElementListAttribute::Set(_) => NFTA_SET_ELEM_LIST_SET,
+            SetElementListAttribute::Elements(_) => NFTA_SET_ELEM_LIST_ELEMENTS,
+            SetElementListAttribute::SetId(_) => NFTA_SET_ELEM_LIST_SET_ID,
+            SetElementListAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetElementListAttribute::Table(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SetElementListAttribute::Set(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SetElementListAttribute::Elements(attr) => {
+                attr.as_slice().emit(buffer)
+            }
+            SetElementListAttribute::SetId(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            SetElementListAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, SetElementListAttribute::Elements(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetElementListAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SET_ELEM_LIST_TABLE => SetElementListAttribute::Table(
+                parse_string(payload)
+                    .context("invalid NFTA_SET_ELEM_LIST_TABLE value")?,
+            ),
+            NFTA_SET_ELEM_LIST_SET => SetElementListAttribute::Set(
+                parse_string(payload)
+                    .context("invalid NFTA_SET_ELEM_LIST_SET value")?,
+            ),
+            NFTA_SET_ELEM_LIST_ELEMENTS => {
+                let mut set_elements = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas
+                        .context("invalid NFTA_SET_ELEM_LIST_ELEMENTS value")?;
+                    set_elements.push(SetElement::parse(nlas)?);
+                }
+                SetElementListAttribute::Elements(set_elements)
+            }
+            NFTA_SET_ELEM_LIST_SET_ID => SetElementListAttribute::SetId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_SET_ELEM_LIST_SET_ID value")?,
+            ),
+            _ => SetElementListAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFTA_LIST_ELEM: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetElement {
+    Element(Vec<SetElementAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for SetElement {
+    fn value_len(&self) -> usize {
+        match self {
+            SetElement::Element(attr) => attr.as_slice().buffer_len(),
+            SetElement::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetElement::Element(_) => NFTA_LIST_ELEM,
+            SetElement::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetElement::Element(attr) => attr.as_slice().emit(buffer),
+            SetElement::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, SetElement::Element(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetElement
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_LIST_ELEM => {
+                let mut set_element_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_LIST_ELEM value")?;
+                    set_element_attributes
+                        .push(SetElementAttribute::parse(nlas)?);
+                }
+                SetElement::Element(set_element_attributes)
+            }
+            _ => SetElement::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFTA_SET_ELEM_KEY: u16 = 1;
+const NFTA_SET_ELEM_DATA: u16 = 2;
+const NFTA_SET_ELEM_FLAGS: u16 = 3;
+const NFTA_SET_ELEM_TIMEOUT: u16 = 4;
+const NFTA_SET_ELEM_EXPIRATION: u16 = 5;
+const NFTA_SET_ELEM_USERDATA: u16 = 6;
+const NFTA_SET_ELEM_EXPR: u16 = 7;
+const NFTA_SET_ELEM_OBJREF: u16 = 9;
+const NFTA_SET_ELEM_KEY_END: u16 = 10;
+const NFTA_SET_ELEM_EXPRESSIONS: u16 = 11;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetElementAttribute {
+    // The fields of a concatenated key are each padded to 4 bytes, see
+    // DataAttribute::concat()
+    Key(Vec<DataAttribute>),
+    // Only for maps, either a DataAttribute::Value or a
+    // DataAttribute::Verdict
+    Data(Vec<DataAttribute>),
+    Flags(SetElementFlags),
+    // In milliseconds
+    Timeout(u64),
+    // Remaining time before the element expires, in milliseconds
+    Expiration(u64),
+    Userdata(Vec<u8>),
+    Expr(Expression),
+    // Name of the object of an object map
+    Objref(String),
+    // End of the range of an interval set with concatenated keys, the
+    // other interval sets use an element with SetElementFlags::IntervalEnd
+    KeyEnd(Vec<DataAttribute>),
+    Expressions(Vec<Expression>),
+    Other(DefaultNla),
+}
+
+impl Nla for SetElementAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SetElementAttribute::Key(attr) => attr.as_slice().buffer_len(),
+            SetElementAttribute::Data(attr) => attr.as_slice().buffer_len(),
+            SetElementAttribute::Flags(_) => size_of::<u32>(),
+            SetElementAttribute::Timeout(attr) => size_of_val(attr),
+            SetElementAttribute::Expiration(attr) => size_of_val(attr),
+            SetElementAttribute::Userdata(attr) => attr.len(),
+            SetElementAttribute::Expr(attr) => attr.value_len(),
+            SetElementAttribute::Objref(attr) => attr.len() + 1,
+            SetElementAttribute::KeyEnd(attr) => attr.as_slice().buffer_len(),
+            SetElementAttribute::Expressions(attr) => {
+                attr.as_slice().buffer_len()
+            }
+            SetElementAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SetElementAttribute::Key(_) => NFTA_SET_ELEM_KEY,
+            SetElementAttribute::Data(_) => NFTA_SET_ELEM_DATA,
+            SetElementAttribute::Flags(_) => NFTA_SET_ELEM_FLAGS,
+            SetElementAttribute::Timeout(_) => NFTA_SET_ELEM_TIMEOUT,
+            SetElementAttribute::Expiration(_) => NFTA_SET_ELEM_EXPIRATION,
+            SetElementAttribute::Userdata(_) => NFTA_SET_ELEM_USERDATA,
+            SetElementAttribute::Expr(_) => NFTA_SET_ELEM_EXPR,
+            SetElementAttribute::Objref(_) => NFTA_SET_ELEM_OBJREF,
+            SetElementAttribute::KeyEnd(_) => NFTA_SET_ELEM_KEY_END,
+            SetElementAttribute::Expressions(_) => NFTA_SET_ELEM_EXPRESSIONS,
+            SetElementAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SetElementAttribute::Key(attr) => attr.as_slice().emit(buffer),
+            SetElementAttribute::Data(attr) => attr.as_slice().emit(buffer),
+            SetElementAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            SetElementAttribute::Timeout(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            SetElementAttribute::Expiration(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            SetElementAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            SetElementAttribute::Expr(attr) => attr.emit_value(buffer),
+            SetElementAttribute::Objref(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SetElementAttribute::KeyEnd(attr) => attr.as_slice().emit(buffer),
+            SetElementAttribute::Expressions(attr) => {
+                attr.as_slice().emit(buffer)
+            }
+            SetElementAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            SetElementAttribute::Key(_)
+                | SetElementAttribute::Data(_)
+                | SetElementAttribute::Expr(_)
+                | SetElementAttribute::KeyEnd(_)
+                | SetElementAttribute::Expressions(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SetElementAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SET_ELEM_KEY => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_SET_ELEM_KEY value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                SetElementAttribute::Key(data_attributes)
+            }
+            NFTA_SET_ELEM_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_SET_ELEM_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                SetElementAttribute::Data(data_attributes)
+            }
+            NFTA_SET_ELEM_FLAGS => {
+                SetElementAttribute::Flags(SetElementFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_SET_ELEM_FLAGS value")?,
+                ))
+            }
+            NFTA_SET_ELEM_TIMEOUT => SetElementAttribute::Timeout(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_SET_ELEM_TIMEOUT value")?,
+            ),
+            NFTA_SET_ELEM_EXPIRATION => SetElementAttribute::Expiration(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_SET_ELEM_EXPIRATION value")?,
+            ),
+            NFTA_SET_ELEM_USERDATA => {
+                SetElementAttribute::Userdata(payload.to_vec())
+            }
+            NFTA_SET_ELEM_EXPR => {
+                SetElementAttribute::Expr(Expression::parse(buf)?)
+            }
+            NFTA_SET_ELEM_OBJREF => SetElementAttribute::Objref(
+                parse_string(payload)
+                    .context("invalid NFTA_SET_ELEM_OBJREF value")?,
+            ),
+            NFTA_SET_ELEM_KEY_END => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_SET_ELEM_KEY_END value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                SetElementAttribute::KeyEnd(data_attributes)
+            }
+            NFTA_SET_ELEM_EXPRESSIONS => {
+                let mut expressions = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas
+                        .context("invalid NFTA_SET_ELEM_EXPRESSIONS value")?;
+                    expressions.push(Expression::parse(nlas)?);
+                }
+                SetElementAttribute::Expressions(expressions)
+            }
+            _ => SetElementAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
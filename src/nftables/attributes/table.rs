@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use crate::nftables::attributes::flags::TableFlags;
+
+const NFTA_TABLE_NAME: u16 = 1;
+const NFTA_TABLE_FLAGS: u16 = 2;
+const NFTA_TABLE_USE: u16 = 3;
+const NFTA_TABLE_HANDLE: u16 = 4;
+const NFTA_TABLE_USERDATA: u16 = 6;
+const NFTA_TABLE_OWNER: u16 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TableAttribute {
+    Name(String),
+    Flags(TableFlags),
+    // Number of chains in the table
+    Use(u32),
+    Handle(u64),
+    Userdata(Vec<u8>),
+    // Netlink port id of the owner, see TableFlags::Owner
+    Owner(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TableAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            TableAttribute::Name(attr) => attr.len() + 1,
+            TableAttribute::Flags(_) => size_of::<u32>(),
+            TableAttribute::Use(attr) => size_of_val(attr),
+            TableAttribute::Handle(attr) => size_of_val(attr),
+            TableAttribute::Userdata(attr) => attr.len(),
+            TableAttribute::Owner(attr) => size_of_val(attr),
+            TableAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            TableAttribute::Name(_) => NFTA_TABLE_NAME,
+            TableAttribute::Flags(_) => NFTA_TABLE_FLAGS,
+            TableAttribute::Use(_) => NFTA_TABLE_USE,
+            TableAttribute::Handle(_) => NFTA_TABLE_HANDLE,
+            TableAttribute::Userdata(_) => NFTA_TABLE_USERDATA,
+            TableAttribute::Owner(_) => NFTA_TABLE_OWNER,
+            TableAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            TableAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            TableAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            TableAttribute::Use(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TableAttribute::Handle(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            TableAttribute::Userdata(attr) => buffer.copy_from_slice(attr),
+            TableAttribute::Owner(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            TableAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for TableAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_TABLE_NAME => TableAttribute::Name(
+                parse_string(payload)
+                    .context("invalid NFTA_TABLE_NAME value")?,
+            ),
+            NFTA_TABLE_FLAGS => {
+                TableAttribute::Flags(TableFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_TABLE_FLAGS value")?,
+                ))
+            }
+            NFTA_TABLE_USE => TableAttribute::Use(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_TABLE_USE value")?,
+            ),
+            NFTA_TABLE_HANDLE => TableAttribute::Handle(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_TABLE_HANDLE value")?,
+            ),
+            NFTA_TABLE_USERDATA => TableAttribute::Userdata(payload.to_vec()),
+            NFTA_TABLE_OWNER => TableAttribute::Owner(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_TABLE_OWNER value")?,
+            ),
+            _ => TableAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::nftables::expressions::{DataAttribute, Register};
+
+const NFTA_BITWISE_SREG: u16 = 1;
+const NFTA_BITWISE_DREG: u16 = 2;
+const NFTA_BITWISE_LEN: u16 = 3;
+const NFTA_BITWISE_MASK: u16 = 4;
+const NFTA_BITWISE_XOR: u16 = 5;
+const NFTA_BITWISE_OP: u16 = 6;
+const NFTA_BITWISE_DATA: u16 = 7;
+
+// dreg = (sreg & mask) ^ xor for BitwiseOp::Bool
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitwiseAttribute {
+    Sreg(Register),
+    Dreg(Register),
+    Len(u32),
+    Mask(Vec<DataAttribute>),
+    Xor(Vec<DataAttribute>),
+    Op(BitwiseOp),
+    // Shift amount of BitwiseOp::Lshift and BitwiseOp::Rshift
+    Data(Vec<DataAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for BitwiseAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            BitwiseAttribute::Sreg(_) => size_of::<u32>(),
+            BitwiseAttribute::Dreg(_) => size_of::<u32>(),
+            BitwiseAttribute::Len(attr) => size_of_val(attr),
+            BitwiseAttribute::Mask(attr) => attr.as_slice().buffer_len(),
+            BitwiseAttribute::Xor(attr) => attr.as_slice().buffer_len(),
+            BitwiseAttribute::Op(_) => size_of::<u32>(),
+            BitwiseAttribute::Data(attr) => attr.as_slice().buffer_len(),
+            BitwiseAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            BitwiseAttribute::Sreg(_) => NFTA_BITWISE_SREG,
+            BitwiseAttribute::Dreg(_) => NFTA_BITWISE_DREG,
+            BitwiseAttribute::Len(_) => NFTA_BITWISE_LEN,
+            BitwiseAttribute::Mask(_) => NFTA_BITWISE_MASK,
+            BitwiseAttribute::Xor(_) => NFTA_BITWISE_XOR,
+            BitwiseAttribute::Op(_) => NFTA_BITWISE_OP,
+            BitwiseAttribute::Data(_) => NFTA_BITWISE_DATA,
+            BitwiseAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            BitwiseAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            BitwiseAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            BitwiseAttribute::Len(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            BitwiseAttribute::Mask(attr) => attr.as_slice().emit(buffer),
+            BitwiseAttribute::Xor(attr) => attr.as_slice().emit(buffer),
+            BitwiseAttribute::Op(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            BitwiseAttribute::Data(attr) => attr.as_slice().emit(buffer),
+            BitwiseAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            BitwiseAttribute::Mask(_)
+                | BitwiseAttribute::Xor(_)
+                | BitwiseAttribute::Data(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for BitwiseAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_BITWISE_SREG => BitwiseAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BITWISE_SREG value")?
+                    .into(),
+            ),
+            NFTA_BITWISE_DREG => BitwiseAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BITWISE_DREG value")?
+                    .into(),
+            ),
+            NFTA_BITWISE_LEN => BitwiseAttribute::Len(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BITWISE_LEN value")?,
+            ),
+            NFTA_BITWISE_MASK => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_BITWISE_MASK value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                BitwiseAttribute::Mask(data_attributes)
+            }
+            NFTA_BITWISE_XOR => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_BITWISE_XOR value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                BitwiseAttribute::Xor(data_attributes)
+            }
+            NFTA_BITWISE_OP => BitwiseAttribute::Op(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BITWISE_OP value")?
+                    .into(),
+            ),
+            NFTA_BITWISE_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_BITWISE_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                BitwiseAttribute::Data(data_attributes)
+            }
+            _ => BitwiseAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_BITWISE_BOOL: u32 = 0;
+const NFT_BITWISE_LSHIFT: u32 = 1;
+const NFT_BITWISE_RSHIFT: u32 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitwiseOp {
+    Bool,
+    Lshift,
+    Rshift,
+    Other(u32),
+}
+
+impl From<u32> for BitwiseOp {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_BITWISE_BOOL => Self::Bool,
+            NFT_BITWISE_LSHIFT => Self::Lshift,
+            NFT_BITWISE_RSHIFT => Self::Rshift,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<BitwiseOp> for u32 {
+    fn from(value: BitwiseOp) -> Self {
+        match value {
+            BitwiseOp::Bool => NFT_BITWISE_BOOL,
+            BitwiseOp::Lshift => NFT_BITWISE_LSHIFT,
+            BitwiseOp::Rshift => NFT_BITWISE_RSHIFT,
+            BitwiseOp::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_BYTEORDER_SREG: u16 = 1;
+const NFTA_BYTEORDER_DREG: u16 = 2;
+const NFTA_BYTEORDER_OP: u16 = 3;
+const NFTA_BYTEORDER_LEN: u16 = 4;
+const NFTA_BYTEORDER_SIZE: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ByteorderAttribute {
+    Sreg(Register),
+    Dreg(Register),
+    Op(ByteorderOp),
+    Len(u32),
+    // Size of the converted words: 2, 4 or 8
+    Size(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ByteorderAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ByteorderAttribute::Sreg(_) => size_of::<u32>(),
+            ByteorderAttribute::Dreg(_) => size_of::<u32>(),
+            ByteorderAttribute::Op(_) => size_of::<u32>(),
+            ByteorderAttribute::Len(attr) => size_of_val(attr),
+            ByteorderAttribute::Size(attr) => size_of_val(attr),
+            ByteorderAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ByteorderAttribute::Sreg(_) => NFTA_BYTEORDER_SREG,
+            ByteorderAttribute::Dreg(_) => NFTA_BYTEORDER_DREG,
+            ByteorderAttribute::Op(_) => NFTA_BYTEORDER_OP,
+            ByteorderAttribute::Len(_) => NFTA_BYTEORDER_LEN,
+            ByteorderAttribute::Size(_) => NFTA_BYTEORDER_SIZE,
+            ByteorderAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ByteorderAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ByteorderAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ByteorderAttribute::Op(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ByteorderAttribute::Len(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ByteorderAttribute::Size(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ByteorderAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ByteorderAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_BYTEORDER_SREG => ByteorderAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BYTEORDER_SREG value")?
+                    .into(),
+            ),
+            NFTA_BYTEORDER_DREG => ByteorderAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BYTEORDER_DREG value")?
+                    .into(),
+            ),
+            NFTA_BYTEORDER_OP => ByteorderAttribute::Op(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BYTEORDER_OP value")?
+                    .into(),
+            ),
+            NFTA_BYTEORDER_LEN => ByteorderAttribute::Len(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BYTEORDER_LEN value")?,
+            ),
+            NFTA_BYTEORDER_SIZE => ByteorderAttribute::Size(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_BYTEORDER_SIZE value")?,
+            ),
+            _ => ByteorderAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_BYTEORDER_NTOH: u32 = 0;
+const NFT_BYTEORDER_HTON: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ByteorderOp {
+    Ntoh,
+    Hton,
+    Other(u32),
+}
+
+impl From<u32> for ByteorderOp {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_BYTEORDER_NTOH => Self::Ntoh,
+            NFT_BYTEORDER_HTON => Self::Hton,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<ByteorderOp> for u32 {
+    fn from(value: ByteorderOp) -> Self {
+        match value {
+            ByteorderOp::Ntoh => NFT_BYTEORDER_NTOH,
+            ByteorderOp::Hton => NFT_BYTEORDER_HTON,
+            ByteorderOp::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::nftables::expressions::{DataAttribute, Register};
+
+const NFTA_CMP_SREG: u16 = 1;
+const NFTA_CMP_OP: u16 = 2;
+const NFTA_CMP_DATA: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CmpAttribute {
+    Sreg(Register),
+    Op(CmpOp),
+    Data(Vec<DataAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for CmpAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            CmpAttribute::Sreg(_) => size_of::<u32>(),
+            CmpAttribute::Op(_) => size_of::<u32>(),
+            CmpAttribute::Data(attr) => attr.as_slice().buffer_len(),
+            CmpAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            CmpAttribute::Sreg(_) => NFTA_CMP_SREG,
+            CmpAttribute::Op(_) => NFTA_CMP_OP,
+            CmpAttribute::Data(_) => NFTA_CMP_DATA,
+            CmpAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            CmpAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            CmpAttribute::Op(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            CmpAttribute::Data(attr) => attr.as_slice().emit(buffer),
+            CmpAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, CmpAttribute::Data(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for CmpAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CMP_SREG => CmpAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CMP_SREG value")?
+                    .into(),
+            ),
+            NFTA_CMP_OP => CmpAttribute::Op(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CMP_OP value")?
+                    .into(),
+            ),
+            NFTA_CMP_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_CMP_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                CmpAttribute::Data(data_attributes)
+            }
+            _ => CmpAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_CMP_EQ: u32 = 0;
+const NFT_CMP_NEQ: u32 = 1;
+const NFT_CMP_LT: u32 = 2;
+const NFT_CMP_LTE: u32 = 3;
+const NFT_CMP_GT: u32 = 4;
+const NFT_CMP_GTE: u32 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CmpOp {
+    Eq,
+    Neq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Other(u32),
+}
+
+impl From<u32> for CmpOp {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_CMP_EQ => Self::Eq,
+            NFT_CMP_NEQ => Self::Neq,
+            NFT_CMP_LT => Self::Lt,
+            NFT_CMP_LTE => Self::Lte,
+            NFT_CMP_GT => Self::Gt,
+            NFT_CMP_GTE => Self::Gte,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<CmpOp> for u32 {
+    fn from(value: CmpOp) -> Self {
+        match value {
+            CmpOp::Eq => NFT_CMP_EQ,
+            CmpOp::Neq => NFT_CMP_NEQ,
+            CmpOp::Lt => NFT_CMP_LT,
+            CmpOp::Lte => NFT_CMP_LTE,
+            CmpOp::Gt => NFT_CMP_GT,
+            CmpOp::Gte => NFT_CMP_GTE,
+            CmpOp::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+const NFTA_CONNLIMIT_COUNT: u16 = 1;
+const NFTA_CONNLIMIT_FLAGS: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnlimitAttribute {
+    Count(u32),
+    Flags(ConnlimitFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for ConnlimitAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ConnlimitAttribute::Count(attr) => size_of_val(attr),
+            ConnlimitAttribute::Flags(_) => size_of::<u32>(),
+            ConnlimitAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ConnlimitAttribute::Count(_) => NFTA_CONNLIMIT_COUNT,
+            ConnlimitAttribute::Flags(_) => NFTA_CONNLIMIT_FLAGS,
+            ConnlimitAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ConnlimitAttribute::Count(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            ConnlimitAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            ConnlimitAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ConnlimitAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CONNLIMIT_COUNT => ConnlimitAttribute::Count(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CONNLIMIT_COUNT value")?,
+            ),
+            NFTA_CONNLIMIT_FLAGS => {
+                ConnlimitAttribute::Flags(ConnlimitFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_CONNLIMIT_FLAGS value")?,
+                ))
+            }
+            _ => ConnlimitAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_CONNLIMIT_F_INV: u32 = 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct ConnlimitFlags: u32 {
+        // Matches when the count is not exceeded
+        const Inv = NFT_CONNLIMIT_F_INV;
+        const _ = !0;
+    }
+}
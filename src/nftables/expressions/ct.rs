@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, parse_u8, DecodeError, DefaultNla, ErrorContext,
+    Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+use crate::{
+    conntrack::{Protocol, Status},
+    nftables::expressions::{DataAttribute, Register},
+};
+
+const NFTA_CT_DREG: u16 = 1;
+const NFTA_CT_KEY: u16 = 2;
+const NFTA_CT_DIRECTION: u16 = 3;
+const NFTA_CT_SREG: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtAttribute {
+    Dreg(Register),
+    Key(CtKey),
+    // Required by the keys which differ between both directions, e.g.
+    // CtKey::Src
+    Direction(CtDirection),
+    // Set instead of Dreg to set the key, e.g. ct mark set
+    Sreg(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for CtAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            CtAttribute::Dreg(_) => size_of::<u32>(),
+            CtAttribute::Key(_) => size_of::<u32>(),
+            CtAttribute::Direction(_) => size_of::<u8>(),
+            CtAttribute::Sreg(_) => size_of::<u32>(),
+            CtAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            CtAttribute::Dreg(_) => NFTA_CT_DREG,
+            CtAttribute::Key(_) => NFTA_CT_KEY,
+            CtAttribute::Direction(_) => NFTA_CT_DIRECTION,
+            CtAttribute::Sreg(_) => NFTA_CT_SREG,
+            CtAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            CtAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            CtAttribute::Key(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            CtAttribute::Direction(attr) => buffer[0] = (*attr).into(),
+            CtAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            CtAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for CtAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CT_DREG => CtAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CT_DREG value")?
+                    .into(),
+            ),
+            NFTA_CT_KEY => CtAttribute::Key(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CT_KEY value")?
+                    .into(),
+            ),
+            NFTA_CT_DIRECTION => CtAttribute::Direction(
+                parse_u8(payload)
+                    .context("invalid NFTA_CT_DIRECTION value")?
+                    .into(),
+            ),
+            NFTA_CT_SREG => CtAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CT_SREG value")?
+                    .into(),
+            ),
+            _ => CtAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_CT_STATE: u32 = 0;
+const NFT_CT_DIRECTION: u32 = 1;
+const NFT_CT_STATUS: u32 = 2;
+const NFT_CT_MARK: u32 = 3;
+const NFT_CT_SECMARK: u32 = 4;
+const NFT_CT_EXPIRATION: u32 = 5;
+const NFT_CT_HELPER: u32 = 6;
+const NFT_CT_L3PROTOCOL: u32 = 7;
+const NFT_CT_SRC: u32 = 8;
+const NFT_CT_DST: u32 = 9;
+const NFT_CT_PROTOCOL: u32 = 10;
+const NFT_CT_PROTO_SRC: u32 = 11;
+const NFT_CT_PROTO_DST: u32 = 12;
+const NFT_CT_LABELS: u32 = 13;
+const NFT_CT_PKTS: u32 = 14;
+const NFT_CT_BYTES: u32 = 15;
+const NFT_CT_AVGPKT: u32 = 16;
+const NFT_CT_ZONE: u32 = 17;
+const NFT_CT_EVENTMASK: u32 = 18;
+const NFT_CT_SRC_IP: u32 = 19;
+const NFT_CT_DST_IP: u32 = 20;
+const NFT_CT_SRC_IP6: u32 = 21;
+const NFT_CT_DST_IP6: u32 = 22;
+const NFT_CT_ID: u32 = 23;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtKey {
+    // Loads a CtState
+    State,
+    Direction,
+    // Loads a conntrack::Status
+    Status,
+    Mark,
+    Secmark,
+    Expiration,
+    Helper,
+    L3Protocol,
+    Src,
+    Dst,
+    // Loads a conntrack::Protocol
+    Protocol,
+    ProtoSrc,
+    ProtoDst,
+    Labels,
+    Pkts,
+    Bytes,
+    AvgPkt,
+    Zone,
+    EventMask,
+    SrcIp,
+    DstIp,
+    SrcIp6,
+    DstIp6,
+    Id,
+    Other(u32),
+}
+
+impl From<u32> for CtKey {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_CT_STATE => Self::State,
+            NFT_CT_DIRECTION => Self::Direction,
+            NFT_CT_STATUS => Self::Status,
+            NFT_CT_MARK => Self::Mark,
+            NFT_CT_SECMARK => Self::Secmark,
+            NFT_CT_EXPIRATION => Self::Expiration,
+            NFT_CT_HELPER => Self::Helper,
+            NFT_CT_L3PROTOCOL => Self::L3Protocol,
+            NFT_CT_SRC => Self::Src,
+            NFT_CT_DST => Self::Dst,
+            NFT_CT_PROTOCOL => Self::Protocol,
+            NFT_CT_PROTO_SRC => Self::ProtoSrc,
+            NFT_CT_PROTO_DST => Self::ProtoDst,
+            NFT_CT_LABELS => Self::Labels,
+            NFT_CT_PKTS => Self::Pkts,
+            NFT_CT_BYTES => Self::Bytes,
+            NFT_CT_AVGPKT => Self::AvgPkt,
+            NFT_CT_ZONE => Self::Zone,
+            NFT_CT_EVENTMASK => Self::EventMask,
+            NFT_CT_SRC_IP => Self::SrcIp,
+            NFT_CT_DST_IP => Self::DstIp,
+            NFT_CT_SRC_IP6 => Self::SrcIp6,
+            NFT_CT_DST_IP6 => Self::DstIp6,
+            NFT_CT_ID => Self::Id,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<CtKey> for u32 {
+    fn from(value: CtKey) -> Self {
+        match value {
+            CtKey::State => NFT_CT_STATE,
+            CtKey::Direction => NFT_CT_DIRECTION,
+            CtKey::Status => NFT_CT_STATUS,
+            CtKey::Mark => NFT_CT_MARK,
+            CtKey::Secmark => NFT_CT_SECMARK,
+            CtKey::Expiration => NFT_CT_EXPIRATION,
+            CtKey::Helper => NFT_CT_HELPER,
+            CtKey::L3Protocol => NFT_CT_L3PROTOCOL,
+            CtKey::Src => NFT_CT_SRC,
+            CtKey::Dst => NFT_CT_DST,
+            CtKey::Protocol => NFT_CT_PROTOCOL,
+            CtKey::ProtoSrc => NFT_CT_PROTO_SRC,
+            CtKey::ProtoDst => NFT_CT_PROTO_DST,
+            CtKey::Labels => NFT_CT_LABELS,
+            CtKey::Pkts => NFT_CT_PKTS,
+            CtKey::Bytes => NFT_CT_BYTES,
+            CtKey::AvgPkt => NFT_CT_AVGPKT,
+            CtKey::Zone => NFT_CT_ZONE,
+            CtKey::EventMask => NFT_CT_EVENTMASK,
+            CtKey::SrcIp => NFT_CT_SRC_IP,
+            CtKey::DstIp => NFT_CT_DST_IP,
+            CtKey::SrcIp6 => NFT_CT_SRC_IP6,
+            CtKey::DstIp6 => NFT_CT_DST_IP6,
+            CtKey::Id => NFT_CT_ID,
+            CtKey::Other(v) => v,
+        }
+    }
+}
+
+const IP_CT_DIR_ORIGINAL: u8 = 0;
+const IP_CT_DIR_REPLY: u8 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtDirection {
+    Original,
+    Reply,
+    Other(u8),
+}
+
+impl From<u8> for CtDirection {
+    fn from(value: u8) -> Self {
+        match value {
+            IP_CT_DIR_ORIGINAL => Self::Original,
+            IP_CT_DIR_REPLY => Self::Reply,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<CtDirection> for u8 {
+    fn from(value: CtDirection) -> Self {
+        match value {
+            CtDirection::Original => IP_CT_DIR_ORIGINAL,
+            CtDirection::Reply => IP_CT_DIR_REPLY,
+            CtDirection::Other(v) => v,
+        }
+    }
+}
+
+const NF_CT_STATE_INVALID_BIT: u32 = 1;
+const NF_CT_STATE_ESTABLISHED_BIT: u32 = 1 << 1;
+const NF_CT_STATE_RELATED_BIT: u32 = 1 << 2;
+const NF_CT_STATE_NEW_BIT: u32 = 1 << 3;
+const NF_CT_STATE_UNTRACKED_BIT: u32 = 1 << 6;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct CtState: u32 {
+        const Invalid = NF_CT_STATE_INVALID_BIT;
+        const Established = NF_CT_STATE_ESTABLISHED_BIT;
+        const Related = NF_CT_STATE_RELATED_BIT;
+        const New = NF_CT_STATE_NEW_BIT;
+        const Untracked = NF_CT_STATE_UNTRACKED_BIT;
+        const _ = !0;
+    }
+}
+
+// The ct keys are loaded in host byte order, these build the value to compare
+// them with, e.g. with CmpAttribute::Data or BitwiseAttribute::Mask
+impl From<CtState> for DataAttribute {
+    fn from(state: CtState) -> Self {
+        DataAttribute::Value(state.bits().to_ne_bytes().to_vec())
+    }
+}
+
+impl From<Status> for DataAttribute {
+    fn from(status: Status) -> Self {
+        DataAttribute::Value(status.bits().to_ne_bytes().to_vec())
+    }
+}
+
+impl From<Protocol> for DataAttribute {
+    fn from(protocol: Protocol) -> Self {
+        DataAttribute::Value(vec![protocol.into()])
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_i32_be, emit_u32_be, parse_i32_be, parse_string, parse_u32_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+const NFTA_DATA_VALUE: u16 = 1;
+const NFTA_DATA_VERDICT: u16 = 2;
+
+// Content of the NFTA_*_DATA attributes of the expressions, set elements and
+// maps
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DataAttribute {
+    // Raw bytes, in the byte order of the packet for the fields matched
+    // against it
+    Value(Vec<u8>),
+    Verdict(Vec<VerdictAttribute>),
+    Other(DefaultNla),
+}
+
+impl DataAttribute {
+    // Value of a concatenated key, e.g. ip . port, where each field is padded
+    // to a multiple of 4 bytes
+    pub fn concat(fields: &[&[u8]]) -> Self {
+        let mut value = Vec::new();
+        for field in fields {
+            value.extend_from_slice(field);
+            value.resize((value.len() + 3) & !3, 0);
+        }
+        DataAttribute::Value(value)
+    }
+}
+
+impl Nla for DataAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            DataAttribute::Value(attr) => attr.len(),
+            DataAttribute::Verdict(attr) => attr.as_slice().buffer_len(),
+            DataAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            DataAttribute::Value(_) => NFTA_DATA_VALUE,
+            DataAttribute::Verdict(_) => NFTA_DATA_VERDICT,
+            DataAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            DataAttribute::Value(attr) => buffer.copy_from_slice(attr),
+            DataAttribute::Verdict(attr) => attr.as_slice().emit(buffer),
+            DataAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, DataAttribute::Verdict(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for DataAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_DATA_VALUE => DataAttribute::Value(payload.to_vec()),
+            NFTA_DATA_VERDICT => {
+                let mut verdict_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_DATA_VERDICT value")?;
+                    verdict_attributes.push(VerdictAttribute::parse(nlas)?);
+                }
+                DataAttribute::Verdict(verdict_attributes)
+            }
+            _ => DataAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFTA_VERDICT_CODE: u16 = 1;
+const NFTA_VERDICT_CHAIN: u16 = 2;
+const NFTA_VERDICT_CHAIN_ID: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerdictAttribute {
+    Code(VerdictCode),
+    // Target of VerdictCode::Jump and VerdictCode::Goto
+    Chain(String),
+    ChainId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for VerdictAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            VerdictAttribute::Code(_) => size_of::<i32>(),
+            VerdictAttribute::Chain(attr) => attr.len() + 1,
+            VerdictAttribute::ChainId(attr) => size_of_val(attr),
+            VerdictAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            VerdictAttribute::Code(_) => NFTA_VERDICT_CODE,
+            VerdictAttribute::Chain(_) => NFTA_VERDICT_CHAIN,
+            VerdictAttribute::ChainId(_) => NFTA_VERDICT_CHAIN_ID,
+            VerdictAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            VerdictAttribute::Code(attr) => {
+                emit_i32_be(buffer, (*attr).into()).unwrap()
+            }
+            VerdictAttribute::Chain(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            VerdictAttribute::ChainId(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            VerdictAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for VerdictAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_VERDICT_CODE => VerdictAttribute::Code(
+                parse_i32_be(payload)
+                    .context("invalid NFTA_VERDICT_CODE value")?
+                    .into(),
+            ),
+            NFTA_VERDICT_CHAIN => VerdictAttribute::Chain(
+                parse_string(payload)
+                    .context("invalid NFTA_VERDICT_CHAIN value")?,
+            ),
+            NFTA_VERDICT_CHAIN_ID => VerdictAttribute::ChainId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_VERDICT_CHAIN_ID value")?,
+            ),
+            _ => VerdictAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NF_DROP: i32 = 0;
+const NF_ACCEPT: i32 = 1;
+const NF_QUEUE: i32 = 3;
+const NFT_CONTINUE: i32 = -1;
+const NFT_BREAK: i32 = -2;
+const NFT_JUMP: i32 = -3;
+const NFT_GOTO: i32 = -4;
+const NFT_RETURN: i32 = -5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerdictCode {
+    Drop,
+    Accept,
+    Queue,
+    Continue,
+    // Stops the evaluation of the current rule
+    Break,
+    Jump,
+    Goto,
+    Return,
+    Other(i32),
+}
+
+impl From<i32> for VerdictCode {
+    fn from(value: i32) -> Self {
+        match value {
+            NF_DROP => Self::Drop,
+            NF_ACCEPT => Self::Accept,
+            NF_QUEUE => Self::Queue,
+            NFT_CONTINUE => Self::Continue,
+            NFT_BREAK => Self::Break,
+            NFT_JUMP => Self::Jump,
+            NFT_GOTO => Self::Goto,
+            NFT_RETURN => Self::Return,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<VerdictCode> for i32 {
+    fn from(value: VerdictCode) -> Self {
+        match value {
+            VerdictCode::Drop => NF_DROP,
+            VerdictCode::Accept => NF_ACCEPT,
+            VerdictCode::Queue => NF_QUEUE,
+            VerdictCode::Continue => NFT_CONTINUE,
+            VerdictCode::Break => NFT_BREAK,
+            VerdictCode::Jump => NFT_JUMP,
+            VerdictCode::Goto => NFT_GOTO,
+            VerdictCode::Return => NFT_RETURN,
+            VerdictCode::Other(v) => v,
+        }
+    }
+}
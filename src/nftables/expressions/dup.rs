@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_DUP_SREG_ADDR: u16 = 1;
+const NFTA_DUP_SREG_DEV: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DupAttribute {
+    SregAddr(Register),
+    SregDev(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for DupAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            DupAttribute::SregAddr(_) => size_of::<u32>(),
+            DupAttribute::SregDev(_) => size_of::<u32>(),
+            DupAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            DupAttribute::SregAddr(_) => NFTA_DUP_SREG_ADDR,
+            DupAttribute::SregDev(_) => NFTA_DUP_SREG_DEV,
+            DupAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            DupAttribute::SregAddr(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            DupAttribute::SregDev(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            DupAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for DupAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_DUP_SREG_ADDR => DupAttribute::SregAddr(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DUP_SREG_ADDR value")?
+                    .into(),
+            ),
+            NFTA_DUP_SREG_DEV => DupAttribute::SregDev(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DUP_SREG_DEV value")?
+                    .into(),
+            ),
+            _ => DupAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_string, parse_u32_be, parse_u64_be,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable,
+};
+
+use bitflags::bitflags;
+
+use crate::nftables::expressions::{Expression, Register};
+
+const NFTA_DYNSET_SET_NAME: u16 = 1;
+const NFTA_DYNSET_SET_ID: u16 = 2;
+const NFTA_DYNSET_OP: u16 = 3;
+const NFTA_DYNSET_SREG_KEY: u16 = 4;
+const NFTA_DYNSET_SREG_DATA: u16 = 5;
+const NFTA_DYNSET_TIMEOUT: u16 = 6;
+const NFTA_DYNSET_EXPR: u16 = 7;
+const NFTA_DYNSET_FLAGS: u16 = 9;
+const NFTA_DYNSET_EXPRESSIONS: u16 = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynsetAttribute {
+    SetName(String),
+    SetId(u32),
+    Op(DynsetOp),
+    SregKey(Register),
+    SregData(Register),
+    // In milliseconds
+    Timeout(u64),
+    // Single stateful expression attached to the element, see Expressions
+    // for several of them
+    Expr(Expression),
+    Flags(DynsetFlags),
+    Expressions(Vec<Expression>),
+    Other(DefaultNla),
+}
+
+impl Nla for DynsetAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            DynsetAttribute::SetName(attr) => attr.len() + 1,
+            DynsetAttribute::SetId(attr) => size_of_val(attr),
+            DynsetAttribute::Op(_) => size_of::<u32>(),
+            DynsetAttribute::SregKey(_) => size_of::<u32>(),
+            DynsetAttribute::SregData(_) => size_of::<u32>(),
+            DynsetAttribute::Timeout(attr) => size_of_val(attr),
+            DynsetAttribute::Expr(attr) => attr.value_len(),
+            DynsetAttribute::Flags(_) => size_of::<u32>(),
+            DynsetAttribute::Expressions(attr) => attr.as_slice().buffer_len(),
+            DynsetAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            DynsetAttribute::SetName(_) => NFTA_DYNSET_SET_NAME,
+            DynsetAttribute::SetId(_) => NFTA_DYNSET_SET_ID,
+            DynsetAttribute::Op(_) => NFTA_DYNSET_OP,
+            DynsetAttribute::SregKey(_) => NFTA_DYNSET_SREG_KEY,
+            DynsetAttribute::SregData(_) => NFTA_DYNSET_SREG_DATA,
+            DynsetAttribute::Timeout(_) => NFTA_DYNSET_TIMEOUT,
+            DynsetAttribute::Expr(_) => NFTA_DYNSET_EXPR,
+            DynsetAttribute::Flags(_) => NFTA_DYNSET_FLAGS,
+            DynsetAttribute::Expressions(_) => NFTA_DYNSET_EXPRESSIONS,
+            DynsetAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            DynsetAttribute::SetName(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            DynsetAttribute::SetId(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            DynsetAttribute::Op(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            DynsetAttribute::SregKey(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            DynsetAttribute::SregData(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            DynsetAttribute::Timeout(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            DynsetAttribute::Expr(attr) => attr.emit_value(buffer),
+            DynsetAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            DynsetAttribute::Expressions(attr) => attr.as_slice().emit(buffer),
+            DynsetAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            DynsetAttribute::Expr(_) | DynsetAttribute::Expressions(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for DynsetAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_DYNSET_SET_NAME => DynsetAttribute::SetName(
+                parse_string(payload)
+                    .context("invalid NFTA_DYNSET_SET_NAME value")?,
+            ),
+            NFTA_DYNSET_SET_ID => DynsetAttribute::SetId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DYNSET_SET_ID value")?,
+            ),
+            NFTA_DYNSET_OP => DynsetAttribute::Op(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DYNSET_OP value")?
+                    .into(),
+            ),
+            NFTA_DYNSET_SREG_KEY => DynsetAttribute::SregKey(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DYNSET_SREG_KEY value")?
+                    .into(),
+            ),
+            NFTA_DYNSET_SREG_DATA => DynsetAttribute::SregData(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_DYNSET_SREG_DATA value")?
+                    .into(),
+            ),
+            NFTA_DYNSET_TIMEOUT => DynsetAttribute::Timeout(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_DYNSET_TIMEOUT value")?,
+            ),
+            NFTA_DYNSET_EXPR => DynsetAttribute::Expr(Expression::parse(buf)?),
+            NFTA_DYNSET_FLAGS => {
+                DynsetAttribute::Flags(DynsetFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_DYNSET_FLAGS value")?,
+                ))
+            }
+            NFTA_DYNSET_EXPRESSIONS => {
+                let mut expressions = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas
+                        .context("invalid NFTA_DYNSET_EXPRESSIONS value")?;
+                    expressions.push(Expression::parse(nlas)?);
+                }
+                DynsetAttribute::Expressions(expressions)
+            }
+            _ => DynsetAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_DYNSET_OP_ADD: u32 = 0;
+const NFT_DYNSET_OP_UPDATE: u32 = 1;
+const NFT_DYNSET_OP_DELETE: u32 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynsetOp {
+    Add,
+    Update,
+    Delete,
+    Other(u32),
+}
+
+impl From<u32> for DynsetOp {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_DYNSET_OP_ADD => Self::Add,
+            NFT_DYNSET_OP_UPDATE => Self::Update,
+            NFT_DYNSET_OP_DELETE => Self::Delete,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<DynsetOp> for u32 {
+    fn from(value: DynsetOp) -> Self {
+        match value {
+            DynsetOp::Add => NFT_DYNSET_OP_ADD,
+            DynsetOp::Update => NFT_DYNSET_OP_UPDATE,
+            DynsetOp::Delete => NFT_DYNSET_OP_DELETE,
+            DynsetOp::Other(v) => v,
+        }
+    }
+}
+
+const NFT_DYNSET_F_INV: u32 = 1;
+const NFT_DYNSET_F_EXPR: u32 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct DynsetFlags: u32 {
+        const Inv = NFT_DYNSET_F_INV;
+        const Expr = NFT_DYNSET_F_EXPR;
+        const _ = !0;
+    }
+}
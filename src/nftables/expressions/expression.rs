@@ -0,0 +1,528 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_string, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
+    NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::nftables::{
+    attributes::Counter,
+    expressions::{
+        BitwiseAttribute, ByteorderAttribute, CmpAttribute, ConnlimitAttribute,
+        CtAttribute, DupAttribute, DynsetAttribute, FwdAttribute,
+        HashAttribute, ImmediateAttribute, LimitAttribute, LogAttribute,
+        LookupAttribute, MasqAttribute, MetaAttribute, NatAttribute,
+        NumgenAttribute, ObjrefAttribute, PayloadAttribute, QueueAttribute,
+        QuotaAttribute, RangeAttribute, RedirAttribute, RejectAttribute,
+        TproxyAttribute,
+    },
+};
+
+const NFTA_LIST_ELEM: u16 = 1;
+
+const NFTA_EXPR_NAME: u16 = 1;
+const NFTA_EXPR_DATA: u16 = 2;
+
+const BITWISE: &str = "bitwise";
+const BYTEORDER: &str = "byteorder";
+const CMP: &str = "cmp";
+const CONNLIMIT: &str = "connlimit";
+const COUNTER: &str = "counter";
+const CT: &str = "ct";
+const DUP: &str = "dup";
+const DYNSET: &str = "dynset";
+const FWD: &str = "fwd";
+const HASH: &str = "hash";
+const IMMEDIATE: &str = "immediate";
+const LIMIT: &str = "limit";
+const LOG: &str = "log";
+const LOOKUP: &str = "lookup";
+const MASQ: &str = "masq";
+const META: &str = "meta";
+const NAT: &str = "nat";
+const NOTRACK: &str = "notrack";
+const NUMGEN: &str = "numgen";
+const OBJREF: &str = "objref";
+const PAYLOAD: &str = "payload";
+const QUEUE: &str = "queue";
+const QUOTA: &str = "quota";
+const RANGE: &str = "range";
+const REDIR: &str = "redir";
+const REJECT: &str = "reject";
+const TPROXY: &str = "tproxy";
+
+// One element of a list of expressions, e.g. NFTA_RULE_EXPRESSIONS. The
+// kernel identifies the expression by its name and the attributes of its
+// NFTA_EXPR_DATA depend on it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Expression {
+    Bitwise(Vec<BitwiseAttribute>),
+    Byteorder(Vec<ByteorderAttribute>),
+    Cmp(Vec<CmpAttribute>),
+    Connlimit(Vec<ConnlimitAttribute>),
+    Counter(Vec<Counter>),
+    Ct(Vec<CtAttribute>),
+    Dup(Vec<DupAttribute>),
+    Dynset(Vec<DynsetAttribute>),
+    Fwd(Vec<FwdAttribute>),
+    Hash(Vec<HashAttribute>),
+    Immediate(Vec<ImmediateAttribute>),
+    Limit(Vec<LimitAttribute>),
+    Log(Vec<LogAttribute>),
+    Lookup(Vec<LookupAttribute>),
+    Masq(Vec<MasqAttribute>),
+    Meta(Vec<MetaAttribute>),
+    Nat(Vec<NatAttribute>),
+    Notrack,
+    Numgen(Vec<NumgenAttribute>),
+    Objref(Vec<ObjrefAttribute>),
+    Payload(Vec<PayloadAttribute>),
+    Queue(Vec<QueueAttribute>),
+    Quota(Vec<QuotaAttribute>),
+    Range(Vec<RangeAttribute>),
+    Redir(Vec<RedirAttribute>),
+    Reject(Vec<RejectAttribute>),
+    Tproxy(Vec<TproxyAttribute>),
+    // Expressions this crate does not know about, the NFTA_EXPR_DATA
+    // attribute is kept as is so that it can be emitted again unchanged
+    Other {
+        name: String,
+        data: Option<DefaultNla>,
+    },
+}
+
+impl Expression {
+    pub fn name(&self) -> &str {
+        match self {
+            Expression::Bitwise(_) => BITWISE,
+            Expression::Byteorder(_) => BYTEORDER,
+            Expression::Cmp(_) => CMP,
+            Expression::Connlimit(_) => CONNLIMIT,
+            Expression::Counter(_) => COUNTER,
+            Expression::Ct(_) => CT,
+            Expression::Dup(_) => DUP,
+            Expression::Dynset(_) => DYNSET,
+            Expression::Fwd(_) => FWD,
+            Expression::Hash(_) => HASH,
+            Expression::Immediate(_) => IMMEDIATE,
+            Expression::Limit(_) => LIMIT,
+            Expression::Log(_) => LOG,
+            Expression::Lookup(_) => LOOKUP,
+            Expression::Masq(_) => MASQ,
+            Expression::Meta(_) => META,
+            Expression::Nat(_) => NAT,
+            Expression::Notrack => NOTRACK,
+            Expression::Numgen(_) => NUMGEN,
+            Expression::Objref(_) => OBJREF,
+            Expression::Payload(_) => PAYLOAD,
+            Expression::Queue(_) => QUEUE,
+            Expression::Quota(_) => QUOTA,
+            Expression::Range(_) => RANGE,
+            Expression::Redir(_) => REDIR,
+            Expression::Reject(_) => REJECT,
+            Expression::Tproxy(_) => TPROXY,
+            Expression::Other { name, .. } => name,
+        }
+    }
+
+    fn data_len(&self) -> usize {
+        match self {
+            Expression::Bitwise(attr) => attr.as_slice().buffer_len(),
+            Expression::Byteorder(attr) => attr.as_slice().buffer_len(),
+            Expression::Cmp(attr) => attr.as_slice().buffer_len(),
+            Expression::Connlimit(attr) => attr.as_slice().buffer_len(),
+            Expression::Counter(attr) => attr.as_slice().buffer_len(),
+            Expression::Ct(attr) => attr.as_slice().buffer_len(),
+            Expression::Dup(attr) => attr.as_slice().buffer_len(),
+            Expression::Dynset(attr) => attr.as_slice().buffer_len(),
+            Expression::Fwd(attr) => attr.as_slice().buffer_len(),
+            Expression::Hash(attr) => attr.as_slice().buffer_len(),
+            Expression::Immediate(attr) => attr.as_slice().buffer_len(),
+            Expression::Limit(attr) => attr.as_slice().buffer_len(),
+            Expression::Log(attr) => attr.as_slice().buffer_len(),
+            Expression::Lookup(attr) => attr.as_slice().buffer_len(),
+            Expression::Masq(attr) => attr.as_slice().buffer_len(),
+            Expression::Meta(attr) => attr.as_slice().buffer_len(),
+            Expression::Nat(attr) => attr.as_slice().buffer_len(),
+            Expression::Numgen(attr) => attr.as_slice().buffer_len(),
+            Expression::Objref(attr) => attr.as_slice().buffer_len(),
+            Expression::Payload(attr) => attr.as_slice().buffer_len(),
+            Expression::Queue(attr) => attr.as_slice().buffer_len(),
+            Expression::Quota(attr) => attr.as_slice().buffer_len(),
+            Expression::Range(attr) => attr.as_slice().buffer_len(),
+            Expression::Redir(attr) => attr.as_slice().buffer_len(),
+            Expression::Reject(attr) => attr.as_slice().buffer_len(),
+            Expression::Tproxy(attr) => attr.as_slice().buffer_len(),
+            Expression::Notrack => 0,
+            Expression::Other { data, .. } => {
+                data.as_ref().map_or(0, |data| data.value_len())
+            }
+        }
+    }
+
+    fn emit_data(&self, buffer: &mut [u8]) {
+        match self {
+            Expression::Bitwise(attr) => attr.as_slice().emit(buffer),
+            Expression::Byteorder(attr) => attr.as_slice().emit(buffer),
+            Expression::Cmp(attr) => attr.as_slice().emit(buffer),
+            Expression::Connlimit(attr) => attr.as_slice().emit(buffer),
+            Expression::Counter(attr) => attr.as_slice().emit(buffer),
+            Expression::Ct(attr) => attr.as_slice().emit(buffer),
+            Expression::Dup(attr) => attr.as_slice().emit(buffer),
+            Expression::Dynset(attr) => attr.as_slice().emit(buffer),
+            Expression::Fwd(attr) => attr.as_slice().emit(buffer),
+            Expression::Hash(attr) => attr.as_slice().emit(buffer),
+            Expression::Immediate(attr) => attr.as_slice().emit(buffer),
+            Expression::Limit(attr) => attr.as_slice().emit(buffer),
+            Expression::Log(attr) => attr.as_slice().emit(buffer),
+            Expression::Lookup(attr) => attr.as_slice().emit(buffer),
+            Expression::Masq(attr) => attr.as_slice().emit(buffer),
+            Expression::Meta(attr) => attr.as_slice().emit(buffer),
+            Expression::Nat(attr) => attr.as_slice().emit(buffer),
+            Expression::Numgen(attr) => attr.as_slice().emit(buffer),
+            Expression::Objref(attr) => attr.as_slice().emit(buffer),
+            Expression::Payload(attr) => attr.as_slice().emit(buffer),
+            Expression::Queue(attr) => attr.as_slice().emit(buffer),
+            Expression::Quota(attr) => attr.as_slice().emit(buffer),
+            Expression::Range(attr) => attr.as_slice().emit(buffer),
+            Expression::Redir(attr) => attr.as_slice().emit(buffer),
+            Expression::Reject(attr) => attr.as_slice().emit(buffer),
+            Expression::Tproxy(attr) => attr.as_slice().emit(buffer),
+            Expression::Notrack => (),
+            Expression::Other { data, .. } => {
+                if let Some(data) = data {
+                    data.emit_value(buffer)
+                }
+            }
+        }
+    }
+
+    fn attributes(&self) -> (ExpressionName<'_>, Option<ExpressionData<'_>>) {
+        let data = match self {
+            Expression::Notrack | Expression::Other { data: None, .. } => None,
+            _ => Some(ExpressionData(self)),
+        };
+        (ExpressionName(self.name()), data)
+    }
+}
+
+impl Nla for Expression {
+    fn value_len(&self) -> usize {
+        let (name, data) = self.attributes();
+        name.buffer_len() + data.map_or(0, |data| data.buffer_len())
+    }
+
+    fn kind(&self) -> u16 {
+        NFTA_LIST_ELEM
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        let (name, data) = self.attributes();
+        name.emit(buffer);
+        if let Some(data) = data {
+            data.emit(&mut buffer[name.buffer_len()..]);
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        true
+    }
+}
+
+// Also parses the single expressions which are not wrapped in a
+// NFTA_LIST_ELEM, e.g. NFTA_DYNSET_EXPR
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for Expression
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let mut name = None;
+        let mut data = None;
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla.context("invalid NFTA_LIST_ELEM value")?;
+            match nla.kind() {
+                NFTA_EXPR_NAME => {
+                    name = Some(
+                        parse_string(nla.value())
+                            .context("invalid NFTA_EXPR_NAME value")?,
+                    )
+                }
+                NFTA_EXPR_DATA => data = Some(nla),
+                _ => (),
+            }
+        }
+        let name =
+            name.ok_or_else(|| DecodeError::from("missing NFTA_EXPR_NAME"))?;
+        let Some(data) = data else {
+            return Ok(match name.as_str() {
+                NOTRACK => Expression::Notrack,
+                _ => Expression::Other { name, data: None },
+            });
+        };
+        let payload = data.value();
+        let expression = match name.as_str() {
+            BITWISE => {
+                let mut bitwise_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    bitwise_attributes.push(BitwiseAttribute::parse(nlas)?);
+                }
+                Expression::Bitwise(bitwise_attributes)
+            }
+            BYTEORDER => {
+                let mut byteorder_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    byteorder_attributes.push(ByteorderAttribute::parse(nlas)?);
+                }
+                Expression::Byteorder(byteorder_attributes)
+            }
+            CMP => {
+                let mut cmp_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    cmp_attributes.push(CmpAttribute::parse(nlas)?);
+                }
+                Expression::Cmp(cmp_attributes)
+            }
+            CONNLIMIT => {
+                let mut connlimit_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    connlimit_attributes.push(ConnlimitAttribute::parse(nlas)?);
+                }
+                Expression::Connlimit(connlimit_attributes)
+            }
+            COUNTER => {
+                let mut counters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    counters.push(Counter::parse(nlas)?);
+                }
+                Expression::Counter(counters)
+            }
+            CT => {
+                let mut ct_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    ct_attributes.push(CtAttribute::parse(nlas)?);
+                }
+                Expression::Ct(ct_attributes)
+            }
+            DUP => {
+                let mut dup_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    dup_attributes.push(DupAttribute::parse(nlas)?);
+                }
+                Expression::Dup(dup_attributes)
+            }
+            DYNSET => {
+                let mut dynset_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    dynset_attributes.push(DynsetAttribute::parse(nlas)?);
+                }
+                Expression::Dynset(dynset_attributes)
+            }
+            FWD => {
+                let mut fwd_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    fwd_attributes.push(FwdAttribute::parse(nlas)?);
+                }
+                Expression::Fwd(fwd_attributes)
+            }
+            HASH => {
+                let mut hash_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    hash_attributes.push(HashAttribute::parse(nlas)?);
+                }
+                Expression::Hash(hash_attributes)
+            }
+            IMMEDIATE => {
+                let mut immediate_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    immediate_attributes.push(ImmediateAttribute::parse(nlas)?);
+                }
+                Expression::Immediate(immediate_attributes)
+            }
+            LIMIT => {
+                let mut limit_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    limit_attributes.push(LimitAttribute::parse(nlas)?);
+                }
+                Expression::Limit(limit_attributes)
+            }
+            LOG => {
+                let mut log_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    log_attributes.push(LogAttribute::parse(nlas)?);
+                }
+                Expression::Log(log_attributes)
+            }
+            LOOKUP => {
+                let mut lookup_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    lookup_attributes.push(LookupAttribute::parse(nlas)?);
+                }
+                Expression::Lookup(lookup_attributes)
+            }
+            MASQ => {
+                let mut masq_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    masq_attributes.push(MasqAttribute::parse(nlas)?);
+                }
+                Expression::Masq(masq_attributes)
+            }
+            META => {
+                let mut meta_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    meta_attributes.push(MetaAttribute::parse(nlas)?);
+                }
+                Expression::Meta(meta_attributes)
+            }
+            NAT => {
+                let mut nat_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    nat_attributes.push(NatAttribute::parse(nlas)?);
+                }
+                Expression::Nat(nat_attributes)
+            }
+            NUMGEN => {
+                let mut numgen_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    numgen_attributes.push(NumgenAttribute::parse(nlas)?);
+                }
+                Expression::Numgen(numgen_attributes)
+            }
+            OBJREF => {
+                let mut objref_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    objref_attributes.push(ObjrefAttribute::parse(nlas)?);
+                }
+                Expression::Objref(objref_attributes)
+            }
+            PAYLOAD => {
+                let mut payload_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    payload_attributes.push(PayloadAttribute::parse(nlas)?);
+                }
+                Expression::Payload(payload_attributes)
+            }
+            QUEUE => {
+                let mut queue_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    queue_attributes.push(QueueAttribute::parse(nlas)?);
+                }
+                Expression::Queue(queue_attributes)
+            }
+            QUOTA => {
+                let mut quota_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    quota_attributes.push(QuotaAttribute::parse(nlas)?);
+                }
+                Expression::Quota(quota_attributes)
+            }
+            RANGE => {
+                let mut range_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    range_attributes.push(RangeAttribute::parse(nlas)?);
+                }
+                Expression::Range(range_attributes)
+            }
+            REDIR => {
+                let mut redir_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    redir_attributes.push(RedirAttribute::parse(nlas)?);
+                }
+                Expression::Redir(redir_attributes)
+            }
+            REJECT => {
+                let mut reject_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    reject_attributes.push(RejectAttribute::parse(nlas)?);
+                }
+                Expression::Reject(reject_attributes)
+            }
+            TPROXY => {
+                let mut tproxy_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_EXPR_DATA value")?;
+                    tproxy_attributes.push(TproxyAttribute::parse(nlas)?);
+                }
+                Expression::Tproxy(tproxy_attributes)
+            }
+            _ => Expression::Other {
+                name,
+                data: Some(DefaultNla::parse(&data)?),
+            },
+        };
+        Ok(expression)
+    }
+}
+
+struct ExpressionName<'a>(&'a str);
+
+impl Nla for ExpressionName<'_> {
+    fn value_len(&self) -> usize {
+        self.0.len() + 1
+    }
+
+    fn kind(&self) -> u16 {
+        NFTA_EXPR_NAME
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        buffer[..self.0.len()].copy_from_slice(self.0.as_bytes());
+        buffer[self.0.len()] = 0;
+    }
+}
+
+struct ExpressionData<'a>(&'a Expression);
+
+impl Nla for ExpressionData<'_> {
+    fn value_len(&self) -> usize {
+        self.0.data_len()
+    }
+
+    fn kind(&self) -> u16 {
+        match self.0 {
+            Expression::Other {
+                data: Some(data), ..
+            } => data.kind(),
+            _ => NFTA_EXPR_DATA,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.emit_data(buffer)
+    }
+
+    // The flags of an unknown expression are emitted as they were parsed,
+    // e.g. nft sets NLA_F_NESTED while the kernel dumps do not
+    fn is_nested(&self) -> bool {
+        match self.0 {
+            Expression::Other {
+                data: Some(data), ..
+            } => data.is_nested(),
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::{message::ProtoFamily, nftables::expressions::Register};
+
+const NFTA_FWD_SREG_DEV: u16 = 1;
+const NFTA_FWD_SREG_ADDR: u16 = 2;
+const NFTA_FWD_NFPROTO: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FwdAttribute {
+    SregDev(Register),
+    SregAddr(Register),
+    NfProto(ProtoFamily),
+    Other(DefaultNla),
+}
+
+impl Nla for FwdAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            FwdAttribute::SregDev(_) => size_of::<u32>(),
+            FwdAttribute::SregAddr(_) => size_of::<u32>(),
+            FwdAttribute::NfProto(_) => size_of::<u32>(),
+            FwdAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            FwdAttribute::SregDev(_) => NFTA_FWD_SREG_DEV,
+            FwdAttribute::SregAddr(_) => NFTA_FWD_SREG_ADDR,
+            FwdAttribute::NfProto(_) => NFTA_FWD_NFPROTO,
+            FwdAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            FwdAttribute::SregDev(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            FwdAttribute::SregAddr(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            FwdAttribute::NfProto(attr) => {
+                emit_u32_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            FwdAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for FwdAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_FWD_SREG_DEV => FwdAttribute::SregDev(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_FWD_SREG_DEV value")?
+                    .into(),
+            ),
+            NFTA_FWD_SREG_ADDR => FwdAttribute::SregAddr(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_FWD_SREG_ADDR value")?
+                    .into(),
+            ),
+            NFTA_FWD_NFPROTO => FwdAttribute::NfProto(
+                (parse_u32_be(payload)
+                    .context("invalid NFTA_FWD_NFPROTO value")?
+                    as u8)
+                    .into(),
+            ),
+            _ => FwdAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
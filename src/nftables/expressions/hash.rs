@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_HASH_SREG: u16 = 1;
+const NFTA_HASH_DREG: u16 = 2;
+const NFTA_HASH_LEN: u16 = 3;
+const NFTA_HASH_MODULUS: u16 = 4;
+const NFTA_HASH_SEED: u16 = 5;
+const NFTA_HASH_OFFSET: u16 = 6;
+const NFTA_HASH_TYPE: u16 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HashAttribute {
+    Sreg(Register),
+    Dreg(Register),
+    Len(u32),
+    Modulus(u32),
+    Seed(u32),
+    Offset(u32),
+    Type(HashType),
+    Other(DefaultNla),
+}
+
+impl Nla for HashAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            HashAttribute::Sreg(_) => size_of::<u32>(),
+            HashAttribute::Dreg(_) => size_of::<u32>(),
+            HashAttribute::Len(attr) => size_of_val(attr),
+            HashAttribute::Modulus(attr) => size_of_val(attr),
+            HashAttribute::Seed(attr) => size_of_val(attr),
+            HashAttribute::Offset(attr) => size_of_val(attr),
+            HashAttribute::Type(_) => size_of::<u32>(),
+            HashAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HashAttribute::Sreg(_) => NFTA_HASH_SREG,
+            HashAttribute::Dreg(_) => NFTA_HASH_DREG,
+            HashAttribute::Len(_) => NFTA_HASH_LEN,
+            HashAttribute::Modulus(_) => NFTA_HASH_MODULUS,
+            HashAttribute::Seed(_) => NFTA_HASH_SEED,
+            HashAttribute::Offset(_) => NFTA_HASH_OFFSET,
+            HashAttribute::Type(_) => NFTA_HASH_TYPE,
+            HashAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HashAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            HashAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            HashAttribute::Len(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            HashAttribute::Modulus(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            HashAttribute::Seed(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            HashAttribute::Offset(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            HashAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            HashAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for HashAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_HASH_SREG => HashAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_SREG value")?
+                    .into(),
+            ),
+            NFTA_HASH_DREG => HashAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_DREG value")?
+                    .into(),
+            ),
+            NFTA_HASH_LEN => HashAttribute::Len(
+                parse_u32_be(payload).context("invalid NFTA_HASH_LEN value")?,
+            ),
+            NFTA_HASH_MODULUS => HashAttribute::Modulus(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_MODULUS value")?,
+            ),
+            NFTA_HASH_SEED => HashAttribute::Seed(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_SEED value")?,
+            ),
+            NFTA_HASH_OFFSET => HashAttribute::Offset(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_OFFSET value")?,
+            ),
+            NFTA_HASH_TYPE => HashAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_HASH_TYPE value")?
+                    .into(),
+            ),
+            _ => HashAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_HASH_JENKINS: u32 = 0;
+const NFT_HASH_SYM: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HashType {
+    Jenkins,
+    // Symmetric hash of the packet addresses and ports, without Sreg
+    Sym,
+    Other(u32),
+}
+
+impl From<u32> for HashType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_HASH_JENKINS => Self::Jenkins,
+            NFT_HASH_SYM => Self::Sym,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<HashType> for u32 {
+    fn from(value: HashType) -> Self {
+        match value {
+            HashType::Jenkins => NFT_HASH_JENKINS,
+            HashType::Sym => NFT_HASH_SYM,
+            HashType::Other(v) => v,
+        }
+    }
+}
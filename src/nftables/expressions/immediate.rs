@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::nftables::expressions::{DataAttribute, Register};
+
+const NFTA_IMMEDIATE_DREG: u16 = 1;
+const NFTA_IMMEDIATE_DATA: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImmediateAttribute {
+    // Register::Verdict for verdicts
+    Dreg(Register),
+    Data(Vec<DataAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for ImmediateAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ImmediateAttribute::Dreg(_) => size_of::<u32>(),
+            ImmediateAttribute::Data(attr) => attr.as_slice().buffer_len(),
+            ImmediateAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ImmediateAttribute::Dreg(_) => NFTA_IMMEDIATE_DREG,
+            ImmediateAttribute::Data(_) => NFTA_IMMEDIATE_DATA,
+            ImmediateAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ImmediateAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ImmediateAttribute::Data(attr) => attr.as_slice().emit(buffer),
+            ImmediateAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ImmediateAttribute::Data(_))
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ImmediateAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_IMMEDIATE_DREG => ImmediateAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_IMMEDIATE_DREG value")?
+                    .into(),
+            ),
+            NFTA_IMMEDIATE_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_IMMEDIATE_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                ImmediateAttribute::Data(data_attributes)
+            }
+            _ => ImmediateAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_u32_be, parse_u64_be, DecodeError,
+    DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+const NFTA_LIMIT_RATE: u16 = 1;
+const NFTA_LIMIT_UNIT: u16 = 2;
+const NFTA_LIMIT_BURST: u16 = 3;
+const NFTA_LIMIT_TYPE: u16 = 4;
+const NFTA_LIMIT_FLAGS: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LimitAttribute {
+    Rate(u64),
+    // In seconds
+    Unit(u64),
+    Burst(u32),
+    Type(LimitType),
+    Flags(LimitFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for LimitAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            LimitAttribute::Rate(attr) => size_of_val(attr),
+            LimitAttribute::Unit(attr) => size_of_val(attr),
+            LimitAttribute::Burst(attr) => size_of_val(attr),
+            LimitAttribute::Type(_) => size_of::<u32>(),
+            LimitAttribute::Flags(_) => size_of::<u32>(),
+            LimitAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            LimitAttribute::Rate(_) => NFTA_LIMIT_RATE,
+            LimitAttribute::Unit(_) => NFTA_LIMIT_UNIT,
+            LimitAttribute::Burst(_) => NFTA_LIMIT_BURST,
+            LimitAttribute::Type(_) => NFTA_LIMIT_TYPE,
+            LimitAttribute::Flags(_) => NFTA_LIMIT_FLAGS,
+            LimitAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            LimitAttribute::Rate(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            LimitAttribute::Unit(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            LimitAttribute::Burst(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            LimitAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            LimitAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            LimitAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for LimitAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_LIMIT_RATE => LimitAttribute::Rate(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_LIMIT_RATE value")?,
+            ),
+            NFTA_LIMIT_UNIT => LimitAttribute::Unit(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_LIMIT_UNIT value")?,
+            ),
+            NFTA_LIMIT_BURST => LimitAttribute::Burst(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LIMIT_BURST value")?,
+            ),
+            NFTA_LIMIT_TYPE => LimitAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LIMIT_TYPE value")?
+                    .into(),
+            ),
+            NFTA_LIMIT_FLAGS => {
+                LimitAttribute::Flags(LimitFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_LIMIT_FLAGS value")?,
+                ))
+            }
+            _ => LimitAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_LIMIT_PKTS: u32 = 0;
+const NFT_LIMIT_PKT_BYTES: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LimitType {
+    Packets,
+    Bytes,
+    Other(u32),
+}
+
+impl From<u32> for LimitType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_LIMIT_PKTS => Self::Packets,
+            NFT_LIMIT_PKT_BYTES => Self::Bytes,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<LimitType> for u32 {
+    fn from(value: LimitType) -> Self {
+        match value {
+            LimitType::Packets => NFT_LIMIT_PKTS,
+            LimitType::Bytes => NFT_LIMIT_PKT_BYTES,
+            LimitType::Other(v) => v,
+        }
+    }
+}
+
+const NFT_LIMIT_F_INV: u32 = 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct LimitFlags: u32 {
+        // Matches when over the limit
+        const Inv = NFT_LIMIT_F_INV;
+        const _ = !0;
+    }
+}
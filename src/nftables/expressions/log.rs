@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_string, parse_u16_be, parse_u32_be,
+    DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+const NFTA_LOG_GROUP: u16 = 1;
+const NFTA_LOG_PREFIX: u16 = 2;
+const NFTA_LOG_SNAPLEN: u16 = 3;
+const NFTA_LOG_QTHRESHOLD: u16 = 4;
+const NFTA_LOG_LEVEL: u16 = 5;
+const NFTA_LOG_FLAGS: u16 = 6;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LogAttribute {
+    // Sends the packets to this nflog group instead of the kernel log
+    Group(u16),
+    Prefix(String),
+    Snaplen(u32),
+    QThreshold(u16),
+    Level(LogLevel),
+    Flags(LogFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for LogAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            LogAttribute::Group(attr) => size_of_val(attr),
+            LogAttribute::Prefix(attr) => attr.len() + 1,
+            LogAttribute::Snaplen(attr) => size_of_val(attr),
+            LogAttribute::QThreshold(attr) => size_of_val(attr),
+            LogAttribute::Level(_) => size_of::<u32>(),
+            LogAttribute::Flags(_) => size_of::<u32>(),
+            LogAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            LogAttribute::Group(_) => NFTA_LOG_GROUP,
+            LogAttribute::Prefix(_) => NFTA_LOG_PREFIX,
+            LogAttribute::Snaplen(_) => NFTA_LOG_SNAPLEN,
+            LogAttribute::QThreshold(_) => NFTA_LOG_QTHRESHOLD,
+            LogAttribute::Level(_) => NFTA_LOG_LEVEL,
+            LogAttribute::Flags(_) => NFTA_LOG_FLAGS,
+            LogAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            LogAttribute::Group(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            LogAttribute::Prefix(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            LogAttribute::Snaplen(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            LogAttribute::QThreshold(attr) => {
+                emit_u16_be(buffer, *attr).unwrap()
+            }
+            LogAttribute::Level(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            LogAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            LogAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for LogAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_LOG_GROUP => LogAttribute::Group(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_LOG_GROUP value")?,
+            ),
+            NFTA_LOG_PREFIX => LogAttribute::Prefix(
+                parse_string(payload)
+                    .context("invalid NFTA_LOG_PREFIX value")?,
+            ),
+            NFTA_LOG_SNAPLEN => LogAttribute::Snaplen(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOG_SNAPLEN value")?,
+            ),
+            NFTA_LOG_QTHRESHOLD => LogAttribute::QThreshold(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_LOG_QTHRESHOLD value")?,
+            ),
+            NFTA_LOG_LEVEL => LogAttribute::Level(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOG_LEVEL value")?
+                    .into(),
+            ),
+            NFTA_LOG_FLAGS => LogAttribute::Flags(LogFlags::from_bits_retain(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOG_FLAGS value")?,
+            )),
+            _ => LogAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_LOGLEVEL_EMERG: u32 = 0;
+const NFT_LOGLEVEL_ALERT: u32 = 1;
+const NFT_LOGLEVEL_CRIT: u32 = 2;
+const NFT_LOGLEVEL_ERR: u32 = 3;
+const NFT_LOGLEVEL_WARNING: u32 = 4;
+const NFT_LOGLEVEL_NOTICE: u32 = 5;
+const NFT_LOGLEVEL_INFO: u32 = 6;
+const NFT_LOGLEVEL_DEBUG: u32 = 7;
+const NFT_LOGLEVEL_AUDIT: u32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LogLevel {
+    Emerg,
+    Alert,
+    Crit,
+    Err,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+    Audit,
+    Other(u32),
+}
+
+impl From<u32> for LogLevel {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_LOGLEVEL_EMERG => Self::Emerg,
+            NFT_LOGLEVEL_ALERT => Self::Alert,
+            NFT_LOGLEVEL_CRIT => Self::Crit,
+            NFT_LOGLEVEL_ERR => Self::Err,
+            NFT_LOGLEVEL_WARNING => Self::Warning,
+            NFT_LOGLEVEL_NOTICE => Self::Notice,
+            NFT_LOGLEVEL_INFO => Self::Info,
+            NFT_LOGLEVEL_DEBUG => Self::Debug,
+            NFT_LOGLEVEL_AUDIT => Self::Audit,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<LogLevel> for u32 {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Emerg => NFT_LOGLEVEL_EMERG,
+            LogLevel::Alert => NFT_LOGLEVEL_ALERT,
+            LogLevel::Crit => NFT_LOGLEVEL_CRIT,
+            LogLevel::Err => NFT_LOGLEVEL_ERR,
+            LogLevel::Warning => NFT_LOGLEVEL_WARNING,
+            LogLevel::Notice => NFT_LOGLEVEL_NOTICE,
+            LogLevel::Info => NFT_LOGLEVEL_INFO,
+            LogLevel::Debug => NFT_LOGLEVEL_DEBUG,
+            LogLevel::Audit => NFT_LOGLEVEL_AUDIT,
+            LogLevel::Other(v) => v,
+        }
+    }
+}
+
+const NF_LOG_TCPSEQ: u32 = 1;
+const NF_LOG_TCPOPT: u32 = 1 << 1;
+const NF_LOG_IPOPT: u32 = 1 << 2;
+const NF_LOG_UID: u32 = 1 << 3;
+const NF_LOG_MACDECODE: u32 = 1 << 5;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct LogFlags: u32 {
+        const TcpSeq = NF_LOG_TCPSEQ;
+        const TcpOpt = NF_LOG_TCPOPT;
+        const IpOpt = NF_LOG_IPOPT;
+        const Uid = NF_LOG_UID;
+        const MacDecode = NF_LOG_MACDECODE;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_string, parse_u32_be, DecodeError, DefaultNla,
+    ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+use crate::nftables::expressions::Register;
+
+const NFTA_LOOKUP_SET: u16 = 1;
+const NFTA_LOOKUP_SREG: u16 = 2;
+const NFTA_LOOKUP_DREG: u16 = 3;
+const NFTA_LOOKUP_SET_ID: u16 = 4;
+const NFTA_LOOKUP_FLAGS: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LookupAttribute {
+    Set(String),
+    Sreg(Register),
+    // Only for maps, receives the data of the element found
+    Dreg(Register),
+    // Identifies a set created in the same batch
+    SetId(u32),
+    Flags(LookupFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for LookupAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            LookupAttribute::Set(attr) => attr.len() + 1,
+            LookupAttribute::Sreg(_) => size_of::<u32>(),
+            LookupAttribute::Dreg(_) => size_of::<u32>(),
+            LookupAttribute::SetId(attr) => size_of_val(attr),
+            LookupAttribute::Flags(_) => size_of::<u32>(),
+            LookupAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            LookupAttribute::Set(_) => NFTA_LOOKUP_SET,
+            LookupAttribute::Sreg(_) => NFTA_LOOKUP_SREG,
+            LookupAttribute::Dreg(_) => NFTA_LOOKUP_DREG,
+            LookupAttribute::SetId(_) => NFTA_LOOKUP_SET_ID,
+            LookupAttribute::Flags(_) => NFTA_LOOKUP_FLAGS,
+            LookupAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            LookupAttribute::Set(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            LookupAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            LookupAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            LookupAttribute::SetId(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            LookupAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            LookupAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for LookupAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_LOOKUP_SET => LookupAttribute::Set(
+                parse_string(payload)
+                    .context("invalid NFTA_LOOKUP_SET value")?,
+            ),
+            NFTA_LOOKUP_SREG => LookupAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOOKUP_SREG value")?
+                    .into(),
+            ),
+            NFTA_LOOKUP_DREG => LookupAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOOKUP_DREG value")?
+                    .into(),
+            ),
+            NFTA_LOOKUP_SET_ID => LookupAttribute::SetId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_LOOKUP_SET_ID value")?,
+            ),
+            NFTA_LOOKUP_FLAGS => {
+                LookupAttribute::Flags(LookupFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_LOOKUP_FLAGS value")?,
+                ))
+            }
+            _ => LookupAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_LOOKUP_F_INV: u32 = 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct LookupFlags: u32 {
+        // Matches when the key is not in the set
+        const Inv = NFT_LOOKUP_F_INV;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::{NatFlags, Register};
+
+const NFTA_MASQ_FLAGS: u16 = 1;
+const NFTA_MASQ_REG_PROTO_MIN: u16 = 2;
+const NFTA_MASQ_REG_PROTO_MAX: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MasqAttribute {
+    Flags(NatFlags),
+    RegProtoMin(Register),
+    RegProtoMax(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for MasqAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            MasqAttribute::Flags(_) => size_of::<u32>(),
+            MasqAttribute::RegProtoMin(_) => size_of::<u32>(),
+            MasqAttribute::RegProtoMax(_) => size_of::<u32>(),
+            MasqAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MasqAttribute::Flags(_) => NFTA_MASQ_FLAGS,
+            MasqAttribute::RegProtoMin(_) => NFTA_MASQ_REG_PROTO_MIN,
+            MasqAttribute::RegProtoMax(_) => NFTA_MASQ_REG_PROTO_MAX,
+            MasqAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MasqAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            MasqAttribute::RegProtoMin(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            MasqAttribute::RegProtoMax(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            MasqAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for MasqAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_MASQ_FLAGS => {
+                MasqAttribute::Flags(NatFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_MASQ_FLAGS value")?,
+                ))
+            }
+            NFTA_MASQ_REG_PROTO_MIN => MasqAttribute::RegProtoMin(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_MASQ_REG_PROTO_MIN value")?
+                    .into(),
+            ),
+            NFTA_MASQ_REG_PROTO_MAX => MasqAttribute::RegProtoMax(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_MASQ_REG_PROTO_MAX value")?
+                    .into(),
+            ),
+            _ => MasqAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
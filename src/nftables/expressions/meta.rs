@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_META_DREG: u16 = 1;
+const NFTA_META_KEY: u16 = 2;
+const NFTA_META_SREG: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MetaAttribute {
+    Dreg(Register),
+    Key(MetaKey),
+    // Set when writing the key, e.g. meta mark set
+    Sreg(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for MetaAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            MetaAttribute::Dreg(_) => size_of::<u32>(),
+            MetaAttribute::Key(_) => size_of::<u32>(),
+            MetaAttribute::Sreg(_) => size_of::<u32>(),
+            MetaAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MetaAttribute::Dreg(_) => NFTA_META_DREG,
+            MetaAttribute::Key(_) => NFTA_META_KEY,
+            MetaAttribute::Sreg(_) => NFTA_META_SREG,
+            MetaAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MetaAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            MetaAttribute::Key(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            MetaAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            MetaAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for MetaAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_META_DREG => MetaAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_META_DREG value")?
+                    .into(),
+            ),
+            NFTA_META_KEY => MetaAttribute::Key(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_META_KEY value")?
+                    .into(),
+            ),
+            NFTA_META_SREG => MetaAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_META_SREG value")?
+                    .into(),
+            ),
+            _ => MetaAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_META_LEN: u32 = 0;
+const NFT_META_PROTOCOL: u32 = 1;
+const NFT_META_PRIORITY: u32 = 2;
+const NFT_META_MARK: u32 = 3;
+const NFT_META_IIF: u32 = 4;
+const NFT_META_OIF: u32 = 5;
+const NFT_META_IIFNAME: u32 = 6;
+const NFT_META_OIFNAME: u32 = 7;
+const NFT_META_IIFTYPE: u32 = 8;
+const NFT_META_OIFTYPE: u32 = 9;
+const NFT_META_SKUID: u32 = 10;
+const NFT_META_SKGID: u32 = 11;
+const NFT_META_NFTRACE: u32 = 12;
+const NFT_META_RTCLASSID: u32 = 13;
+const NFT_META_SECMARK: u32 = 14;
+const NFT_META_NFPROTO: u32 = 15;
+const NFT_META_L4PROTO: u32 = 16;
+const NFT_META_BRI_IIFNAME: u32 = 17;
+const NFT_META_BRI_OIFNAME: u32 = 18;
+const NFT_META_PKTTYPE: u32 = 19;
+const NFT_META_CPU: u32 = 20;
+const NFT_META_IIFGROUP: u32 = 21;
+const NFT_META_OIFGROUP: u32 = 22;
+const NFT_META_CGROUP: u32 = 23;
+const NFT_META_PRANDOM: u32 = 24;
+const NFT_META_SECPATH: u32 = 25;
+const NFT_META_IIFKIND: u32 = 26;
+const NFT_META_OIFKIND: u32 = 27;
+const NFT_META_BRI_IIFPVID: u32 = 28;
+const NFT_META_BRI_IIFVPROTO: u32 = 29;
+const NFT_META_TIME_NS: u32 = 30;
+const NFT_META_TIME_DAY: u32 = 31;
+const NFT_META_TIME_HOUR: u32 = 32;
+const NFT_META_SDIF: u32 = 33;
+const NFT_META_SDIFNAME: u32 = 34;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MetaKey {
+    Len,
+    Protocol,
+    Priority,
+    Mark,
+    Iif,
+    Oif,
+    IifName,
+    OifName,
+    IifType,
+    OifType,
+    SkUid,
+    SkGid,
+    NfTrace,
+    RtClassId,
+    Secmark,
+    NfProto,
+    L4Proto,
+    BriIifName,
+    BriOifName,
+    PktType,
+    Cpu,
+    IifGroup,
+    OifGroup,
+    Cgroup,
+    Prandom,
+    Secpath,
+    IifKind,
+    OifKind,
+    BriIifPvid,
+    BriIifVproto,
+    TimeNs,
+    TimeDay,
+    TimeHour,
+    Sdif,
+    SdifName,
+    Other(u32),
+}
+
+impl From<u32> for MetaKey {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_META_LEN => Self::Len,
+            NFT_META_PROTOCOL => Self::Protocol,
+            NFT_META_PRIORITY => Self::Priority,
+            NFT_META_MARK => Self::Mark,
+            NFT_META_IIF => Self::Iif,
+            NFT_META_OIF => Self::Oif,
+            NFT_META_IIFNAME => Self::IifName,
+            NFT_META_OIFNAME => Self::OifName,
+            NFT_META_IIFTYPE => Self::IifType,
+            NFT_META_OIFTYPE => Self::OifType,
+            NFT_META_SKUID => Self::SkUid,
+            NFT_META_SKGID => Self::SkGid,
+            NFT_META_NFTRACE => Self::NfTrace,
+            NFT_META_RTCLASSID => Self::RtClassId,
+            NFT_META_SECMARK => Self::Secmark,
+            NFT_META_NFPROTO => Self::NfProto,
+            NFT_META_L4PROTO => Self::L4Proto,
+            NFT_META_BRI_IIFNAME => Self::BriIifName,
+            NFT_META_BRI_OIFNAME => Self::BriOifName,
+            NFT_META_PKTTYPE => Self::PktType,
+            NFT_META_CPU => Self::Cpu,
+            NFT_META_IIFGROUP => Self::IifGroup,
+            NFT_META_OIFGROUP => Self::OifGroup,
+            NFT_META_CGROUP => Self::Cgroup,
+            NFT_META_PRANDOM => Self::Prandom,
+            NFT_META_SECPATH => Self::Secpath,
+            NFT_META_IIFKIND => Self::IifKind,
+            NFT_META_OIFKIND => Self::OifKind,
+            NFT_META_BRI_IIFPVID => Self::BriIifPvid,
+            NFT_META_BRI_IIFVPROTO => Self::BriIifVproto,
+            NFT_META_TIME_NS => Self::TimeNs,
+            NFT_META_TIME_DAY => Self::TimeDay,
+            NFT_META_TIME_HOUR => Self::TimeHour,
+            NFT_META_SDIF => Self::Sdif,
+            NFT_META_SDIFNAME => Self::SdifName,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<MetaKey> for u32 {
+    fn from(value: MetaKey) -> Self {
+        match value {
+            MetaKey::Len => NFT_META_LEN,
+            MetaKey::Protocol => NFT_META_PROTOCOL,
+            MetaKey::Priority => NFT_META_PRIORITY,
+            MetaKey::Mark => NFT_META_MARK,
+            MetaKey::Iif => NFT_META_IIF,
+            MetaKey::Oif => NFT_META_OIF,
+            MetaKey::IifName => NFT_META_IIFNAME,
+            MetaKey::OifName => NFT_META_OIFNAME,
+            MetaKey::IifType => NFT_META_IIFTYPE,
+            MetaKey::OifType => NFT_META_OIFTYPE,
+            MetaKey::SkUid => NFT_META_SKUID,
+            MetaKey::SkGid => NFT_META_SKGID,
+            MetaKey::NfTrace => NFT_META_NFTRACE,
+            MetaKey::RtClassId => NFT_META_RTCLASSID,
+            MetaKey::Secmark => NFT_META_SECMARK,
+            MetaKey::NfProto => NFT_META_NFPROTO,
+            MetaKey::L4Proto => NFT_META_L4PROTO,
+            MetaKey::BriIifName => NFT_META_BRI_IIFNAME,
+            MetaKey::BriOifName => NFT_META_BRI_OIFNAME,
+            MetaKey::PktType => NFT_META_PKTTYPE,
+            MetaKey::Cpu => NFT_META_CPU,
+            MetaKey::IifGroup => NFT_META_IIFGROUP,
+            MetaKey::OifGroup => NFT_META_OIFGROUP,
+            MetaKey::Cgroup => NFT_META_CGROUP,
+            MetaKey::Prandom => NFT_META_PRANDOM,
+            MetaKey::Secpath => NFT_META_SECPATH,
+            MetaKey::IifKind => NFT_META_IIFKIND,
+            MetaKey::OifKind => NFT_META_OIFKIND,
+            MetaKey::BriIifPvid => NFT_META_BRI_IIFPVID,
+            MetaKey::BriIifVproto => NFT_META_BRI_IIFVPROTO,
+            MetaKey::TimeNs => NFT_META_TIME_NS,
+            MetaKey::TimeDay => NFT_META_TIME_DAY,
+            MetaKey::TimeHour => NFT_META_TIME_HOUR,
+            MetaKey::Sdif => NFT_META_SDIF,
+            MetaKey::SdifName => NFT_META_SDIFNAME,
+            MetaKey::Other(v) => v,
+        }
+    }
+}
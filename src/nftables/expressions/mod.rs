@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+mod bitwise;
+mod byteorder;
+mod cmp;
+mod connlimit;
+mod ct;
+mod data;
+mod dup;
+mod dynset;
+mod expression;
+mod fwd;
+mod hash;
+mod immediate;
+mod limit;
+mod log;
+mod lookup;
+mod masq;
+mod meta;
+mod nat;
+mod numgen;
+mod objref;
+mod payload;
+mod queue;
+mod quota;
+mod range;
+mod redir;
+mod register;
+mod reject;
+mod tproxy;
+
+pub use bitwise::{BitwiseAttribute, BitwiseOp};
+pub use byteorder::{ByteorderAttribute, ByteorderOp};
+pub use cmp::{CmpAttribute, CmpOp};
+pub use connlimit::{ConnlimitAttribute, ConnlimitFlags};
+pub use ct::{CtAttribute, CtDirection, CtKey, CtState};
+pub use data::{DataAttribute, VerdictAttribute, VerdictCode};
+pub use dup::DupAttribute;
+pub use dynset::{DynsetAttribute, DynsetFlags, DynsetOp};
+pub use expression::Expression;
+pub use fwd::FwdAttribute;
+pub use hash::{HashAttribute, HashType};
+pub use immediate::ImmediateAttribute;
+pub use limit::{LimitAttribute, LimitFlags, LimitType};
+pub use log::{LogAttribute, LogFlags, LogLevel};
+pub use lookup::{LookupAttribute, LookupFlags};
+pub use masq::MasqAttribute;
+pub use meta::{MetaAttribute, MetaKey};
+pub use nat::{NatAttribute, NatFlags, NatType};
+pub use numgen::{NumgenAttribute, NumgenType};
+pub use objref::ObjrefAttribute;
+pub use payload::{PayloadAttribute, PayloadBase};
+pub use queue::{QueueAttribute, QueueFlags};
+pub use quota::{QuotaAttribute, QuotaFlags};
+pub use range::{RangeAttribute, RangeOp};
+pub use redir::RedirAttribute;
+pub use register::Register;
+pub use reject::{RejectAttribute, RejectType};
+pub use tproxy::TproxyAttribute;
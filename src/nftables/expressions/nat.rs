@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+use crate::{message::ProtoFamily, nftables::expressions::Register};
+
+const NFTA_NAT_TYPE: u16 = 1;
+const NFTA_NAT_FAMILY: u16 = 2;
+const NFTA_NAT_REG_ADDR_MIN: u16 = 3;
+const NFTA_NAT_REG_ADDR_MAX: u16 = 4;
+const NFTA_NAT_REG_PROTO_MIN: u16 = 5;
+const NFTA_NAT_REG_PROTO_MAX: u16 = 6;
+const NFTA_NAT_FLAGS: u16 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NatAttribute {
+    Type(NatType),
+    Family(ProtoFamily),
+    RegAddrMin(Register),
+    RegAddrMax(Register),
+    RegProtoMin(Register),
+    RegProtoMax(Register),
+    Flags(NatFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for NatAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            NatAttribute::Type(_) => size_of::<u32>(),
+            NatAttribute::Family(_) => size_of::<u32>(),
+            NatAttribute::RegAddrMin(_) => size_of::<u32>(),
+            NatAttribute::RegAddrMax(_) => size_of::<u32>(),
+            NatAttribute::RegProtoMin(_) => size_of::<u32>(),
+            NatAttribute::RegProtoMax(_) => size_of::<u32>(),
+            NatAttribute::Flags(_) => size_of::<u32>(),
+            NatAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            NatAttribute::Type(_) => NFTA_NAT_TYPE,
+            NatAttribute::Family(_) => NFTA_NAT_FAMILY,
+            NatAttribute::RegAddrMin(_) => NFTA_NAT_REG_ADDR_MIN,
+            NatAttribute::RegAddrMax(_) => NFTA_NAT_REG_ADDR_MAX,
+            NatAttribute::RegProtoMin(_) => NFTA_NAT_REG_PROTO_MIN,
+            NatAttribute::RegProtoMax(_) => NFTA_NAT_REG_PROTO_MAX,
+            NatAttribute::Flags(_) => NFTA_NAT_FLAGS,
+            NatAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            NatAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NatAttribute::Family(attr) => {
+                emit_u32_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            NatAttribute::RegAddrMin(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NatAttribute::RegAddrMax(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NatAttribute::RegProtoMin(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NatAttribute::RegProtoMax(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NatAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            NatAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for NatAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_NAT_TYPE => NatAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_TYPE value")?
+                    .into(),
+            ),
+            NFTA_NAT_FAMILY => NatAttribute::Family(
+                (parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_FAMILY value")?
+                    as u8)
+                    .into(),
+            ),
+            NFTA_NAT_REG_ADDR_MIN => NatAttribute::RegAddrMin(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_REG_ADDR_MIN value")?
+                    .into(),
+            ),
+            NFTA_NAT_REG_ADDR_MAX => NatAttribute::RegAddrMax(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_REG_ADDR_MAX value")?
+                    .into(),
+            ),
+            NFTA_NAT_REG_PROTO_MIN => NatAttribute::RegProtoMin(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_REG_PROTO_MIN value")?
+                    .into(),
+            ),
+            NFTA_NAT_REG_PROTO_MAX => NatAttribute::RegProtoMax(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_REG_PROTO_MAX value")?
+                    .into(),
+            ),
+            NFTA_NAT_FLAGS => NatAttribute::Flags(NatFlags::from_bits_retain(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NAT_FLAGS value")?,
+            )),
+            _ => NatAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_NAT_SNAT: u32 = 0;
+const NFT_NAT_DNAT: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NatType {
+    Snat,
+    Dnat,
+    Other(u32),
+}
+
+impl From<u32> for NatType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_NAT_SNAT => Self::Snat,
+            NFT_NAT_DNAT => Self::Dnat,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<NatType> for u32 {
+    fn from(value: NatType) -> Self {
+        match value {
+            NatType::Snat => NFT_NAT_SNAT,
+            NatType::Dnat => NFT_NAT_DNAT,
+            NatType::Other(v) => v,
+        }
+    }
+}
+
+const NF_NAT_RANGE_MAP_IPS: u32 = 1;
+const NF_NAT_RANGE_PROTO_SPECIFIED: u32 = 1 << 1;
+const NF_NAT_RANGE_PROTO_RANDOM: u32 = 1 << 2;
+const NF_NAT_RANGE_PERSISTENT: u32 = 1 << 3;
+const NF_NAT_RANGE_PROTO_RANDOM_FULLY: u32 = 1 << 4;
+const NF_NAT_RANGE_PROTO_OFFSET: u32 = 1 << 5;
+const NF_NAT_RANGE_NETMAP: u32 = 1 << 6;
+
+bitflags! {
+    // Also used by the masq and redir expressions
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct NatFlags: u32 {
+        const MapIps = NF_NAT_RANGE_MAP_IPS;
+        const ProtoSpecified = NF_NAT_RANGE_PROTO_SPECIFIED;
+        const ProtoRandom = NF_NAT_RANGE_PROTO_RANDOM;
+        const Persistent = NF_NAT_RANGE_PERSISTENT;
+        const ProtoRandomFully = NF_NAT_RANGE_PROTO_RANDOM_FULLY;
+        const ProtoOffset = NF_NAT_RANGE_PROTO_OFFSET;
+        const Netmap = NF_NAT_RANGE_NETMAP;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_NG_DREG: u16 = 1;
+const NFTA_NG_MODULUS: u16 = 2;
+const NFTA_NG_TYPE: u16 = 3;
+const NFTA_NG_OFFSET: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NumgenAttribute {
+    Dreg(Register),
+    Modulus(u32),
+    Type(NumgenType),
+    Offset(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for NumgenAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            NumgenAttribute::Dreg(_) => size_of::<u32>(),
+            NumgenAttribute::Modulus(attr) => size_of_val(attr),
+            NumgenAttribute::Type(_) => size_of::<u32>(),
+            NumgenAttribute::Offset(attr) => size_of_val(attr),
+            NumgenAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            NumgenAttribute::Dreg(_) => NFTA_NG_DREG,
+            NumgenAttribute::Modulus(_) => NFTA_NG_MODULUS,
+            NumgenAttribute::Type(_) => NFTA_NG_TYPE,
+            NumgenAttribute::Offset(_) => NFTA_NG_OFFSET,
+            NumgenAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            NumgenAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NumgenAttribute::Modulus(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            NumgenAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            NumgenAttribute::Offset(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            NumgenAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for NumgenAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_NG_DREG => NumgenAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NG_DREG value")?
+                    .into(),
+            ),
+            NFTA_NG_MODULUS => NumgenAttribute::Modulus(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NG_MODULUS value")?,
+            ),
+            NFTA_NG_TYPE => NumgenAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NG_TYPE value")?
+                    .into(),
+            ),
+            NFTA_NG_OFFSET => NumgenAttribute::Offset(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_NG_OFFSET value")?,
+            ),
+            _ => NumgenAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_NG_INCREMENTAL: u32 = 0;
+const NFT_NG_RANDOM: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NumgenType {
+    Incremental,
+    Random,
+    Other(u32),
+}
+
+impl From<u32> for NumgenType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_NG_INCREMENTAL => Self::Incremental,
+            NFT_NG_RANDOM => Self::Random,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<NumgenType> for u32 {
+    fn from(value: NumgenType) -> Self {
+        match value {
+            NumgenType::Incremental => NFT_NG_INCREMENTAL,
+            NumgenType::Random => NFT_NG_RANDOM,
+            NumgenType::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_string, parse_u32_be, DecodeError, DefaultNla,
+    ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use crate::nftables::{expressions::Register, ObjectType};
+
+const NFTA_OBJREF_IMM_TYPE: u16 = 1;
+const NFTA_OBJREF_IMM_NAME: u16 = 2;
+const NFTA_OBJREF_SET_SREG: u16 = 3;
+const NFTA_OBJREF_SET_NAME: u16 = 4;
+const NFTA_OBJREF_SET_ID: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ObjrefAttribute {
+    ImmType(ObjectType),
+    ImmName(String),
+    // Looks the object up in a map instead, e.g. counter name ip saddr map
+    SetSreg(Register),
+    SetName(String),
+    SetId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ObjrefAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            ObjrefAttribute::ImmType(_) => size_of::<u32>(),
+            ObjrefAttribute::ImmName(attr) => attr.len() + 1,
+            ObjrefAttribute::SetSreg(_) => size_of::<u32>(),
+            ObjrefAttribute::SetName(attr) => attr.len() + 1,
+            ObjrefAttribute::SetId(attr) => size_of_val(attr),
+            ObjrefAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            ObjrefAttribute::ImmType(_) => NFTA_OBJREF_IMM_TYPE,
+            ObjrefAttribute::ImmName(_) => NFTA_OBJREF_IMM_NAME,
+            ObjrefAttribute::SetSreg(_) => NFTA_OBJREF_SET_SREG,
+            ObjrefAttribute::SetName(_) => NFTA_OBJREF_SET_NAME,
+            ObjrefAttribute::SetId(_) => NFTA_OBJREF_SET_ID,
+            ObjrefAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            ObjrefAttribute::ImmType(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ObjrefAttribute::ImmName(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ObjrefAttribute::SetSreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            ObjrefAttribute::SetName(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            ObjrefAttribute::SetId(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            ObjrefAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for ObjrefAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_OBJREF_IMM_TYPE => ObjrefAttribute::ImmType(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_OBJREF_IMM_TYPE value")?
+                    .into(),
+            ),
+            NFTA_OBJREF_IMM_NAME => ObjrefAttribute::ImmName(
+                parse_string(payload)
+                    .context("invalid NFTA_OBJREF_IMM_NAME value")?,
+            ),
+            NFTA_OBJREF_SET_SREG => ObjrefAttribute::SetSreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_OBJREF_SET_SREG value")?
+                    .into(),
+            ),
+            NFTA_OBJREF_SET_NAME => ObjrefAttribute::SetName(
+                parse_string(payload)
+                    .context("invalid NFTA_OBJREF_SET_NAME value")?,
+            ),
+            NFTA_OBJREF_SET_ID => ObjrefAttribute::SetId(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_OBJREF_SET_ID value")?,
+            ),
+            _ => ObjrefAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::Register;
+
+const NFTA_PAYLOAD_DREG: u16 = 1;
+const NFTA_PAYLOAD_BASE: u16 = 2;
+const NFTA_PAYLOAD_OFFSET: u16 = 3;
+const NFTA_PAYLOAD_LEN: u16 = 4;
+const NFTA_PAYLOAD_SREG: u16 = 5;
+const NFTA_PAYLOAD_CSUM_TYPE: u16 = 6;
+const NFTA_PAYLOAD_CSUM_OFFSET: u16 = 7;
+const NFTA_PAYLOAD_CSUM_FLAGS: u16 = 8;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PayloadAttribute {
+    Dreg(Register),
+    Base(PayloadBase),
+    Offset(u32),
+    Len(u32),
+    // Set when writing to the packet instead of loading from it
+    Sreg(Register),
+    CsumType(u32),
+    CsumOffset(u32),
+    CsumFlags(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for PayloadAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            PayloadAttribute::Dreg(_) => size_of::<u32>(),
+            PayloadAttribute::Base(_) => size_of::<u32>(),
+            PayloadAttribute::Offset(attr) => size_of_val(attr),
+            PayloadAttribute::Len(attr) => size_of_val(attr),
+            PayloadAttribute::Sreg(_) => size_of::<u32>(),
+            PayloadAttribute::CsumType(attr) => size_of_val(attr),
+            PayloadAttribute::CsumOffset(attr) => size_of_val(attr),
+            PayloadAttribute::CsumFlags(attr) => size_of_val(attr),
+            PayloadAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            PayloadAttribute::Dreg(_) => NFTA_PAYLOAD_DREG,
+            PayloadAttribute::Base(_) => NFTA_PAYLOAD_BASE,
+            PayloadAttribute::Offset(_) => NFTA_PAYLOAD_OFFSET,
+            PayloadAttribute::Len(_) => NFTA_PAYLOAD_LEN,
+            PayloadAttribute::Sreg(_) => NFTA_PAYLOAD_SREG,
+            PayloadAttribute::CsumType(_) => NFTA_PAYLOAD_CSUM_TYPE,
+            PayloadAttribute::CsumOffset(_) => NFTA_PAYLOAD_CSUM_OFFSET,
+            PayloadAttribute::CsumFlags(_) => NFTA_PAYLOAD_CSUM_FLAGS,
+            PayloadAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            PayloadAttribute::Dreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            PayloadAttribute::Base(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            PayloadAttribute::Offset(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            PayloadAttribute::Len(attr) => emit_u32_be(buffer, *attr).unwrap(),
+            PayloadAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            PayloadAttribute::CsumType(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            PayloadAttribute::CsumOffset(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            PayloadAttribute::CsumFlags(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            PayloadAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for PayloadAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_PAYLOAD_DREG => PayloadAttribute::Dreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_DREG value")?
+                    .into(),
+            ),
+            NFTA_PAYLOAD_BASE => PayloadAttribute::Base(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_BASE value")?
+                    .into(),
+            ),
+            NFTA_PAYLOAD_OFFSET => PayloadAttribute::Offset(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_OFFSET value")?,
+            ),
+            NFTA_PAYLOAD_LEN => PayloadAttribute::Len(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_LEN value")?,
+            ),
+            NFTA_PAYLOAD_SREG => PayloadAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_SREG value")?
+                    .into(),
+            ),
+            NFTA_PAYLOAD_CSUM_TYPE => PayloadAttribute::CsumType(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_CSUM_TYPE value")?,
+            ),
+            NFTA_PAYLOAD_CSUM_OFFSET => PayloadAttribute::CsumOffset(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_CSUM_OFFSET value")?,
+            ),
+            NFTA_PAYLOAD_CSUM_FLAGS => PayloadAttribute::CsumFlags(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_PAYLOAD_CSUM_FLAGS value")?,
+            ),
+            _ => PayloadAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_PAYLOAD_LL_HEADER: u32 = 0;
+const NFT_PAYLOAD_NETWORK_HEADER: u32 = 1;
+const NFT_PAYLOAD_TRANSPORT_HEADER: u32 = 2;
+const NFT_PAYLOAD_INNER_HEADER: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PayloadBase {
+    LinkLayer,
+    Network,
+    Transport,
+    Inner,
+    Other(u32),
+}
+
+impl From<u32> for PayloadBase {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_PAYLOAD_LL_HEADER => Self::LinkLayer,
+            NFT_PAYLOAD_NETWORK_HEADER => Self::Network,
+            NFT_PAYLOAD_TRANSPORT_HEADER => Self::Transport,
+            NFT_PAYLOAD_INNER_HEADER => Self::Inner,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<PayloadBase> for u32 {
+    fn from(value: PayloadBase) -> Self {
+        match value {
+            PayloadBase::LinkLayer => NFT_PAYLOAD_LL_HEADER,
+            PayloadBase::Network => NFT_PAYLOAD_NETWORK_HEADER,
+            PayloadBase::Transport => NFT_PAYLOAD_TRANSPORT_HEADER,
+            PayloadBase::Inner => NFT_PAYLOAD_INNER_HEADER,
+            PayloadBase::Other(v) => v,
+        }
+    }
+}
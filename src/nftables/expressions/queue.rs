@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_u16_be, parse_u32_be, DecodeError,
+    DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+use crate::nftables::expressions::Register;
+
+const NFTA_QUEUE_NUM: u16 = 1;
+const NFTA_QUEUE_TOTAL: u16 = 2;
+const NFTA_QUEUE_FLAGS: u16 = 3;
+const NFTA_QUEUE_SREG_QNUM: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QueueAttribute {
+    Num(u16),
+    // Number of queues to balance the packets over, starting at Num
+    Total(u16),
+    Flags(QueueFlags),
+    SregQnum(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for QueueAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            QueueAttribute::Num(attr) => size_of_val(attr),
+            QueueAttribute::Total(attr) => size_of_val(attr),
+            QueueAttribute::Flags(_) => size_of::<u16>(),
+            QueueAttribute::SregQnum(_) => size_of::<u32>(),
+            QueueAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            QueueAttribute::Num(_) => NFTA_QUEUE_NUM,
+            QueueAttribute::Total(_) => NFTA_QUEUE_TOTAL,
+            QueueAttribute::Flags(_) => NFTA_QUEUE_FLAGS,
+            QueueAttribute::SregQnum(_) => NFTA_QUEUE_SREG_QNUM,
+            QueueAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            QueueAttribute::Num(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            QueueAttribute::Total(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            QueueAttribute::Flags(attr) => {
+                emit_u16_be(buffer, attr.bits()).unwrap()
+            }
+            QueueAttribute::SregQnum(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            QueueAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for QueueAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_QUEUE_NUM => QueueAttribute::Num(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_QUEUE_NUM value")?,
+            ),
+            NFTA_QUEUE_TOTAL => QueueAttribute::Total(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_QUEUE_TOTAL value")?,
+            ),
+            NFTA_QUEUE_FLAGS => {
+                QueueAttribute::Flags(QueueFlags::from_bits_retain(
+                    parse_u16_be(payload)
+                        .context("invalid NFTA_QUEUE_FLAGS value")?,
+                ))
+            }
+            NFTA_QUEUE_SREG_QNUM => QueueAttribute::SregQnum(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_QUEUE_SREG_QNUM value")?
+                    .into(),
+            ),
+            _ => QueueAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_QUEUE_FLAG_BYPASS: u16 = 1;
+const NFT_QUEUE_FLAG_CPU_FANOUT: u16 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct QueueFlags: u16 {
+        // Accepts the packets when no program listens on the queue
+        const Bypass = NFT_QUEUE_FLAG_BYPASS;
+        const CpuFanout = NFT_QUEUE_FLAG_CPU_FANOUT;
+        const _ = !0;
+    }
+}
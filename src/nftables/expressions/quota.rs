@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, emit_u64_be, parse_u32_be, parse_u64_be, DecodeError,
+    DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+const NFTA_QUOTA_BYTES: u16 = 1;
+const NFTA_QUOTA_FLAGS: u16 = 2;
+const NFTA_QUOTA_CONSUMED: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuotaAttribute {
+    Bytes(u64),
+    Flags(QuotaFlags),
+    Consumed(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for QuotaAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            QuotaAttribute::Bytes(attr) => size_of_val(attr),
+            QuotaAttribute::Flags(_) => size_of::<u32>(),
+            QuotaAttribute::Consumed(attr) => size_of_val(attr),
+            QuotaAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            QuotaAttribute::Bytes(_) => NFTA_QUOTA_BYTES,
+            QuotaAttribute::Flags(_) => NFTA_QUOTA_FLAGS,
+            QuotaAttribute::Consumed(_) => NFTA_QUOTA_CONSUMED,
+            QuotaAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            QuotaAttribute::Bytes(attr) => emit_u64_be(buffer, *attr).unwrap(),
+            QuotaAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            QuotaAttribute::Consumed(attr) => {
+                emit_u64_be(buffer, *attr).unwrap()
+            }
+            QuotaAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for QuotaAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_QUOTA_BYTES => QuotaAttribute::Bytes(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_QUOTA_BYTES value")?,
+            ),
+            NFTA_QUOTA_FLAGS => {
+                QuotaAttribute::Flags(QuotaFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_QUOTA_FLAGS value")?,
+                ))
+            }
+            NFTA_QUOTA_CONSUMED => QuotaAttribute::Consumed(
+                parse_u64_be(payload)
+                    .context("invalid NFTA_QUOTA_CONSUMED value")?,
+            ),
+            _ => QuotaAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_QUOTA_F_INV: u32 = 1;
+const NFT_QUOTA_F_DEPLETED: u32 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct QuotaFlags: u32 {
+        // Matches when over the quota
+        const Inv = NFT_QUOTA_F_INV;
+        const Depleted = NFT_QUOTA_F_DEPLETED;
+        const _ = !0;
+    }
+}
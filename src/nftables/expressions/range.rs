@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, Emitable, ErrorContext,
+    Nla, NlaBuffer, NlasIterator, Parseable,
+};
+
+use crate::nftables::expressions::{DataAttribute, Register};
+
+const NFTA_RANGE_SREG: u16 = 1;
+const NFTA_RANGE_OP: u16 = 2;
+const NFTA_RANGE_FROM_DATA: u16 = 3;
+const NFTA_RANGE_TO_DATA: u16 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RangeAttribute {
+    Sreg(Register),
+    Op(RangeOp),
+    FromData(Vec<DataAttribute>),
+    ToData(Vec<DataAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for RangeAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            RangeAttribute::Sreg(_) => size_of::<u32>(),
+            RangeAttribute::Op(_) => size_of::<u32>(),
+            RangeAttribute::FromData(attr) => attr.as_slice().buffer_len(),
+            RangeAttribute::ToData(attr) => attr.as_slice().buffer_len(),
+            RangeAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            RangeAttribute::Sreg(_) => NFTA_RANGE_SREG,
+            RangeAttribute::Op(_) => NFTA_RANGE_OP,
+            RangeAttribute::FromData(_) => NFTA_RANGE_FROM_DATA,
+            RangeAttribute::ToData(_) => NFTA_RANGE_TO_DATA,
+            RangeAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            RangeAttribute::Sreg(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            RangeAttribute::Op(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            RangeAttribute::FromData(attr) => attr.as_slice().emit(buffer),
+            RangeAttribute::ToData(attr) => attr.as_slice().emit(buffer),
+            RangeAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(
+            self,
+            RangeAttribute::FromData(_) | RangeAttribute::ToData(_)
+        )
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for RangeAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_RANGE_SREG => RangeAttribute::Sreg(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_RANGE_SREG value")?
+                    .into(),
+            ),
+            NFTA_RANGE_OP => RangeAttribute::Op(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_RANGE_OP value")?
+                    .into(),
+            ),
+            NFTA_RANGE_FROM_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_RANGE_FROM_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                RangeAttribute::FromData(data_attributes)
+            }
+            NFTA_RANGE_TO_DATA => {
+                let mut data_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas =
+                        &nlas.context("invalid NFTA_RANGE_TO_DATA value")?;
+                    data_attributes.push(DataAttribute::parse(nlas)?);
+                }
+                RangeAttribute::ToData(data_attributes)
+            }
+            _ => RangeAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_RANGE_EQ: u32 = 0;
+const NFT_RANGE_NEQ: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RangeOp {
+    Eq,
+    Neq,
+    Other(u32),
+}
+
+impl From<u32> for RangeOp {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_RANGE_EQ => Self::Eq,
+            NFT_RANGE_NEQ => Self::Neq,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<RangeOp> for u32 {
+    fn from(value: RangeOp) -> Self {
+        match value {
+            RangeOp::Eq => NFT_RANGE_EQ,
+            RangeOp::Neq => NFT_RANGE_NEQ,
+            RangeOp::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::nftables::expressions::{NatFlags, Register};
+
+const NFTA_REDIR_REG_PROTO_MIN: u16 = 1;
+const NFTA_REDIR_REG_PROTO_MAX: u16 = 2;
+const NFTA_REDIR_FLAGS: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RedirAttribute {
+    RegProtoMin(Register),
+    RegProtoMax(Register),
+    Flags(NatFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for RedirAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            RedirAttribute::RegProtoMin(_) => size_of::<u32>(),
+            RedirAttribute::RegProtoMax(_) => size_of::<u32>(),
+            RedirAttribute::Flags(_) => size_of::<u32>(),
+            RedirAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            RedirAttribute::RegProtoMin(_) => NFTA_REDIR_REG_PROTO_MIN,
+            RedirAttribute::RegProtoMax(_) => NFTA_REDIR_REG_PROTO_MAX,
+            RedirAttribute::Flags(_) => NFTA_REDIR_FLAGS,
+            RedirAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            RedirAttribute::RegProtoMin(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            RedirAttribute::RegProtoMax(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            RedirAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            RedirAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for RedirAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_REDIR_REG_PROTO_MIN => RedirAttribute::RegProtoMin(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_REDIR_REG_PROTO_MIN value")?
+                    .into(),
+            ),
+            NFTA_REDIR_REG_PROTO_MAX => RedirAttribute::RegProtoMax(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_REDIR_REG_PROTO_MAX value")?
+                    .into(),
+            ),
+            NFTA_REDIR_FLAGS => {
+                RedirAttribute::Flags(NatFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_REDIR_FLAGS value")?,
+                ))
+            }
+            _ => RedirAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
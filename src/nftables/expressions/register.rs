@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+const NFT_REG_VERDICT: u32 = 0;
+const NFT_REG_1: u32 = 1;
+const NFT_REG_4: u32 = 4;
+const NFT_REG32_00: u32 = 8;
+const NFT_REG32_15: u32 = 23;
+
+// nf_tables registers, either addressed as one of the four 16 bytes
+// registers or as one of the sixteen 4 bytes registers which overlap them.
+// `Reg(1)` is the same storage as `Reg32(0)` to `Reg32(3)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Register {
+    Verdict,
+    // 1 to 4, other values are not emitted as the same register: `Reg(0)` is
+    // the verdict register and `Reg(8)` and above are 4 bytes registers
+    Reg(u8),
+    // 0 to 15, `Reg32(16)` and above are parsed back as `Other`
+    Reg32(u8),
+    Other(u32),
+}
+
+impl From<u32> for Register {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_REG_VERDICT => Self::Verdict,
+            NFT_REG_1..=NFT_REG_4 => Self::Reg(value as u8),
+            NFT_REG32_00..=NFT_REG32_15 => {
+                Self::Reg32((value - NFT_REG32_00) as u8)
+            }
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<Register> for u32 {
+    fn from(value: Register) -> Self {
+        match value {
+            Register::Verdict => NFT_REG_VERDICT,
+            Register::Reg(reg) => reg as u32,
+            Register::Reg32(reg) => NFT_REG32_00 + reg as u32,
+            Register::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, parse_u8, DecodeError, DefaultNla, ErrorContext,
+    Nla, NlaBuffer, Parseable,
+};
+
+const NFTA_REJECT_TYPE: u16 = 1;
+const NFTA_REJECT_ICMP_CODE: u16 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RejectAttribute {
+    Type(RejectType),
+    // An ICMP or ICMPv6 code, or one of the NFT_REJECT_ICMPX_* codes for
+    // RejectType::IcmpxUnreach
+    IcmpCode(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for RejectAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            RejectAttribute::Type(_) => size_of::<u32>(),
+            RejectAttribute::IcmpCode(attr) => size_of_val(attr),
+            RejectAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            RejectAttribute::Type(_) => NFTA_REJECT_TYPE,
+            RejectAttribute::IcmpCode(_) => NFTA_REJECT_ICMP_CODE,
+            RejectAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            RejectAttribute::Type(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            RejectAttribute::IcmpCode(attr) => buffer[0] = *attr,
+            RejectAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for RejectAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_REJECT_TYPE => RejectAttribute::Type(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_REJECT_TYPE value")?
+                    .into(),
+            ),
+            NFTA_REJECT_ICMP_CODE => RejectAttribute::IcmpCode(
+                parse_u8(payload)
+                    .context("invalid NFTA_REJECT_ICMP_CODE value")?,
+            ),
+            _ => RejectAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NFT_REJECT_ICMP_UNREACH: u32 = 0;
+const NFT_REJECT_TCP_RST: u32 = 1;
+const NFT_REJECT_ICMPX_UNREACH: u32 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RejectType {
+    IcmpUnreach,
+    TcpRst,
+    // Family independent, for inet and bridge tables
+    IcmpxUnreach,
+    Other(u32),
+}
+
+impl From<u32> for RejectType {
+    fn from(value: u32) -> Self {
+        match value {
+            NFT_REJECT_ICMP_UNREACH => Self::IcmpUnreach,
+            NFT_REJECT_TCP_RST => Self::TcpRst,
+            NFT_REJECT_ICMPX_UNREACH => Self::IcmpxUnreach,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<RejectType> for u32 {
+    fn from(value: RejectType) -> Self {
+        match value {
+            RejectType::IcmpUnreach => NFT_REJECT_ICMP_UNREACH,
+            RejectType::TcpRst => NFT_REJECT_TCP_RST,
+            RejectType::IcmpxUnreach => NFT_REJECT_ICMPX_UNREACH,
+            RejectType::Other(v) => v,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32_be, parse_u32_be, DecodeError, DefaultNla, ErrorContext, Nla,
+    NlaBuffer, Parseable,
+};
+
+use crate::{message::ProtoFamily, nftables::expressions::Register};
+
+const NFTA_TPROXY_FAMILY: u16 = 1;
+const NFTA_TPROXY_REG_ADDR: u16 = 2;
+const NFTA_TPROXY_REG_PORT: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TproxyAttribute {
+    Family(ProtoFamily),
+    RegAddr(Register),
+    RegPort(Register),
+    Other(DefaultNla),
+}
+
+impl Nla for TproxyAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            TproxyAttribute::Family(_) => size_of::<u32>(),
+            TproxyAttribute::RegAddr(_) => size_of::<u32>(),
+            TproxyAttribute::RegPort(_) => size_of::<u32>(),
+            TproxyAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            TproxyAttribute::Family(_) => NFTA_TPROXY_FAMILY,
+            TproxyAttribute::RegAddr(_) => NFTA_TPROXY_REG_ADDR,
+            TproxyAttribute::RegPort(_) => NFTA_TPROXY_REG_PORT,
+            TproxyAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            TproxyAttribute::Family(attr) => {
+                emit_u32_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            TproxyAttribute::RegAddr(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            TproxyAttribute::RegPort(attr) => {
+                emit_u32_be(buffer, (*attr).into()).unwrap()
+            }
+            TproxyAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for TproxyAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_TPROXY_FAMILY => TproxyAttribute::Family(
+                (parse_u32_be(payload)
+                    .context("invalid NFTA_TPROXY_FAMILY value")?
+                    as u8)
+                    .into(),
+            ),
+            NFTA_TPROXY_REG_ADDR => TproxyAttribute::RegAddr(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_TPROXY_REG_ADDR value")?
+                    .into(),
+            ),
+            NFTA_TPROXY_REG_PORT => TproxyAttribute::RegPort(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_TPROXY_REG_PORT value")?
+                    .into(),
+            ),
+            _ => TproxyAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,535 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    buffer::NetfilterBuffer,
+    nftables::attributes::{
+        find_object_type, ChainAttribute, ObjectAttribute, RuleAttribute,
+        SetAttribute, SetElementListAttribute, TableAttribute,
+    },
+};
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, Parseable, ParseableParametrized,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NftMessage {
+    NewTable(Vec<TableAttribute>),
+    GetTable(Vec<TableAttribute>),
+    DelTable(Vec<TableAttribute>),
+    DestroyTable(Vec<TableAttribute>),
+    NewChain(Vec<ChainAttribute>),
+    GetChain(Vec<ChainAttribute>),
+    DelChain(Vec<ChainAttribute>),
+    DestroyChain(Vec<ChainAttribute>),
+    NewRule(Vec<RuleAttribute>),
+    GetRule(Vec<RuleAttribute>),
+    DelRule(Vec<RuleAttribute>),
+    DestroyRule(Vec<RuleAttribute>),
+    NewSet(Vec<SetAttribute>),
+    GetSet(Vec<SetAttribute>),
+    DelSet(Vec<SetAttribute>),
+    DestroySet(Vec<SetAttribute>),
+    NewSetElement(Vec<SetElementListAttribute>),
+    GetSetElement(Vec<SetElementListAttribute>),
+    DelSetElement(Vec<SetElementListAttribute>),
+    DestroySetElement(Vec<SetElementListAttribute>),
+    NewObject(Vec<ObjectAttribute>),
+    GetObject(Vec<ObjectAttribute>),
+    DelObject(Vec<ObjectAttribute>),
+    GetObjectReset(Vec<ObjectAttribute>),
+    DestroyObject(Vec<ObjectAttribute>),
+    Other {
+        message_type: u8,
+        attributes: Vec<DefaultNla>,
+    },
+}
+
+const NFT_MSG_NEWTABLE: u8 = 0;
+const NFT_MSG_GETTABLE: u8 = 1;
+const NFT_MSG_DELTABLE: u8 = 2;
+const NFT_MSG_NEWCHAIN: u8 = 3;
+const NFT_MSG_GETCHAIN: u8 = 4;
+const NFT_MSG_DELCHAIN: u8 = 5;
+const NFT_MSG_NEWRULE: u8 = 6;
+const NFT_MSG_GETRULE: u8 = 7;
+const NFT_MSG_DELRULE: u8 = 8;
+const NFT_MSG_NEWSET: u8 = 9;
+const NFT_MSG_GETSET: u8 = 10;
+const NFT_MSG_DELSET: u8 = 11;
+const NFT_MSG_NEWSETELEM: u8 = 12;
+const NFT_MSG_GETSETELEM: u8 = 13;
+const NFT_MSG_DELSETELEM: u8 = 14;
+const NFT_MSG_NEWOBJ: u8 = 18;
+const NFT_MSG_GETOBJ: u8 = 19;
+const NFT_MSG_DELOBJ: u8 = 20;
+const NFT_MSG_GETOBJ_RESET: u8 = 21;
+const NFT_MSG_DESTROYTABLE: u8 = 26;
+const NFT_MSG_DESTROYCHAIN: u8 = 27;
+const NFT_MSG_DESTROYRULE: u8 = 28;
+const NFT_MSG_DESTROYSET: u8 = 29;
+const NFT_MSG_DESTROYSETELEM: u8 = 30;
+const NFT_MSG_DESTROYOBJ: u8 = 31;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NftMessageType {
+    NewTable,
+    GetTable,
+    DelTable,
+    DestroyTable,
+    NewChain,
+    GetChain,
+    DelChain,
+    DestroyChain,
+    NewRule,
+    GetRule,
+    DelRule,
+    DestroyRule,
+    NewSet,
+    GetSet,
+    DelSet,
+    DestroySet,
+    NewSetElement,
+    GetSetElement,
+    DelSetElement,
+    DestroySetElement,
+    NewObject,
+    GetObject,
+    DelObject,
+    GetObjectReset,
+    DestroyObject,
+    Other(u8),
+}
+
+impl From<u8> for NftMessageType {
+    fn from(value: u8) -> Self {
+        match value {
+            NFT_MSG_NEWTABLE => Self::NewTable,
+            NFT_MSG_GETTABLE => Self::GetTable,
+            NFT_MSG_DELTABLE => Self::DelTable,
+            NFT_MSG_DESTROYTABLE => Self::DestroyTable,
+            NFT_MSG_NEWCHAIN => Self::NewChain,
+            NFT_MSG_GETCHAIN => Self::GetChain,
+            NFT_MSG_DELCHAIN => Self::DelChain,
+            NFT_MSG_DESTROYCHAIN => Self::DestroyChain,
+            NFT_MSG_NEWRULE => Self::NewRule,
+            NFT_MSG_GETRULE => Self::GetRule,
+            NFT_MSG_DELRULE => Self::DelRule,
+            NFT_MSG_DESTROYRULE => Self::DestroyRule,
+            NFT_MSG_NEWSET => Self::NewSet,
+            NFT_MSG_GETSET => Self::GetSet,
+            NFT_MSG_DELSET => Self::DelSet,
+            NFT_MSG_DESTROYSET => Self::DestroySet,
+            NFT_MSG_NEWSETELEM => Self::NewSetElement,
+            NFT_MSG_GETSETELEM => Self::GetSetElement,
+            NFT_MSG_DELSETELEM => Self::DelSetElement,
+            NFT_MSG_DESTROYSETELEM => Self::DestroySetElement,
+            NFT_MSG_NEWOBJ => Self::NewObject,
+            NFT_MSG_GETOBJ => Self::GetObject,
+            NFT_MSG_DELOBJ => Self::DelObject,
+            NFT_MSG_GETOBJ_RESET => Self::GetObjectReset,
+            NFT_MSG_DESTROYOBJ => Self::DestroyObject,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<NftMessageType> for u8 {
+    fn from(value: NftMessageType) -> Self {
+        match value {
+            NftMessageType::NewTable => NFT_MSG_NEWTABLE,
+            NftMessageType::GetTable => NFT_MSG_GETTABLE,
+            NftMessageType::DelTable => NFT_MSG_DELTABLE,
+            NftMessageType::DestroyTable => NFT_MSG_DESTROYTABLE,
+            NftMessageType::NewChain => NFT_MSG_NEWCHAIN,
+            NftMessageType::GetChain => NFT_MSG_GETCHAIN,
+            NftMessageType::DelChain => NFT_MSG_DELCHAIN,
+            NftMessageType::DestroyChain => NFT_MSG_DESTROYCHAIN,
+            NftMessageType::NewRule => NFT_MSG_NEWRULE,
+            NftMessageType::GetRule => NFT_MSG_GETRULE,
+            NftMessageType::DelRule => NFT_MSG_DELRULE,
+            NftMessageType::DestroyRule => NFT_MSG_DESTROYRULE,
+            NftMessageType::NewSet => NFT_MSG_NEWSET,
+            NftMessageType::GetSet => NFT_MSG_GETSET,
+            NftMessageType::DelSet => NFT_MSG_DELSET,
+            NftMessageType::DestroySet => NFT_MSG_DESTROYSET,
+            NftMessageType::NewSetElement => NFT_MSG_NEWSETELEM,
+            NftMessageType::GetSetElement => NFT_MSG_GETSETELEM,
+            NftMessageType::DelSetElement => NFT_MSG_DELSETELEM,
+            NftMessageType::DestroySetElement => NFT_MSG_DESTROYSETELEM,
+            NftMessageType::NewObject => NFT_MSG_NEWOBJ,
+            NftMessageType::GetObject => NFT_MSG_GETOBJ,
+            NftMessageType::DelObject => NFT_MSG_DELOBJ,
+            NftMessageType::GetObjectReset => NFT_MSG_GETOBJ_RESET,
+            NftMessageType::DestroyObject => NFT_MSG_DESTROYOBJ,
+            NftMessageType::Other(v) => v,
+        }
+    }
+}
+
+impl NftMessage {
+    pub fn message_type(&self) -> NftMessageType {
+        match self {
+            NftMessage::NewTable(_) => NftMessageType::NewTable,
+            NftMessage::GetTable(_) => NftMessageType::GetTable,
+            NftMessage::DelTable(_) => NftMessageType::DelTable,
+            NftMessage::DestroyTable(_) => NftMessageType::DestroyTable,
+            NftMessage::NewChain(_) => NftMessageType::NewChain,
+            NftMessage::GetChain(_) => NftMessageType::GetChain,
+            NftMessage::DelChain(_) => NftMessageType::DelChain,
+            NftMessage::DestroyChain(_) => NftMessageType::DestroyChain,
+            NftMessage::NewRule(_) => NftMessageType::NewRule,
+            NftMessage::GetRule(_) => NftMessageType::GetRule,
+            NftMessage::DelRule(_) => NftMessageType::DelRule,
+            NftMessage::DestroyRule(_) => NftMessageType::DestroyRule,
+            NftMessage::NewSet(_) => NftMessageType::NewSet,
+            NftMessage::GetSet(_) => NftMessageType::GetSet,
+            NftMessage::DelSet(_) => NftMessageType::DelSet,
+            NftMessage::DestroySet(_) => NftMessageType::DestroySet,
+            NftMessage::NewSetElement(_) => NftMessageType::NewSetElement,
+            NftMessage::GetSetElement(_) => NftMessageType::GetSetElement,
+            NftMessage::DelSetElement(_) => NftMessageType::DelSetElement,
+            NftMessage::DestroySetElement(_) => {
+                NftMessageType::DestroySetElement
+            }
+            NftMessage::NewObject(_) => NftMessageType::NewObject,
+            NftMessage::GetObject(_) => NftMessageType::GetObject,
+            NftMessage::DelObject(_) => NftMessageType::DelObject,
+            NftMessage::GetObjectReset(_) => NftMessageType::GetObjectReset,
+            NftMessage::DestroyObject(_) => NftMessageType::DestroyObject,
+            NftMessage::Other { message_type, .. } => (*message_type).into(),
+        }
+    }
+}
+
+impl Emitable for NftMessage {
+    fn buffer_len(&self) -> usize {
+        match self {
+            NftMessage::NewTable(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetTable(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelTable(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroyTable(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::NewChain(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetChain(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelChain(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroyChain(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::NewRule(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetRule(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelRule(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroyRule(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::NewSet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetSet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelSet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroySet(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::NewSetElement(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetSetElement(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelSetElement(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroySetElement(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::NewObject(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetObject(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DelObject(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::GetObjectReset(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::DestroyObject(attributes) => {
+                attributes.as_slice().buffer_len()
+            }
+            NftMessage::Other { attributes, .. } => {
+                attributes.as_slice().buffer_len()
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            NftMessage::NewTable(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetTable(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelTable(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroyTable(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::NewChain(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetChain(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelChain(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroyChain(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::NewRule(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetRule(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelRule(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroyRule(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::NewSet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetSet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelSet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroySet(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::NewSetElement(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetSetElement(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelSetElement(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroySetElement(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::NewObject(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetObject(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DelObject(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::GetObjectReset(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::DestroyObject(attributes) => {
+                attributes.as_slice().emit(buffer)
+            }
+            NftMessage::Other { attributes, .. } => {
+                attributes.as_slice().emit(buffer)
+            }
+        };
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NetfilterBuffer<&'a T>, u8> for NftMessage
+{
+    fn parse_with_param(
+        buf: &NetfilterBuffer<&'a T>,
+        message_type: u8,
+    ) -> Result<Self, DecodeError> {
+        Ok(match NftMessageType::from(message_type) {
+            NftMessageType::NewTable => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TableAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::NewTable(attributes)
+            }
+            NftMessageType::GetTable => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TableAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::GetTable(attributes)
+            }
+            NftMessageType::DelTable => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TableAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DelTable(attributes)
+            }
+            NftMessageType::DestroyTable => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    TableAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DestroyTable(attributes)
+            }
+            NftMessageType::NewChain => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ChainAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::NewChain(attributes)
+            }
+            NftMessageType::GetChain => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ChainAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::GetChain(attributes)
+            }
+            NftMessageType::DelChain => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ChainAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DelChain(attributes)
+            }
+            NftMessageType::DestroyChain => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ChainAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DestroyChain(attributes)
+            }
+            NftMessageType::NewRule => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| RuleAttribute::parse(&nla_buf))?;
+                NftMessage::NewRule(attributes)
+            }
+            NftMessageType::GetRule => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| RuleAttribute::parse(&nla_buf))?;
+                NftMessage::GetRule(attributes)
+            }
+            NftMessageType::DelRule => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| RuleAttribute::parse(&nla_buf))?;
+                NftMessage::DelRule(attributes)
+            }
+            NftMessageType::DestroyRule => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| RuleAttribute::parse(&nla_buf))?;
+                NftMessage::DestroyRule(attributes)
+            }
+            NftMessageType::NewSet => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| SetAttribute::parse(&nla_buf))?;
+                NftMessage::NewSet(attributes)
+            }
+            NftMessageType::GetSet => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| SetAttribute::parse(&nla_buf))?;
+                NftMessage::GetSet(attributes)
+            }
+            NftMessageType::DelSet => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| SetAttribute::parse(&nla_buf))?;
+                NftMessage::DelSet(attributes)
+            }
+            NftMessageType::DestroySet => {
+                let attributes = buf
+                    .parse_all_nlas(|nla_buf| SetAttribute::parse(&nla_buf))?;
+                NftMessage::DestroySet(attributes)
+            }
+            NftMessageType::NewSetElement => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    SetElementListAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::NewSetElement(attributes)
+            }
+            NftMessageType::GetSetElement => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    SetElementListAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::GetSetElement(attributes)
+            }
+            NftMessageType::DelSetElement => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    SetElementListAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DelSetElement(attributes)
+            }
+            NftMessageType::DestroySetElement => {
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    SetElementListAttribute::parse(&nla_buf)
+                })?;
+                NftMessage::DestroySetElement(attributes)
+            }
+            NftMessageType::NewObject => {
+                let object_type = find_object_type(buf.nlas())?;
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ObjectAttribute::parse_with_param(&nla_buf, object_type)
+                })?;
+                NftMessage::NewObject(attributes)
+            }
+            NftMessageType::GetObject => {
+                let object_type = find_object_type(buf.nlas())?;
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ObjectAttribute::parse_with_param(&nla_buf, object_type)
+                })?;
+                NftMessage::GetObject(attributes)
+            }
+            NftMessageType::DelObject => {
+                let object_type = find_object_type(buf.nlas())?;
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ObjectAttribute::parse_with_param(&nla_buf, object_type)
+                })?;
+                NftMessage::DelObject(attributes)
+            }
+            NftMessageType::GetObjectReset => {
+                let object_type = find_object_type(buf.nlas())?;
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ObjectAttribute::parse_with_param(&nla_buf, object_type)
+                })?;
+                NftMessage::GetObjectReset(attributes)
+            }
+            NftMessageType::DestroyObject => {
+                let object_type = find_object_type(buf.nlas())?;
+                let attributes = buf.parse_all_nlas(|nla_buf| {
+                    ObjectAttribute::parse_with_param(&nla_buf, object_type)
+                })?;
+                NftMessage::DestroyObject(attributes)
+            }
+            NftMessageType::Other(message_type) => NftMessage::Other {
+                message_type,
+                attributes: buf.default_nlas()?,
+            },
+        })
+    }
+}
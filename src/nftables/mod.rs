@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+mod message;
+pub use message::{NftMessage, NftMessageType};
+mod attributes;
+pub use attributes::{
+    ChainAttribute, ChainFlags, ChainHook, ChainPolicy, Counter, HookDevice,
+    ObjectAttribute, ObjectType, RuleAttribute, SetAttribute, SetConcatField,
+    SetDescAttribute, SetElement, SetElementAttribute, SetElementFlags,
+    SetElementListAttribute, SetFieldAttribute, SetFlags, SetPolicy,
+    TableAttribute, TableFlags,
+};
+pub mod expressions;
+pub mod objects;
+
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, NLM_F_REQUEST,
+};
+
+use crate::{
+    constants::{
+        NFNETLINK_V0, NFNL_MSG_BATCH_BEGIN, NFNL_MSG_BATCH_END,
+        NFNL_SUBSYS_NFTABLES, NFNL_SUBSYS_NONE,
+    },
+    message::{ProtoFamily, Subsystem},
+    NetfilterHeader, NetfilterMessage, NetfilterMessageInner,
+};
+
+// nf_tables only accepts its changes (new, delete and destroy messages)
+// within a batch: they have to be sent between batch_begin() and batch_end(),
+// with sequence numbers set by the caller, and are applied atomically.
+pub fn batch_begin() -> NetlinkMessage<NetfilterMessage> {
+    batch_message(NFNL_MSG_BATCH_BEGIN)
+}
+
+pub fn batch_end() -> NetlinkMessage<NetfilterMessage> {
+    batch_message(NFNL_MSG_BATCH_END)
+}
+
+fn batch_message(message_type: u8) -> NetlinkMessage<NetfilterMessage> {
+    let mut hdr = NetlinkHeader::default();
+    hdr.flags = NLM_F_REQUEST;
+    let mut message = NetlinkMessage::new(
+        hdr,
+        NetlinkPayload::from(NetfilterMessage::new(
+            NetfilterHeader::new(
+                ProtoFamily::Unspec,
+                NFNETLINK_V0,
+                NFNL_SUBSYS_NFTABLES as u16,
+            ),
+            NetfilterMessageInner::Other {
+                subsys: Subsystem::Other(NFNL_SUBSYS_NONE),
+                message_type,
+                attributes: vec![],
+            },
+        )),
+    );
+    message.finalize();
+    message
+}
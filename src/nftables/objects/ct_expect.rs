@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_u16_be, parse_u32_be, parse_u8,
+    DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use crate::{conntrack::Protocol, message::ProtoFamily};
+
+const NFTA_CT_EXPECT_L3PROTO: u16 = 1;
+const NFTA_CT_EXPECT_L4PROTO: u16 = 2;
+const NFTA_CT_EXPECT_DPORT: u16 = 3;
+const NFTA_CT_EXPECT_TIMEOUT: u16 = 4;
+const NFTA_CT_EXPECT_SIZE: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtExpectAttribute {
+    L3Proto(ProtoFamily),
+    L4Proto(Protocol),
+    DPort(u16),
+    // In milliseconds
+    Timeout(u32),
+    // Maximum number of expectations of the connection
+    Size(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for CtExpectAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            CtExpectAttribute::L3Proto(_) => size_of::<u16>(),
+            CtExpectAttribute::L4Proto(_) => size_of::<u8>(),
+            CtExpectAttribute::DPort(attr) => size_of_val(attr),
+            CtExpectAttribute::Timeout(attr) => size_of_val(attr),
+            CtExpectAttribute::Size(attr) => size_of_val(attr),
+            CtExpectAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            CtExpectAttribute::L3Proto(_) => NFTA_CT_EXPECT_L3PROTO,
+            CtExpectAttribute::L4Proto(_) => NFTA_CT_EXPECT_L4PROTO,
+            CtExpectAttribute::DPort(_) => NFTA_CT_EXPECT_DPORT,
+            CtExpectAttribute::Timeout(_) => NFTA_CT_EXPECT_TIMEOUT,
+            CtExpectAttribute::Size(_) => NFTA_CT_EXPECT_SIZE,
+            CtExpectAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            CtExpectAttribute::L3Proto(attr) => {
+                emit_u16_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            CtExpectAttribute::L4Proto(attr) => buffer[0] = (*attr).into(),
+            CtExpectAttribute::DPort(attr) => {
+                emit_u16_be(buffer, *attr).unwrap()
+            }
+            CtExpectAttribute::Timeout(attr) => {
+                emit_u32_be(buffer, *attr).unwrap()
+            }
+            CtExpectAttribute::Size(attr) => buffer[0] = *attr,
+            CtExpectAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for CtExpectAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CT_EXPECT_L3PROTO => CtExpectAttribute::L3Proto(
+                (parse_u16_be(payload)
+                    .context("invalid NFTA_CT_EXPECT_L3PROTO value")?
+                    as u8)
+                    .into(),
+            ),
+            NFTA_CT_EXPECT_L4PROTO => CtExpectAttribute::L4Proto(
+                parse_u8(payload)
+                    .context("invalid NFTA_CT_EXPECT_L4PROTO value")?
+                    .into(),
+            ),
+            NFTA_CT_EXPECT_DPORT => CtExpectAttribute::DPort(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_CT_EXPECT_DPORT value")?,
+            ),
+            NFTA_CT_EXPECT_TIMEOUT => CtExpectAttribute::Timeout(
+                parse_u32_be(payload)
+                    .context("invalid NFTA_CT_EXPECT_TIMEOUT value")?,
+            ),
+            NFTA_CT_EXPECT_SIZE => CtExpectAttribute::Size(
+                parse_u8(payload)
+                    .context("invalid NFTA_CT_EXPECT_SIZE value")?,
+            ),
+            _ => CtExpectAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, parse_string, parse_u16_be, parse_u8, DecodeError, DefaultNla,
+    ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use crate::{conntrack::Protocol, message::ProtoFamily};
+
+const NFTA_CT_HELPER_NAME: u16 = 1;
+const NFTA_CT_HELPER_L3PROTO: u16 = 2;
+const NFTA_CT_HELPER_L4PROTO: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtHelperAttribute {
+    Name(String),
+    L3Proto(ProtoFamily),
+    L4Proto(Protocol),
+    Other(DefaultNla),
+}
+
+impl Nla for CtHelperAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            CtHelperAttribute::Name(attr) => attr.len() + 1,
+            CtHelperAttribute::L3Proto(_) => size_of::<u16>(),
+            CtHelperAttribute::L4Proto(_) => size_of::<u8>(),
+            CtHelperAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            CtHelperAttribute::Name(_) => NFTA_CT_HELPER_NAME,
+            CtHelperAttribute::L3Proto(_) => NFTA_CT_HELPER_L3PROTO,
+            CtHelperAttribute::L4Proto(_) => NFTA_CT_HELPER_L4PROTO,
+            CtHelperAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            CtHelperAttribute::Name(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            CtHelperAttribute::L3Proto(attr) => {
+                emit_u16_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            CtHelperAttribute::L4Proto(attr) => buffer[0] = (*attr).into(),
+            CtHelperAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for CtHelperAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CT_HELPER_NAME => CtHelperAttribute::Name(
+                parse_string(payload)
+                    .context("invalid NFTA_CT_HELPER_NAME value")?,
+            ),
+            NFTA_CT_HELPER_L3PROTO => CtHelperAttribute::L3Proto(
+                (parse_u16_be(payload)
+                    .context("invalid NFTA_CT_HELPER_L3PROTO value")?
+                    as u8)
+                    .into(),
+            ),
+            NFTA_CT_HELPER_L4PROTO => CtHelperAttribute::L4Proto(
+                parse_u8(payload)
+                    .context("invalid NFTA_CT_HELPER_L4PROTO value")?
+                    .into(),
+            ),
+            _ => CtHelperAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
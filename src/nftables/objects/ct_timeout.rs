@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, parse_u16_be, parse_u8, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, Parseable, ParseableParametrized,
+};
+
+use crate::{
+    conntrack::Protocol, cttimeout::TimeoutData, message::ProtoFamily,
+};
+
+const NFTA_CT_TIMEOUT_L3PROTO: u16 = 1;
+const NFTA_CT_TIMEOUT_L4PROTO: u16 = 2;
+const NFTA_CT_TIMEOUT_DATA: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CtTimeoutAttribute {
+    L3Proto(ProtoFamily),
+    L4Proto(Protocol),
+    // Same timeouts as the ones of the cttimeout subsystem
+    Data(TimeoutData),
+    Other(DefaultNla),
+}
+
+impl Nla for CtTimeoutAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            CtTimeoutAttribute::L3Proto(_) => size_of::<u16>(),
+            CtTimeoutAttribute::L4Proto(_) => size_of::<u8>(),
+            CtTimeoutAttribute::Data(attr) => attr.buffer_len(),
+            CtTimeoutAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            CtTimeoutAttribute::L3Proto(_) => NFTA_CT_TIMEOUT_L3PROTO,
+            CtTimeoutAttribute::L4Proto(_) => NFTA_CT_TIMEOUT_L4PROTO,
+            CtTimeoutAttribute::Data(_) => NFTA_CT_TIMEOUT_DATA,
+            CtTimeoutAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            CtTimeoutAttribute::L3Proto(attr) => {
+                emit_u16_be(buffer, u8::from(*attr).into()).unwrap()
+            }
+            CtTimeoutAttribute::L4Proto(attr) => buffer[0] = (*attr).into(),
+            CtTimeoutAttribute::Data(attr) => attr.emit(buffer),
+            CtTimeoutAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, CtTimeoutAttribute::Data(_))
+    }
+}
+
+// As for the cttimeout subsystem, the layout of NFTA_CT_TIMEOUT_DATA depends
+// on NFTA_CT_TIMEOUT_L4PROTO
+impl<'buffer, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'buffer T>, Option<Protocol>>
+    for CtTimeoutAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'buffer T>,
+        protocol: Option<Protocol>,
+    ) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_CT_TIMEOUT_L3PROTO => {
+                CtTimeoutAttribute::L3Proto(ProtoFamily::from(
+                    parse_u16_be(payload)
+                        .context("invalid NFTA_CT_TIMEOUT_L3PROTO value")?
+                        as u8,
+                ))
+            }
+            NFTA_CT_TIMEOUT_L4PROTO => CtTimeoutAttribute::L4Proto(
+                parse_u8(payload)
+                    .context("invalid NFTA_CT_TIMEOUT_L4PROTO value")?
+                    .into(),
+            ),
+            NFTA_CT_TIMEOUT_DATA => CtTimeoutAttribute::Data(
+                TimeoutData::parse(payload, protocol)
+                    .context("invalid NFTA_CT_TIMEOUT_DATA value")?,
+            ),
+            _ => CtTimeoutAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+pub(crate) fn find_l4proto<'a, I>(
+    nlas: I,
+) -> Result<Option<Protocol>, DecodeError>
+where
+    I: Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+{
+    for nla in nlas {
+        let nla = nla?;
+        if nla.kind() == NFTA_CT_TIMEOUT_L4PROTO {
+            return Ok(Some(
+                parse_u8(nla.value())
+                    .context("invalid NFTA_CT_TIMEOUT_L4PROTO value")?
+                    .into(),
+            ));
+        }
+    }
+    Ok(None)
+}
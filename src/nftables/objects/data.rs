@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ErrorContext, NlasIterator, Parseable,
+    ParseableParametrized,
+};
+
+use crate::nftables::{
+    attributes::{Counter, ObjectType},
+    expressions::{ConnlimitAttribute, LimitAttribute, QuotaAttribute},
+    objects::{
+        ct_timeout::find_l4proto, CtExpectAttribute, CtHelperAttribute,
+        CtTimeoutAttribute, SecmarkAttribute, SynproxyAttribute,
+    },
+};
+
+// Content of NFTA_OBJ_DATA, whose attributes depend on the object type given
+// by NFTA_OBJ_TYPE. The counter, quota, limit and connlimit objects share
+// their attributes with the expressions of the same name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ObjectData {
+    Counter(Vec<Counter>),
+    Quota(Vec<QuotaAttribute>),
+    CtHelper(Vec<CtHelperAttribute>),
+    Limit(Vec<LimitAttribute>),
+    Connlimit(Vec<ConnlimitAttribute>),
+    CtTimeout(Vec<CtTimeoutAttribute>),
+    Secmark(Vec<SecmarkAttribute>),
+    CtExpect(Vec<CtExpectAttribute>),
+    Synproxy(Vec<SynproxyAttribute>),
+    // Data of the other object types, e.g. tunnels, or of a message without
+    // NFTA_OBJ_TYPE
+    Other(Vec<DefaultNla>),
+}
+
+impl ObjectData {
+    pub(crate) fn parse(
+        payload: &[u8],
+        object_type: Option<ObjectType>,
+    ) -> Result<Self, DecodeError> {
+        Ok(match object_type {
+            Some(ObjectType::Counter) => {
+                let mut counters = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    counters.push(Counter::parse(nlas)?);
+                }
+                ObjectData::Counter(counters)
+            }
+            Some(ObjectType::Quota) => {
+                let mut quota_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    quota_attributes.push(QuotaAttribute::parse(nlas)?);
+                }
+                ObjectData::Quota(quota_attributes)
+            }
+            Some(ObjectType::CtHelper) => {
+                let mut ct_helper_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    ct_helper_attributes.push(CtHelperAttribute::parse(nlas)?);
+                }
+                ObjectData::CtHelper(ct_helper_attributes)
+            }
+            Some(ObjectType::Limit) => {
+                let mut limit_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    limit_attributes.push(LimitAttribute::parse(nlas)?);
+                }
+                ObjectData::Limit(limit_attributes)
+            }
+            Some(ObjectType::Connlimit) => {
+                let mut connlimit_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    connlimit_attributes.push(ConnlimitAttribute::parse(nlas)?);
+                }
+                ObjectData::Connlimit(connlimit_attributes)
+            }
+            Some(ObjectType::CtTimeout) => {
+                let protocol = find_l4proto(NlasIterator::new(payload))?;
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid NFTA_OBJ_DATA value")?;
+                    nlas.push(CtTimeoutAttribute::parse_with_param(
+                        nla, protocol,
+                    )?);
+                }
+                ObjectData::CtTimeout(nlas)
+            }
+            Some(ObjectType::Secmark) => {
+                let mut secmark_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    secmark_attributes.push(SecmarkAttribute::parse(nlas)?);
+                }
+                ObjectData::Secmark(secmark_attributes)
+            }
+            Some(ObjectType::CtExpect) => {
+                let mut ct_expect_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    ct_expect_attributes.push(CtExpectAttribute::parse(nlas)?);
+                }
+                ObjectData::CtExpect(ct_expect_attributes)
+            }
+            Some(ObjectType::Synproxy) => {
+                let mut synproxy_attributes = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    synproxy_attributes.push(SynproxyAttribute::parse(nlas)?);
+                }
+                ObjectData::Synproxy(synproxy_attributes)
+            }
+            _ => {
+                let mut default_nlas = Vec::new();
+                for nlas in NlasIterator::new(payload) {
+                    let nlas = &nlas.context("invalid NFTA_OBJ_DATA value")?;
+                    default_nlas.push(DefaultNla::parse(nlas)?);
+                }
+                ObjectData::Other(default_nlas)
+            }
+        })
+    }
+}
+
+impl Emitable for ObjectData {
+    fn buffer_len(&self) -> usize {
+        match self {
+            ObjectData::Counter(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Quota(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::CtHelper(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Limit(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Connlimit(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::CtTimeout(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Secmark(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::CtExpect(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Synproxy(nlas) => nlas.as_slice().buffer_len(),
+            ObjectData::Other(nlas) => nlas.as_slice().buffer_len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            ObjectData::Counter(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Quota(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::CtHelper(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Limit(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Connlimit(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::CtTimeout(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Secmark(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::CtExpect(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Synproxy(nlas) => nlas.as_slice().emit(buffer),
+            ObjectData::Other(nlas) => nlas.as_slice().emit(buffer),
+        }
+    }
+}
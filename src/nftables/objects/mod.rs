@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod ct_expect;
+mod ct_helper;
+mod ct_timeout;
+mod data;
+mod secmark;
+mod synproxy;
+
+pub use ct_expect::CtExpectAttribute;
+pub use ct_helper::CtHelperAttribute;
+pub use ct_timeout::CtTimeoutAttribute;
+pub use data::ObjectData;
+pub use secmark::SecmarkAttribute;
+pub use synproxy::{SynproxyAttribute, SynproxyFlags};
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_string, DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer,
+    Parseable,
+};
+
+const NFTA_SECMARK_CTX: u16 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecmarkAttribute {
+    // Security context, e.g. an SELinux label
+    Ctx(String),
+    Other(DefaultNla),
+}
+
+impl Nla for SecmarkAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SecmarkAttribute::Ctx(attr) => attr.len() + 1,
+            SecmarkAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SecmarkAttribute::Ctx(_) => NFTA_SECMARK_CTX,
+            SecmarkAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SecmarkAttribute::Ctx(attr) => {
+                buffer[..attr.len()].copy_from_slice(attr.as_bytes());
+                buffer[attr.len()] = 0;
+            }
+            SecmarkAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SecmarkAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SECMARK_CTX => SecmarkAttribute::Ctx(
+                parse_string(payload)
+                    .context("invalid NFTA_SECMARK_CTX value")?,
+            ),
+            _ => SecmarkAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
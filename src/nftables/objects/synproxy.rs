@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32_be, parse_u16_be, parse_u32_be, parse_u8,
+    DecodeError, DefaultNla, ErrorContext, Nla, NlaBuffer, Parseable,
+};
+
+use bitflags::bitflags;
+
+const NFTA_SYNPROXY_MSS: u16 = 1;
+const NFTA_SYNPROXY_WSCALE: u16 = 2;
+const NFTA_SYNPROXY_FLAGS: u16 = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SynproxyAttribute {
+    Mss(u16),
+    Wscale(u8),
+    Flags(SynproxyFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for SynproxyAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            SynproxyAttribute::Mss(attr) => size_of_val(attr),
+            SynproxyAttribute::Wscale(attr) => size_of_val(attr),
+            SynproxyAttribute::Flags(_) => size_of::<u32>(),
+            SynproxyAttribute::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            SynproxyAttribute::Mss(_) => NFTA_SYNPROXY_MSS,
+            SynproxyAttribute::Wscale(_) => NFTA_SYNPROXY_WSCALE,
+            SynproxyAttribute::Flags(_) => NFTA_SYNPROXY_FLAGS,
+            SynproxyAttribute::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            SynproxyAttribute::Mss(attr) => emit_u16_be(buffer, *attr).unwrap(),
+            SynproxyAttribute::Wscale(attr) => buffer[0] = *attr,
+            SynproxyAttribute::Flags(attr) => {
+                emit_u32_be(buffer, attr.bits()).unwrap()
+            }
+            SynproxyAttribute::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'buffer, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'buffer T>>
+    for SynproxyAttribute
+{
+    fn parse(buf: &NlaBuffer<&'buffer T>) -> Result<Self, DecodeError> {
+        let kind = buf.kind();
+        let payload = buf.value();
+        let nla = match kind {
+            NFTA_SYNPROXY_MSS => SynproxyAttribute::Mss(
+                parse_u16_be(payload)
+                    .context("invalid NFTA_SYNPROXY_MSS value")?,
+            ),
+            NFTA_SYNPROXY_WSCALE => SynproxyAttribute::Wscale(
+                parse_u8(payload)
+                    .context("invalid NFTA_SYNPROXY_WSCALE value")?,
+            ),
+            NFTA_SYNPROXY_FLAGS => {
+                SynproxyAttribute::Flags(SynproxyFlags::from_bits_retain(
+                    parse_u32_be(payload)
+                        .context("invalid NFTA_SYNPROXY_FLAGS value")?,
+                ))
+            }
+            _ => SynproxyAttribute::Other(DefaultNla::parse(buf)?),
+        };
+        Ok(nla)
+    }
+}
+
+const NF_SYNPROXY_OPT_MSS: u32 = 1;
+const NF_SYNPROXY_OPT_WSCALE: u32 = 1 << 1;
+const NF_SYNPROXY_OPT_SACK_PERM: u32 = 1 << 2;
+const NF_SYNPROXY_OPT_TIMESTAMP: u32 = 1 << 3;
+const NF_SYNPROXY_OPT_ECN: u32 = 1 << 4;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SynproxyFlags: u32 {
+        const Mss = NF_SYNPROXY_OPT_MSS;
+        const Wscale = NF_SYNPROXY_OPT_WSCALE;
+        const SackPerm = NF_SYNPROXY_OPT_SACK_PERM;
+        const Timestamp = NF_SYNPROXY_OPT_TIMESTAMP;
+        const Ecn = NF_SYNPROXY_OPT_ECN;
+        const _ = !0;
+    }
+}
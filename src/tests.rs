@@ -1,16 +1,82 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::IpAddr;
+use std::{
+    net::IpAddr,
+    time::{Duration, UNIX_EPOCH},
+};
 
-use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_core::{
+    DefaultNla, Emitable, NetlinkHeader, NetlinkMessage, NetlinkPayload,
+    ParseableParametrized, NLA_F_NESTED, NLM_F_CREATE, NLM_F_EXCL,
+};
 
 use crate::{
     buffer::NetfilterBuffer,
     conntrack::{
-        ConntrackAttribute, ConntrackMessage, ConntrackMessageType, IPTuple,
-        ProtoInfo, ProtoInfoTCP, ProtoTuple, Protocol, Status, TCPFlags, Tuple,
+        find_masters, ConnlabelMap, ConntrackAttribute, ConntrackEvent,
+        ConntrackFilter, ConntrackMessage, ConntrackMessageType, Counter,
+        DccpRole, DccpState, Filter, FilterFlags, Help, IPTuple, IcmpFilter,
+        Nat, PortFilter, ProtoInfo, ProtoInfoDCCP, ProtoInfoSCTP, ProtoInfoTCP,
+        ProtoNat, ProtoTuple, Protocol, SctpState, SecCtx, SeqAdj, StatsCpu,
+        StatsGlobal, Status, Synproxy, TCPFlags, TCPWindowFlags, TcpState,
+        Timestamp, Tuple, TupleFilter,
+    },
+    cthelper::{
+        HelperAttribute, HelperMessage, HelperMessageType, HelperPolicy,
+        HelperPolicySet, HelperStatus, HelperTuple,
+    },
+    cttimeout::{
+        DccpTimeout, GenericTimeout, GreTimeout, SctpTimeout, TcpTimeout,
+        TimeoutAttribute, TimeoutData, TimeoutMessage, TimeoutMessageType,
+        UdpTimeout,
+    },
+    expect::{
+        ExpectAttribute, ExpectDirection, ExpectFlags, ExpectMessage,
+        ExpectMessageType, ExpectNat,
     },
     message::{ProtoFamily, Subsystem},
+    nfacct::{
+        AcctAttribute, AcctFilter, AcctFlags, AcctMessage, AcctMessageType,
+    },
+    nfqueue::{
+        config_request,
+        nlas::{
+            config::{
+                ConfigCmd, ConfigCommand, ConfigFlags, ConfigNla, ConfigParams,
+            },
+            packet::{HwAddr, PacketHdr, PacketNla, SkbInfo, VlanNla},
+            verdict::{Verdict, VerdictNla},
+        },
+        verdict_batch_request, verdict_request, QueueMessage, QueueMessageType,
+    },
+    nftables::{
+        batch_begin,
+        expressions::{
+            BitwiseAttribute, BitwiseOp, ByteorderAttribute, ByteorderOp,
+            CmpAttribute, CmpOp, ConnlimitAttribute, ConnlimitFlags,
+            CtAttribute, CtDirection, CtKey, CtState, DataAttribute,
+            DupAttribute, DynsetAttribute, DynsetFlags, DynsetOp, Expression,
+            FwdAttribute, HashAttribute, HashType, ImmediateAttribute,
+            LimitAttribute, LimitFlags, LimitType, LogAttribute, LogFlags,
+            LogLevel, LookupAttribute, LookupFlags, MasqAttribute,
+            MetaAttribute, MetaKey, NatAttribute, NatFlags, NatType,
+            NumgenAttribute, NumgenType, ObjrefAttribute, PayloadAttribute,
+            PayloadBase, QueueAttribute, QueueFlags, QuotaAttribute,
+            QuotaFlags, RangeAttribute, RangeOp, RedirAttribute, Register,
+            RejectAttribute, RejectType, TproxyAttribute, VerdictAttribute,
+            VerdictCode,
+        },
+        objects::{
+            CtExpectAttribute, CtHelperAttribute, CtTimeoutAttribute,
+            ObjectData, SecmarkAttribute, SynproxyAttribute, SynproxyFlags,
+        },
+        ChainAttribute, ChainFlags, ChainHook, ChainPolicy,
+        Counter as NftCounter, HookDevice, NftMessage, NftMessageType,
+        ObjectAttribute, ObjectType, RuleAttribute, SetAttribute,
+        SetConcatField, SetDescAttribute, SetElement, SetElementAttribute,
+        SetElementFlags, SetElementListAttribute, SetFieldAttribute, SetFlags,
+        SetPolicy, TableAttribute, TableFlags,
+    },
     NetfilterHeader, NetfilterMessage,
 };
 
@@ -74,12 +140,12 @@ fn test_get_conntrack_tcp_ipv4() {
 
     let proto_info = ProtoInfo::TCP(vec![
         ProtoInfoTCP::OriginalFlags(TCPFlags {
-            flags: 10,
-            mask: 10,
+            flags: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
+            mask: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
         }),
         ProtoInfoTCP::ReplyFlags(TCPFlags {
-            flags: 10,
-            mask: 10,
+            flags: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
+            mask: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
         }),
     ]);
 
@@ -234,9 +300,19 @@ fn test_delete_conntrack_tcp_ipv4() {
     let mark = 0;
 
     let proto_info = vec![ProtoInfo::TCP(vec![
-        ProtoInfoTCP::State(3),
-        ProtoInfoTCP::OriginalFlags(TCPFlags { flags: 35, mask: 0 }),
-        ProtoInfoTCP::ReplyFlags(TCPFlags { flags: 35, mask: 0 }),
+        ProtoInfoTCP::State(TcpState::Established),
+        ProtoInfoTCP::OriginalFlags(TCPFlags {
+            flags: TCPWindowFlags::WindowScale
+                | TCPWindowFlags::SackPerm
+                | TCPWindowFlags::MaxackSet,
+            mask: TCPWindowFlags::empty(),
+        }),
+        ProtoInfoTCP::ReplyFlags(TCPFlags {
+            flags: TCPWindowFlags::WindowScale
+                | TCPWindowFlags::SackPerm
+                | TCPWindowFlags::MaxackSet,
+            mask: TCPWindowFlags::empty(),
+        }),
         ProtoInfoTCP::OriginalWindowScale(10),
         ProtoInfoTCP::ReplyWindowScale(10),
     ])];
@@ -427,14 +503,14 @@ fn test_new_conntrack() {
     let timeout = 60;
 
     let proto_info = ProtoInfo::TCP(vec![
-        ProtoInfoTCP::State(1),
+        ProtoInfoTCP::State(TcpState::SynSent),
         ProtoInfoTCP::OriginalFlags(TCPFlags {
-            flags: 10,
-            mask: 10,
+            flags: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
+            mask: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
         }),
         ProtoInfoTCP::ReplyFlags(TCPFlags {
-            flags: 10,
-            mask: 10,
+            flags: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
+            mask: TCPWindowFlags::SackPerm | TCPWindowFlags::BeLiberal,
         }),
     ]);
 
@@ -471,3 +547,5223 @@ fn test_new_conntrack() {
         expected
     );
 }
+
+// conntrack -L with net.netfilter.nf_conntrack_acct=1, one entry of the dump
+// reply (netlink message header removed)
+#[test]
+fn test_dump_conntrack_counters() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0x01, 0x01, 0x01, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0xcf, 0xdb, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x35, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x01, 0x01, 0x01, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0xc0, 0xa8, 0x01, 0x0a, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x35, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xcf, 0xdb, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x1d, 0x1c, 0x00, 0x09, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x47, 0x1c, 0x00, 0x0a, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x57,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("192.168.1.10".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("1.1.1.1".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(53211),
+        ProtoTuple::DestinationPort(53),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("1.1.1.1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4(
+            "192.168.1.10".parse().unwrap(),
+        )),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(53),
+        ProtoTuple::DestinationPort(53211),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaStatus(Status::Confirmed),
+        ConntrackAttribute::CtaTimeout(29),
+        ConntrackAttribute::CtaCountersOrig(vec![
+            Counter::Packets(1),
+            Counter::Bytes(71),
+        ]),
+        ConntrackAttribute::CtaCountersReply(vec![
+            Counter::Packets(1),
+            Counter::Bytes(87),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -E -e DESTROY with net.netfilter.nf_conntrack_timestamp=1
+// (netlink message header removed)
+#[test]
+fn test_destroy_event_conntrack_timestamp() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x7b, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x7b, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x1c, 0x00, 0x14, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x18, 0x6c, 0xc6, 0xac, 0xd4, 0xb0, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x18, 0x6c, 0xc6, 0xb3, 0xee, 0xa1, 0x11, 0x00,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.0.0.2".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.0.0.1".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(40000),
+        ProtoTuple::DestinationPort(123),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.0.0.1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.0.0.2".parse().unwrap())),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(123),
+        ProtoTuple::DestinationPort(40000),
+    ]);
+
+    let timestamps = vec![
+        Timestamp::Start(1760000000000000000),
+        Timestamp::Stop(1760000030500000000),
+    ];
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaStatus(
+            Status::SeenReply | Status::Assured | Status::Confirmed,
+        ),
+        ConntrackAttribute::CtaTimestamp(timestamps.clone()),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::Delete(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::Delete) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    assert_eq!(
+        Timestamp::flow_duration(&timestamps),
+        Some(Duration::from_millis(30500))
+    );
+    assert_eq!(
+        timestamps[0].as_system_time(),
+        Some(UNIX_EPOCH + Duration::from_secs(1760000000))
+    );
+}
+
+// conntrack -I -p tcp --src 192.168.1.100 --dst 10.0.0.1 --sport 12345 --dport
+// 80 --dst-nat 10.0.0.5:8080-8090 --timeout 60, with a sequence adjustment
+// for the original direction (netlink message header removed)
+#[test]
+fn test_new_conntrack_dnat() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x64, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x30, 0x39, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x50, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x05,
+        0x08, 0x00, 0x02, 0x00, 0xc0, 0xa8, 0x01, 0x64, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x1f, 0x90, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x30, 0x39, 0x00, 0x00,
+        0x28, 0x00, 0x0d, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x05,
+        0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x05, 0x14, 0x00, 0x03, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x1f, 0x9a, 0x00, 0x00, 0x1c, 0x00, 0x0f, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x1a, 0x2b, 0x3c, 0x4d, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x3c,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("192.168.1.100".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.0.0.1".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Tcp),
+        ProtoTuple::SourcePort(12345),
+        ProtoTuple::DestinationPort(80),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.0.0.5".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4(
+            "192.168.1.100".parse().unwrap(),
+        )),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Tcp),
+        ProtoTuple::SourcePort(8080),
+        ProtoTuple::DestinationPort(12345),
+    ]);
+
+    let nat = vec![
+        Nat::MinIp(IpAddr::V4("10.0.0.5".parse().unwrap())),
+        Nat::MaxIp(IpAddr::V4("10.0.0.5".parse().unwrap())),
+        Nat::Proto(vec![ProtoNat::PortMin(8080), ProtoNat::PortMax(8090)]),
+    ];
+
+    let seq_adj = vec![
+        SeqAdj::CorrectionPos(0x1a2b3c4d),
+        SeqAdj::OffsetBefore(0),
+        SeqAdj::OffsetAfter(4),
+    ];
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaNatDst(nat),
+        ConntrackAttribute::CtaSeqAdjOrig(seq_adj),
+        ConntrackAttribute::CtaTimeout(60),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L -w 10, one entry of the dump reply (netlink message header
+// removed)
+#[test]
+fn test_dump_conntrack_zone_id_use() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xac, 0x10, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0xac, 0x10, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x14, 0xe9, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x14, 0xe9, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xac, 0x10, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0xac, 0x10, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x14, 0xe9, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x14, 0xe9, 0x00, 0x00,
+        0x06, 0x00, 0x12, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x19,
+        0x08, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x0c, 0x00,
+        0x5e, 0x3b, 0x9a, 0x12,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("172.16.0.2".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("172.16.0.1".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(5353),
+        ProtoTuple::DestinationPort(5353),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("172.16.0.1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("172.16.0.2".parse().unwrap())),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(5353),
+        ProtoTuple::DestinationPort(5353),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaZone(10),
+        ConntrackAttribute::CtaStatus(Status::Confirmed),
+        ConntrackAttribute::CtaTimeout(25),
+        ConntrackAttribute::CtaUse(1),
+        ConntrackAttribute::CtaId(0x5e3b9a12),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -G -p udp -s 172.16.0.2 -d 172.16.0.1 --sport 5353 --dport 5353
+// -w 10 --orig-zone 10 (netlink message header removed)
+#[test]
+fn test_get_conntrack_zone() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xac, 0x10, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0xac, 0x10, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x14, 0xe9, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x14, 0xe9, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x00, 0x0a, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00, 0x00, 0x0a, 0x00, 0x00,
+    ];
+
+    let ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("172.16.0.2".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("172.16.0.1".parse().unwrap())),
+    ]);
+    let proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Udp),
+        ProtoTuple::SourcePort(5353),
+        ProtoTuple::DestinationPort(5353),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![
+            ip_tuple,
+            proto_tuple,
+            Tuple::Zone(10),
+        ]),
+        ConntrackAttribute::CtaZone(10),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::Get(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::Get) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -G -p icmp -s 10.0.0.1 -d 8.8.8.8 --icmp-type 8 --icmp-code 0
+// --icmp-id 1234 (netlink message header removed)
+#[test]
+fn test_get_conntrack_icmp_ipv4() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x08, 0x08, 0x08, 0x08, 0x24, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x08, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00,
+        0x04, 0xd2, 0x00, 0x00,
+    ];
+
+    let ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.0.0.1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("8.8.8.8".parse().unwrap())),
+    ]);
+    let proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Icmp),
+        ProtoTuple::IcmpCode(0),
+        ProtoTuple::IcmpType(8),
+        ProtoTuple::IcmpId(1234),
+    ]);
+
+    let attributes = vec![ConntrackAttribute::CtaTupleOrig(vec![
+        ip_tuple,
+        proto_tuple,
+    ])];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::Get(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::Get) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -D -f ipv6 -p icmpv6 -s 2001:db8::1 -d 2001:4860:4860::8888
+// --icmpv6-type 128 --icmpv6-code 0 --icmpv6-id 4321 (netlink message header
+// removed)
+#[test]
+fn test_delete_conntrack_icmpv6() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x00, 0x00, 0x54, 0x00, 0x01, 0x80, 0x2c, 0x00, 0x01, 0x80,
+        0x14, 0x00, 0x03, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x04, 0x00,
+        0x20, 0x01, 0x48, 0x60, 0x48, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x88, 0x88, 0x24, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x3a, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x08, 0x00, 0x80, 0x00, 0x00, 0x00, 0x06, 0x00, 0x07, 0x00,
+        0x10, 0xe1, 0x00, 0x00,
+    ];
+
+    let ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V6("2001:db8::1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V6(
+            "2001:4860:4860::8888".parse().unwrap(),
+        )),
+    ]);
+    let proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Ipv6Icmp),
+        ProtoTuple::Icmpv6Code(0),
+        ProtoTuple::Icmpv6Type(128),
+        ProtoTuple::Icmpv6Id(4321),
+    ]);
+
+    let attributes = vec![ConntrackAttribute::CtaTupleOrig(vec![
+        ip_tuple,
+        proto_tuple,
+    ])];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv6, 0, 0),
+        ConntrackMessage::Delete(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::Delete) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L -p sctp, one entry of the dump reply (netlink message header
+// removed)
+#[test]
+fn test_dump_conntrack_sctp() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x01, 0x00, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x02, 0x00, 0x14, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x84, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x0b, 0x59, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x0b, 0x59, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x02, 0x00, 0x14,
+        0x08, 0x00, 0x02, 0x00, 0x0a, 0x01, 0x00, 0x0a, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x84, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x0b, 0x59, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x0b, 0x59, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x06, 0x97, 0x7f, 0x20, 0x00, 0x04, 0x80, 0x1c, 0x00, 0x03, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x8a, 0x3f, 0x21, 0xc7, 0x08, 0x00, 0x03, 0x00, 0x1b, 0x2e, 0x9d, 0x04,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.1.0.10".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.2.0.20".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Sctp),
+        ProtoTuple::SourcePort(2905),
+        ProtoTuple::DestinationPort(2905),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.2.0.20".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.1.0.10".parse().unwrap())),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Sctp),
+        ProtoTuple::SourcePort(2905),
+        ProtoTuple::DestinationPort(2905),
+    ]);
+
+    let status = Status::SeenReply | Status::Assured | Status::Confirmed;
+
+    let timeout = 431999;
+    let proto_info = ProtoInfo::Sctp(vec![
+        ProtoInfoSCTP::State(SctpState::Established),
+        ProtoInfoSCTP::OriginalVerificationTag(0x8a3f21c7),
+        ProtoInfoSCTP::ReplyVerificationTag(0x1b2e9d04),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaStatus(status),
+        ConntrackAttribute::CtaTimeout(timeout),
+        ConntrackAttribute::CtaProtoInfo(vec![proto_info]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L -p dccp, one entry of the dump reply (netlink message header
+// removed)
+#[test]
+fn test_dump_conntrack_dccp() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x01, 0x00, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x02, 0x00, 0x14, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9d, 0x1e, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x13, 0x8c, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x02, 0x00, 0x14,
+        0x08, 0x00, 0x02, 0x00, 0x0a, 0x01, 0x00, 0x0a, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x21, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x13, 0x8c, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9d, 0x1e, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0xa8, 0xbf, 0x24, 0x00, 0x04, 0x80, 0x20, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xa1, 0xb2, 0xc3, 0xd4,
+    ];
+
+    let orig_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.1.0.10".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.2.0.20".parse().unwrap())),
+    ]);
+    let orig_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Dccp),
+        ProtoTuple::SourcePort(40222),
+        ProtoTuple::DestinationPort(5004),
+    ]);
+
+    let reply_ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.2.0.20".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.1.0.10".parse().unwrap())),
+    ]);
+    let reply_proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Dccp),
+        ProtoTuple::SourcePort(5004),
+        ProtoTuple::DestinationPort(40222),
+    ]);
+
+    let status = Status::SeenReply | Status::Assured | Status::Confirmed;
+
+    let timeout = 43199;
+    let proto_info = ProtoInfo::Dccp(vec![
+        ProtoInfoDCCP::State(DccpState::Open),
+        ProtoInfoDCCP::Role(DccpRole::Client),
+        ProtoInfoDCCP::HandshakeSeq(0xa1b2c3d4),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![orig_ip_tuple, orig_proto_tuple]),
+        ConntrackAttribute::CtaTupleReply(vec![
+            reply_ip_tuple,
+            reply_proto_tuple,
+        ]),
+        ConntrackAttribute::CtaStatus(status),
+        ConntrackAttribute::CtaTimeout(timeout),
+        ConntrackAttribute::CtaProtoInfo(vec![proto_info]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_tcp_state_conversions() {
+    assert_eq!(TcpState::from(3), TcpState::Established);
+    assert_eq!(TcpState::from(9), TcpState::SynSent2);
+    assert_eq!(TcpState::from(42), TcpState::Other(42));
+    assert_eq!(u8::from(TcpState::TimeWait), 7);
+    assert_eq!(TcpState::from(u8::from(TcpState::LISTEN)), TcpState::LISTEN);
+    for state in 0..=10 {
+        assert_eq!(u8::from(TcpState::from(state)), state);
+    }
+
+    assert!(TcpState::Established.is_established());
+    assert!(TcpState::FinWait.is_closing());
+    assert!(!TcpState::SynRecv.is_closing());
+
+    assert_eq!(TcpState::Established.to_string(), "ESTABLISHED");
+    assert_eq!(TcpState::SynSent2.to_string(), "SYN_SENT2");
+}
+
+// conntrack -S, reply for the second CPU (netlink message header removed)
+#[test]
+fn test_get_conntrack_stats_cpu() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0xf0,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        StatsCpu::Found(1520),
+        StatsCpu::Invalid(12),
+        StatsCpu::Insert(0),
+        StatsCpu::InsertFailed(0),
+        StatsCpu::Drop(3),
+        StatsCpu::EarlyDrop(0),
+        StatsCpu::Error(1),
+        StatsCpu::SearchRestart(7),
+        StatsCpu::ClashResolve(2),
+        StatsCpu::ChainTooLong(0),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 1),
+        ConntrackMessage::GetStatsCpu(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::GetStatsCpu) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -C (netlink message header removed)
+#[test]
+fn test_get_conntrack_stats() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x56,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x04, 0x00, 0x00,
+    ];
+
+    let attributes =
+        vec![StatsGlobal::Entries(342), StatsGlobal::MaxEntries(262144)];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        ConntrackMessage::GetStats(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::GetStats) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_conntrack_event_from_message() {
+    let event = |flags: u16, message: ConntrackMessage| {
+        let mut hdr = NetlinkHeader::default();
+        hdr.flags = flags;
+        let mut message = NetlinkMessage::new(
+            hdr,
+            NetlinkPayload::from(NetfilterMessage::new(
+                NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+                message,
+            )),
+        );
+        message.finalize();
+
+        let mut buffer = vec![0; message.buffer_len()];
+        message.serialize(&mut buffer);
+        let message =
+            NetlinkMessage::<NetfilterMessage>::deserialize(&buffer).unwrap();
+        ConntrackEvent::from_message(&message)
+    };
+
+    assert_eq!(
+        event(NLM_F_CREATE | NLM_F_EXCL, ConntrackMessage::New(vec![])),
+        Some(ConntrackEvent::New)
+    );
+    assert_eq!(
+        event(0, ConntrackMessage::New(vec![])),
+        Some(ConntrackEvent::Update)
+    );
+    assert_eq!(
+        event(0, ConntrackMessage::Delete(vec![])),
+        Some(ConntrackEvent::Destroy)
+    );
+    assert_eq!(event(0, ConntrackMessage::GetStats(vec![])), None);
+
+    assert_eq!(ConntrackEvent::Destroy.group(), 3);
+    assert_eq!(ConntrackEvent::ExpNew.group(), 4);
+}
+
+// conntrack -L -f ipv4 -p tcp --dport 443 --mark 0x10/0xff
+// (netlink message header removed)
+#[test]
+fn test_dump_conntrack_filter() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x80, 0x14, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x01, 0xbb, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0xff, 0x0c, 0x00, 0x19, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x28, 0x00, 0x00, 0x00,
+    ];
+
+    let attributes = ConntrackFilter::new()
+        .orig(
+            TupleFilter::ipv4().proto(PortFilter::tcp().destination_port(443)),
+        )
+        .mark(0x10, 0xff)
+        .build();
+
+    assert_eq!(
+        attributes,
+        vec![
+            ConntrackAttribute::CtaTupleOrig(vec![Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::DestinationPort(443),
+            ])]),
+            ConntrackAttribute::CtaMark(0x10),
+            ConntrackAttribute::CtaMarkMask(0xff),
+            ConntrackAttribute::CtaFilter(vec![Filter::OrigFlags(
+                FilterFlags::ProtoNum | FilterFlags::ProtoDstPort
+            )]),
+        ]
+    );
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::Get(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::Get) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_conntrack_filter_flags() {
+    let attributes = ConntrackFilter::new()
+        .orig(
+            TupleFilter::ipv6()
+                .source("2001:db8::1".parse().unwrap())
+                .proto(IcmpFilter::new().icmp_type(128))
+                .zone(1),
+        )
+        .reply(TupleFilter::ipv6().destination("2001:db8::1".parse().unwrap()))
+        .status(Status::Assured, Status::Assured)
+        .build();
+
+    assert_eq!(
+        attributes.last(),
+        Some(&ConntrackAttribute::CtaFilter(vec![
+            Filter::OrigFlags(
+                FilterFlags::IpSrc
+                    | FilterFlags::ProtoNum
+                    | FilterFlags::Icmpv6Type
+                    | FilterFlags::TupleZone
+            ),
+            Filter::ReplyFlags(FilterFlags::IpDst),
+        ]))
+    );
+    assert!(attributes
+        .contains(&ConntrackAttribute::CtaStatusMask(Status::Assured)));
+
+    // The ICMP fields follow the address family of the tuple
+    assert_eq!(
+        ConntrackFilter::new()
+            .orig(TupleFilter::ipv4().proto(IcmpFilter::new().icmp_type(8)))
+            .build(),
+        vec![
+            ConntrackAttribute::CtaTupleOrig(vec![Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Icmp),
+                ProtoTuple::IcmpType(8),
+            ])]),
+            ConntrackAttribute::CtaFilter(vec![Filter::OrigFlags(
+                FilterFlags::ProtoNum | FilterFlags::IcmpType
+            )]),
+        ]
+    );
+
+    // Without any tuple, no CTA_FILTER is needed
+    assert_eq!(
+        ConntrackFilter::new().mark(1, 1).build(),
+        vec![
+            ConntrackAttribute::CtaMark(1),
+            ConntrackAttribute::CtaMarkMask(1),
+        ]
+    );
+}
+
+// conntrack -I -p tcp -s 10.0.0.1 -d 10.0.0.2 --sport 40000 --dport 21
+// --timeout 120 --helper-name ftp (netlink message header removed)
+#[test]
+fn test_new_conntrack_helper() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x15, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x78, 0x0c, 0x00, 0x05, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x66, 0x74, 0x70, 0x00,
+    ];
+
+    let ip_tuple = Tuple::Ip(vec![
+        IPTuple::SourceAddress(IpAddr::V4("10.0.0.1".parse().unwrap())),
+        IPTuple::DestinationAddress(IpAddr::V4("10.0.0.2".parse().unwrap())),
+    ]);
+    let proto_tuple = Tuple::Proto(vec![
+        ProtoTuple::Protocol(Protocol::Tcp),
+        ProtoTuple::SourcePort(40000),
+        ProtoTuple::DestinationPort(21),
+    ]);
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(vec![ip_tuple, proto_tuple]),
+        ConntrackAttribute::CtaTimeout(120),
+        ConntrackAttribute::CtaHelp(vec![Help::Name("ftp".to_string())]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L after `nft add rule ip t out ct secmark set 3` and a UDP
+// datagram sent from 127.0.0.1:40000 to 127.0.0.1:9999, SELinux being enabled
+// without a policy, the secmark is named after its initial SID (netlink
+// message header removed)
+#[test]
+fn test_dump_conntrack_secctx() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x7f, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x27, 0x0f, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x27, 0x0f, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x13, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x75, 0x6e, 0x6c, 0x61, 0x62, 0x65, 0x6c, 0x65, 0x64, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0c, 0x00, 0x2a, 0xb9, 0xff, 0xf6, 0x08, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x1e,
+    ];
+
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Udp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let attributes = vec![
+        ConntrackAttribute::CtaTupleOrig(tuple(
+            "127.0.0.1",
+            "127.0.0.1",
+            40000,
+            9999,
+        )),
+        ConntrackAttribute::CtaTupleReply(tuple(
+            "127.0.0.1",
+            "127.0.0.1",
+            9999,
+            40000,
+        )),
+        ConntrackAttribute::CtaStatus(Status::Confirmed),
+        ConntrackAttribute::CtaMark(0),
+        ConntrackAttribute::CtaSecCtx(vec![SecCtx::Name(
+            "unlabeled".to_string(),
+        )]),
+        ConntrackAttribute::CtaId(0x2ab9fff6),
+        ConntrackAttribute::CtaUse(1),
+        ConntrackAttribute::CtaTimeout(30),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_conntrack_labels() {
+    let map = ConnlabelMap::parse(
+        "# connlabel.conf\n\
+         0\teth0-in\n\
+         1 eth0-out\n\
+         \n\
+         0x7f   last # trailing comment\n",
+    )
+    .unwrap();
+    assert_eq!(map.name(1), Some("eth0-out"));
+    assert_eq!(map.bit("last"), Some(127));
+    assert_eq!(map.bit("eth1-in"), None);
+    assert!(ConnlabelMap::parse("128 out-of-range").is_err());
+    assert!(ConnlabelMap::parse("eth0-in").is_err());
+
+    let mut labels = map.labels(["eth0-in", "last"]).unwrap();
+    labels.set(64);
+    labels.clear(0);
+    assert_eq!(labels.iter().collect::<Vec<_>>(), vec![64, 127]);
+    assert_eq!(map.names(&labels).collect::<Vec<_>>(), vec!["last"]);
+    assert!(map.labels(["eth1-in"]).is_none());
+
+    // Update of an entry created with the eth0-in label, the kernel replaces
+    // the bits of the mask by the ones of the labels. The bitmaps are in host
+    // byte order.
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x7f, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x42, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x27, 0x0d, 0x00, 0x00, 0x14, 0x00, 0x16, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x80, 0x14, 0x00, 0x17, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+    ];
+
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Udp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+    let labels = map.labels(["eth0-in", "last"]).unwrap();
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(vec![
+            ConntrackAttribute::CtaTupleOrig(tuple(
+                "127.0.0.1",
+                "127.0.0.1",
+                40002,
+                9997,
+            )),
+            ConntrackAttribute::CtaLabels(labels),
+            ConntrackAttribute::CtaLabelsMask(labels),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    // conntrack -L -o label after the update
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x7f, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x42, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x27, 0x0d, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x11, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x27, 0x0d, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9c, 0x42, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0xcb, 0x57, 0x06, 0x43,
+        0x08, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x1e, 0x14, 0x00, 0x16, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(vec![
+            ConntrackAttribute::CtaTupleOrig(tuple(
+                "127.0.0.1",
+                "127.0.0.1",
+                40002,
+                9997,
+            )),
+            ConntrackAttribute::CtaTupleReply(tuple(
+                "127.0.0.1",
+                "127.0.0.1",
+                9997,
+                40002,
+            )),
+            ConntrackAttribute::CtaStatus(Status::Confirmed),
+            ConntrackAttribute::CtaMark(0),
+            ConntrackAttribute::CtaId(0xcb570643),
+            ConntrackAttribute::CtaUse(1),
+            ConntrackAttribute::CtaTimeout(30),
+            ConntrackAttribute::CtaLabels(labels),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L on an active FTP session, the data connection has been created
+// from the expectation of the ftp helper (netlink message header removed)
+#[test]
+fn test_dump_conntrack_master() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x14, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x0b, 0x34, 0x00, 0x0e, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x15, 0x00, 0x00,
+    ];
+
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let child = vec![
+        ConntrackAttribute::CtaTupleOrig(tuple(
+            "10.0.0.2", "10.0.0.1", 20, 40001,
+        )),
+        ConntrackAttribute::CtaStatus(
+            Status::Expected | Status::SeenReply | Status::Confirmed,
+        ),
+        ConntrackAttribute::CtaTupleMaster(tuple(
+            "10.0.0.1", "10.0.0.2", 40000, 21,
+        )),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(child.clone()),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    let mut master_orig = tuple("10.0.0.1", "10.0.0.2", 40000, 21);
+    master_orig.push(Tuple::Zone(1));
+    let master = vec![
+        ConntrackAttribute::CtaTupleOrig(master_orig),
+        ConntrackAttribute::CtaStatus(Status::SeenReply | Status::Confirmed),
+    ];
+    let mut orphan = child.clone();
+    orphan[2] = ConntrackAttribute::CtaTupleMaster(tuple(
+        "10.0.0.3", "10.0.0.2", 40000, 21,
+    ));
+
+    assert_eq!(
+        find_masters(&[child, master, orphan]),
+        vec![Some(1), None, None]
+    );
+}
+
+// conntrackd restoring a synproxied connection on the backup node (netlink
+// message header removed)
+#[test]
+fn test_new_conntrack_synproxy() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x02, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0xc6, 0x33, 0x64, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0xc8, 0x22, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x01, 0xbb, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xc6, 0x33, 0x64, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x0a, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x01, 0xbb, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xc8, 0x22, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x78, 0x1c, 0x00, 0x18, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x5a, 0x3c, 0x1f, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x12, 0xd6, 0x87, 0x08, 0x00, 0x03, 0x00, 0xff, 0xff, 0x8f, 0x10,
+    ];
+
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+    let synproxy = ConntrackAttribute::CtaSynproxy(vec![
+        Synproxy::Isn(0x5a3c1f02),
+        Synproxy::Its(0x0012d687),
+        Synproxy::Tsoff(0xffff8f10),
+    ]);
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(vec![
+            ConntrackAttribute::CtaTupleOrig(tuple(
+                "192.0.2.10",
+                "198.51.100.1",
+                51234,
+                443,
+            )),
+            ConntrackAttribute::CtaTupleReply(tuple(
+                "198.51.100.1",
+                "192.0.2.10",
+                443,
+                51234,
+            )),
+            ConntrackAttribute::CtaTimeout(120),
+            synproxy.clone(),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Conntrack) as u16) << 8)
+        | (u8::from(ConntrackMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    // conntrack -L of the restored entry
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x02, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0xc6, 0x33, 0x64, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0xc8, 0x22, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x01, 0xbb, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xc6, 0x33, 0x64, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x0a, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x01, 0xbb, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xc8, 0x22, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x1d, 0x43, 0xe2, 0x8f,
+        0x08, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x74, 0x30, 0x00, 0x04, 0x80, 0x2c, 0x00, 0x01, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x18, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x5a, 0x3c, 0x1f, 0x02, 0x08, 0x00, 0x02, 0x00, 0x00, 0x12, 0xd6, 0x87,
+        0x08, 0x00, 0x03, 0x00, 0xff, 0xff, 0x8f, 0x10,
+    ];
+
+    let no_flags = TCPFlags {
+        flags: TCPWindowFlags::empty(),
+        mask: TCPWindowFlags::empty(),
+    };
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ConntrackMessage::New(vec![
+            ConntrackAttribute::CtaTupleOrig(tuple(
+                "192.0.2.10",
+                "198.51.100.1",
+                51234,
+                443,
+            )),
+            ConntrackAttribute::CtaTupleReply(tuple(
+                "198.51.100.1",
+                "192.0.2.10",
+                443,
+                51234,
+            )),
+            ConntrackAttribute::CtaStatus(Status::Confirmed),
+            ConntrackAttribute::CtaMark(0),
+            ConntrackAttribute::CtaId(0x1d43e28f),
+            ConntrackAttribute::CtaUse(1),
+            ConntrackAttribute::CtaTimeout(116),
+            ConntrackAttribute::CtaProtoInfo(vec![ProtoInfo::TCP(vec![
+                ProtoInfoTCP::State(TcpState::None),
+                ProtoInfoTCP::OriginalWindowScale(0),
+                ProtoInfoTCP::ReplyWindowScale(0),
+                ProtoInfoTCP::OriginalFlags(no_flags.clone()),
+                ProtoInfoTCP::ReplyFlags(no_flags),
+            ])]),
+            synproxy,
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// conntrack -L expect during an active FTP session (netlink message header
+// removed)
+#[test]
+fn test_dump_expect() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00, 0x34, 0x00, 0x03, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x08, 0x00, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xff, 0xff, 0x00, 0x00,
+        0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x02,
+        0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x2b,
+        0x08, 0x00, 0x05, 0x00, 0x8a, 0xb3, 0xc2, 0xd1, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x66, 0x74, 0x70, 0x00,
+    ];
+
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let attributes = vec![
+        ExpectAttribute::Tuple(tuple("10.0.0.2", "10.0.0.1", 0, 40001)),
+        ExpectAttribute::Mask(tuple(
+            "255.255.255.255",
+            "255.255.255.255",
+            0,
+            0xffff,
+        )),
+        ExpectAttribute::Master(tuple("10.0.0.1", "10.0.0.2", 40000, 21)),
+        ExpectAttribute::Timeout(299),
+        ExpectAttribute::Id(0x8ab3c2d1),
+        ExpectAttribute::Flags(ExpectFlags::empty()),
+        ExpectAttribute::Class(0),
+        ExpectAttribute::HelpName("ftp".to_string()),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ExpectMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackExpect) as u16) << 8)
+        | (u8::from(ExpectMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Expectation of the FTP data connection of a conntrack entry in zone 1, whose
+// NATed address is 192.168.1.1 (netlink message header removed)
+#[test]
+fn test_new_expect_nat() {
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x15, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02,
+        0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00,
+        0x34, 0x00, 0x03, 0x80, 0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0x08, 0x00, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0xff, 0xff, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x2c,
+        0x08, 0x00, 0x06, 0x00, 0x66, 0x74, 0x70, 0x00, 0x06, 0x00, 0x07, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x0a, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x34, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00,
+        0x16, 0x00, 0x0b, 0x00, 0x6e, 0x61, 0x74, 0x2d, 0x66, 0x6f, 0x6c, 0x6c,
+        0x6f, 0x77, 0x2d, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x00, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        ExpectAttribute::Master(tuple("10.0.0.1", "10.0.0.2", 40000, 21)),
+        ExpectAttribute::Tuple(tuple("10.0.0.2", "10.0.0.1", 0, 40001)),
+        ExpectAttribute::Mask(tuple(
+            "255.255.255.255",
+            "255.255.255.255",
+            0,
+            0xffff,
+        )),
+        ExpectAttribute::Timeout(300),
+        ExpectAttribute::HelpName("ftp".to_string()),
+        ExpectAttribute::Zone(1),
+        ExpectAttribute::Flags(ExpectFlags::empty()),
+        ExpectAttribute::Class(0),
+        ExpectAttribute::Nat(vec![
+            ExpectNat::Dir(ExpectDirection::Reply),
+            ExpectNat::Tuple(tuple("192.168.1.1", "0.0.0.0", 0, 40001)),
+        ]),
+        ExpectAttribute::Fn("nat-follow-master".to_string()),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ExpectMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackExpect) as u16) << 8)
+        | (u8::from(ExpectMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Lookup of the expectation created in test_new_expect_nat
+// (netlink message header removed)
+#[test]
+fn test_get_expect() {
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let request: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00, 0x06, 0x00, 0x07, 0x00,
+        0x00, 0x01, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        ExpectAttribute::Tuple(tuple("10.0.0.2", "10.0.0.1", 0, 40001)),
+        ExpectAttribute::Zone(1),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ExpectMessage::Get(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, request);
+
+    let message_type = ((u8::from(Subsystem::ConntrackExpect) as u16) << 8)
+        | (u8::from(ExpectMessageType::Get) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&request),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    // The kernel replies with a NEW message, the zone is not reported and the
+    // NAT tuple only holds the saved source address and port
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00, 0x34, 0x00, 0x03, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x08, 0x00, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff, 0x1c, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xff, 0xff, 0x00, 0x00,
+        0x34, 0x00, 0x01, 0x80, 0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x02,
+        0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x9c, 0x40, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x40, 0x00, 0x0a, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x34, 0x00, 0x02, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x01, 0x2c, 0x08, 0x00, 0x05, 0x00, 0x71, 0x34, 0x32, 0xc7,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x66, 0x74, 0x70, 0x00,
+        0x16, 0x00, 0x0b, 0x00, 0x6e, 0x61, 0x74, 0x2d, 0x66, 0x6f, 0x6c, 0x6c,
+        0x6f, 0x77, 0x2d, 0x6d, 0x61, 0x73, 0x74, 0x65, 0x72, 0x00, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        ExpectAttribute::Tuple(tuple("10.0.0.2", "10.0.0.1", 0, 40001)),
+        ExpectAttribute::Mask(tuple(
+            "255.255.255.255",
+            "255.255.255.255",
+            0,
+            0xffff,
+        )),
+        ExpectAttribute::Master(tuple("10.0.0.1", "10.0.0.2", 40000, 21)),
+        ExpectAttribute::Nat(vec![
+            ExpectNat::Dir(ExpectDirection::Reply),
+            ExpectNat::Tuple(tuple("192.168.1.1", "0.0.0.0", 0, 0)),
+        ]),
+        ExpectAttribute::Timeout(300),
+        ExpectAttribute::Id(0x713432c7),
+        ExpectAttribute::Flags(ExpectFlags::empty()),
+        ExpectAttribute::Class(0),
+        ExpectAttribute::HelpName("ftp".to_string()),
+        ExpectAttribute::Fn("nat-follow-master".to_string()),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ExpectMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackExpect) as u16) << 8)
+        | (u8::from(ExpectMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Deletion of the expectation created in test_new_expect_nat
+// (netlink message header removed)
+#[test]
+fn test_delete_expect() {
+    let tuple = |src: &str, dst: &str, sport, dport| {
+        vec![
+            Tuple::Ip(vec![
+                IPTuple::SourceAddress(src.parse().unwrap()),
+                IPTuple::DestinationAddress(dst.parse().unwrap()),
+            ]),
+            Tuple::Proto(vec![
+                ProtoTuple::Protocol(Protocol::Tcp),
+                ProtoTuple::SourcePort(sport),
+                ProtoTuple::DestinationPort(dport),
+            ]),
+        ]
+    };
+
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x9c, 0x41, 0x00, 0x00, 0x06, 0x00, 0x07, 0x00,
+        0x00, 0x01, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        ExpectAttribute::Tuple(tuple("10.0.0.2", "10.0.0.1", 0, 40001)),
+        ExpectAttribute::Zone(1),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        ExpectMessage::Delete(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackExpect) as u16) << 8)
+        | (u8::from(ExpectMessageType::Delete) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nfct timeout add test-tcp inet tcp established 100 close 10
+// (netlink message header removed)
+#[test]
+fn test_new_cttimeout_tcp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x01, 0x00, 0x74, 0x65, 0x73, 0x74,
+        0x2d, 0x74, 0x63, 0x70, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x04, 0x80, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x64,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::Name("test-tcp".to_string()),
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Tcp),
+        TimeoutAttribute::Data(TimeoutData::Tcp(vec![
+            TcpTimeout::Established(100),
+            TcpTimeout::Close(10),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nfct default-timeout-set inet6 udp unreplied 60 replied 180
+// (netlink message header removed)
+#[test]
+fn test_default_set_cttimeout_udp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x11, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0xb4,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv6),
+        TimeoutAttribute::L4Proto(Protocol::Udp),
+        TimeoutAttribute::Data(TimeoutData::Udp(vec![
+            UdpTimeout::Unreplied(60),
+            UdpTimeout::Replied(180),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet sctp
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_sctp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x84, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0xd2, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x1e, 0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Sctp),
+        TimeoutAttribute::Data(TimeoutData::Sctp(vec![
+            SctpTimeout::Closed(10),
+            SctpTimeout::CookieWait(3),
+            SctpTimeout::CookieEchoed(3),
+            SctpTimeout::Established(210),
+            SctpTimeout::ShutdownSent(3),
+            SctpTimeout::ShutdownRecd(3),
+            SctpTimeout::ShutdownAckSent(3),
+            SctpTimeout::HeartbeatSent(30),
+            SctpTimeout::HeartbeatAcked(0),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet gre
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_gre() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0xb4,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Gre),
+        TimeoutAttribute::Data(TimeoutData::Gre(vec![
+            GreTimeout::Unreplied(30),
+            GreTimeout::Replied(180),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet icmp
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_icmp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Icmp),
+        TimeoutAttribute::Data(TimeoutData::Icmp(vec![
+            GenericTimeout::Timeout(30),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet6 icmpv6
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_icmpv6() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv6),
+        TimeoutAttribute::L4Proto(Protocol::Ipv6Icmp),
+        TimeoutAttribute::Data(TimeoutData::Icmpv6(vec![
+            GenericTimeout::Timeout(30),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet6 udplite
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_udplite() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x0a, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x88, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x78,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv6),
+        TimeoutAttribute::L4Proto(Protocol::UdpLite),
+        TimeoutAttribute::Data(TimeoutData::UdpLite(vec![
+            UdpTimeout::Unreplied(30),
+            UdpTimeout::Replied(120),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to IPCTNL_MSG_TIMEOUT_DEFAULT_GET for inet, protocol 255
+// (netlink message header removed)
+#[test]
+fn test_default_get_cttimeout_generic() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0xff, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x58,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Other(255)),
+        TimeoutAttribute::Data(TimeoutData::Generic(vec![
+            GenericTimeout::Timeout(600),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::DefaultSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::DefaultSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nfct timeout add test-dccp inet dccp request 240 open 43200 timewait 240
+// (netlink message header removed, encoded by hand as the kernel it was
+// checked against has no DCCP conntrack support anymore)
+#[test]
+fn test_new_cttimeout_dccp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x01, 0x00, 0x74, 0x65, 0x73, 0x74,
+        0x2d, 0x64, 0x63, 0x63, 0x70, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xf0,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0xa8, 0xc0, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0xf0,
+    ];
+
+    let attributes = vec![
+        TimeoutAttribute::Name("test-dccp".to_string()),
+        TimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+        TimeoutAttribute::L4Proto(Protocol::Dccp),
+        TimeoutAttribute::Data(TimeoutData::Dccp(vec![
+            DccpTimeout::Request(240),
+            DccpTimeout::Open(43200),
+            DccpTimeout::TimeWait(240),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        TimeoutMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackTimeout) as u16) << 8)
+        | (u8::from(TimeoutMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nfct add helper ftp inet tcp, with the ftp policy of conntrackd
+// (netlink message header removed)
+#[test]
+fn test_new_cthelper() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x66, 0x74, 0x70, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x28, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x66, 0x74, 0x70, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let attributes = vec![
+        HelperAttribute::Name("ftp".to_string()),
+        HelperAttribute::Tuple(vec![
+            HelperTuple::L3ProtoNum(ProtoFamily::IPv4),
+            HelperTuple::L4ProtoNum(Protocol::Tcp),
+        ]),
+        HelperAttribute::QueueNum(5),
+        HelperAttribute::Policy(vec![
+            HelperPolicySet::SetNum(1),
+            HelperPolicySet::Set1(vec![
+                HelperPolicy::Name("ftp".to_string()),
+                HelperPolicy::ExpectMax(1),
+                HelperPolicy::ExpectTimeout(300),
+            ]),
+        ]),
+        HelperAttribute::PrivDataLen(0),
+        HelperAttribute::Status(HelperStatus::Enabled),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        HelperMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::ConntrackHelper) as u16) << 8)
+        | (u8::from(HelperMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Creation of http-quota with a quota of 1048576 bytes and initial counters
+// (netlink message header removed)
+#[test]
+fn test_new_nfacct_quota() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x68, 0x74, 0x74, 0x70,
+        0x2d, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xf3, 0x0c, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        AcctAttribute::Name("http-quota".to_string()),
+        AcctAttribute::Pkts(1523),
+        AcctAttribute::Bytes(524288),
+        AcctAttribute::Flags(AcctFlags::QuotaBytes),
+        AcctAttribute::Quota(1048576),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Counters of http-quota read and reset at once
+// (netlink message header removed)
+#[test]
+fn test_nfacct_get_ctrzero() {
+    let request: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x68, 0x74, 0x74, 0x70,
+        0x2d, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00,
+    ];
+
+    let attributes = vec![AcctAttribute::Name("http-quota".to_string())];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::GetCtrZero(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, request);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::GetCtrZero) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&request),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    // The kernel replies with a NEW message holding the counters as they were
+    // before being reset
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x68, 0x74, 0x74, 0x70,
+        0x2d, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xf3, 0x0c, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        AcctAttribute::Name("http-quota".to_string()),
+        AcctAttribute::Pkts(1523),
+        AcctAttribute::Bytes(524288),
+        AcctAttribute::Use(1),
+        AcctAttribute::Flags(AcctFlags::QuotaBytes),
+        AcctAttribute::Quota(1048576),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::New(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::New) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Dump of the objects with a quota in bytes
+// (netlink message header removed)
+#[test]
+fn test_get_nfacct_filter() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x07, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    let attributes = vec![AcctAttribute::Filter(vec![
+        AcctFilter::Mask(AcctFlags::QuotaBytes),
+        AcctFilter::Value(AcctFlags::QuotaBytes),
+    ])];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::Get(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::Get) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nfacct delete http-quota (netlink message header removed)
+#[test]
+fn test_delete_nfacct() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x68, 0x74, 0x74, 0x70,
+        0x2d, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00,
+    ];
+
+    let attributes = vec![AcctAttribute::Name("http-quota".to_string())];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::Delete(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::Delete) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Overquota event sent to NFNLGRP_ACCT_QUOTA after
+// nfacct add http-quota --quota 1048576 --byte
+// (netlink message header removed)
+#[test]
+fn test_nfacct_overquota() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x68, 0x74, 0x74, 0x70,
+        0x2d, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0xf3, 0x0c, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x06,
+        0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+    ];
+
+    let attributes = vec![
+        AcctAttribute::Name("http-quota".to_string()),
+        AcctAttribute::Pkts(1523),
+        AcctAttribute::Bytes(1048577),
+        AcctAttribute::Use(1),
+        AcctAttribute::Flags(AcctFlags::QuotaBytes | AcctFlags::OverQuota),
+        AcctAttribute::Quota(1048576),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Unspec, 0, 0),
+        AcctMessage::OverQuota(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Acct) as u16) << 8)
+        | (u8::from(AcctMessageType::OverQuota) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// ping 192.168.0.2 received on queue 3 of an 802.1Q tagged interface,
+// with nfqueue --queue-num 3 in the INPUT chain
+// (netlink message header removed)
+#[test]
+fn test_nfqueue_packet() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x03, 0x0b, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x01, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x09, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x00,
+        0x14, 0x00, 0x13, 0x80, 0x06, 0x00, 0x01, 0x00, 0x81, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x00, 0x64, 0x00, 0x00, 0x12, 0x00, 0x14, 0x00,
+        0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x52, 0x54, 0x00, 0xab, 0xcd, 0xef,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x18, 0x00, 0x0a, 0x00, 0x45, 0x00, 0x00, 0x54, 0xa1, 0xb2, 0x40, 0x00,
+        0x40, 0x01, 0x5f, 0xe7, 0xc0, 0xa8, 0x00, 0x01, 0xc0, 0xa8, 0x00, 0x02,
+    ];
+
+    let payload = vec![
+        0x45, 0x00, 0x00, 0x54, 0xa1, 0xb2, 0x40, 0x00, 0x40, 0x01, 0x5f, 0xe7,
+        0xc0, 0xa8, 0x00, 0x01, 0xc0, 0xa8, 0x00, 0x02,
+    ];
+    let l2hdr = vec![
+        0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x52, 0x54, 0x00, 0xab, 0xcd, 0xef,
+        0x08, 0x00,
+    ];
+    let nlas = vec![
+        PacketNla::PacketHdr(PacketHdr {
+            packet_id: 1,
+            hw_protocol: 0x0800,
+            hook: 1,
+        }),
+        PacketNla::Mark(0x10),
+        PacketNla::IfIndexInDev(2),
+        PacketNla::HwAddr(HwAddr::new(&[0x52, 0x54, 0x00, 0x12, 0x34, 0x56])),
+        PacketNla::Vlan(vec![VlanNla::Proto(0x8100), VlanNla::Tci(100)]),
+        PacketNla::L2Hdr(l2hdr),
+        PacketNla::SkbInfo(SkbInfo::CsumNotReady),
+        PacketNla::Payload(payload),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 3),
+        QueueMessage::Packet(nlas),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Queue) as u16) << 8)
+        | (u8::from(QueueMessageType::Packet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_nfqueue_verdict() {
+    let raw: Vec<u8> = vec![
+        0x28, 0x00, 0x00, 0x00, 0x01, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x02, 0x80, 0x03, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x10,
+    ];
+
+    let verdict = Verdict::Queue {
+        num: 2,
+        bypass: true,
+    };
+    let message = verdict_request(3, 1, verdict, vec![VerdictNla::Mark(0x10)]);
+
+    let mut buffer = vec![0; message.buffer_len()];
+    message.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetlinkMessage::<NetfilterMessage>::deserialize(&raw).unwrap(),
+        message
+    );
+
+    assert_eq!(Verdict::from(u32::from(Verdict::Accept)), Verdict::Accept);
+    assert_eq!(
+        Verdict::from(0x0002_0003),
+        Verdict::Queue {
+            num: 2,
+            bypass: false,
+        }
+    );
+    // NF_DROP_ERR(-EPERM) can't be set from userspace
+    assert_eq!(Verdict::from(0x0001_0000), Verdict::Other(0x0001_0000));
+}
+
+// UDP datagram sent to 127.0.0.1:9999 by a socket with SO_MARK 0x10 and
+// SO_PRIORITY 6, queued to queue 3 in the OUTPUT chain with the NFQUEUE target
+// and NFQA_CFG_F_UID_GID set
+// (netlink message header removed)
+#[test]
+fn test_nfqueue_packet_output() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x03, 0x0b, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x10, 0x08, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x08, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x0a, 0x00,
+        0x45, 0x00, 0x00, 0x20, 0xff, 0x60, 0x40, 0x00, 0x40, 0x11, 0x3d, 0x6a,
+        0x7f, 0x00, 0x00, 0x01, 0x7f, 0x00, 0x00, 0x01, 0x9c, 0x40, 0x27, 0x0f,
+        0x00, 0x0c, 0x5f, 0xb3, 0x70, 0x69, 0x6e, 0x67,
+    ];
+
+    let payload = vec![
+        0x45, 0x00, 0x00, 0x20, 0xff, 0x60, 0x40, 0x00, 0x40, 0x11, 0x3d, 0x6a,
+        0x7f, 0x00, 0x00, 0x01, 0x7f, 0x00, 0x00, 0x01, 0x9c, 0x40, 0x27, 0x0f,
+        0x00, 0x0c, 0x5f, 0xb3, 0x70, 0x69, 0x6e, 0x67,
+    ];
+    let nlas = vec![
+        PacketNla::PacketHdr(PacketHdr {
+            packet_id: 1,
+            hw_protocol: 0x0800,
+            hook: 3,
+        }),
+        PacketNla::IfIndexOutDev(1),
+        PacketNla::Mark(0x10),
+        PacketNla::Priority(6),
+        PacketNla::Uid(0),
+        PacketNla::Gid(0),
+        PacketNla::Payload(payload),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 3),
+        QueueMessage::Packet(nlas),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Queue) as u16) << 8)
+        | (u8::from(QueueMessageType::Packet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Accepts the packet of test_nfqueue_packet_output, and all the packets
+// queued to queue 3 before it, setting their mark to 0x20
+#[test]
+fn test_nfqueue_verdict_batch() {
+    let raw: Vec<u8> = vec![
+        0x28, 0x00, 0x00, 0x00, 0x03, 0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x20,
+    ];
+
+    let message = verdict_batch_request(
+        3,
+        1,
+        Verdict::Accept,
+        vec![VerdictNla::Mark(0x20)],
+    );
+
+    let mut buffer = vec![0; message.buffer_len()];
+    message.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetlinkMessage::<NetfilterMessage>::deserialize(&raw).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn test_nfqueue_config() {
+    let raw: Vec<u8> = vec![
+        0x40, 0x00, 0x00, 0x00, 0x02, 0x03, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x09, 0x00, 0x02, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x05, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x05,
+    ];
+
+    let flags = ConfigFlags::FAIL_OPEN | ConfigFlags::GSO;
+    let message = config_request(
+        3,
+        vec![
+            ConfigCmd::new(ConfigCommand::Bind, ProtoFamily::Unspec).into(),
+            ConfigParams::new_packet(0xffff).into(),
+            ConfigNla::QueueMaxLen(1024),
+            ConfigNla::Mask(flags),
+            ConfigNla::Flags(flags),
+        ],
+    );
+
+    let mut buffer = vec![0; message.buffer_len()];
+    message.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetlinkMessage::<NetfilterMessage>::deserialize(&raw).unwrap(),
+        message
+    );
+}
+
+// nft list tables, reply for the inet filter table
+// (netlink message header removed)
+#[test]
+fn test_get_nftables_table() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+    ];
+
+    let attributes = vec![
+        TableAttribute::Name("filter".to_string()),
+        TableAttribute::Flags(TableFlags::empty()),
+        TableAttribute::Use(2),
+        TableAttribute::Handle(3),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Inet, 0, 0),
+        NftMessage::NewTable(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewTable) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// nft add chain netdev ingress in '{ type filter hook ingress devices = {
+// eth0, eth1 } priority -500; }', as listed by nft list chains
+// (netlink message header removed)
+#[test]
+fn test_get_nftables_netdev_chain() {
+    let raw: Vec<u8> = vec![
+        0x05, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x69, 0x6e, 0x67, 0x72,
+        0x65, 0x73, 0x73, 0x00, 0x07, 0x00, 0x03, 0x00, 0x69, 0x6e, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x30, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0xff, 0xff, 0xfe, 0x0c, 0x1c, 0x00, 0x04, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x31, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x07, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let attributes = vec![
+        ChainAttribute::Table("ingress".to_string()),
+        ChainAttribute::Name("in".to_string()),
+        ChainAttribute::Handle(1),
+        ChainAttribute::Hook(vec![
+            ChainHook::HookNum(0),
+            ChainHook::Priority(-500),
+            ChainHook::Devs(vec![
+                HookDevice::Name("eth0".to_string()),
+                HookDevice::Name("eth1".to_string()),
+            ]),
+        ]),
+        ChainAttribute::Policy(ChainPolicy::Accept),
+        ChainAttribute::Use(0),
+        ChainAttribute::Type("filter".to_string()),
+        ChainAttribute::Flags(ChainFlags::Base),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::NetDev, 0, 0),
+        NftMessage::NewChain(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewChain) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_table_owner() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0x0f, 0x00, 0x06, 0x00,
+        0x00, 0x09, 0x66, 0x69, 0x72, 0x65, 0x77, 0x61, 0x6c, 0x6c, 0x00, 0x00,
+    ];
+
+    // nft add table ip fw '{ flags dormant,owner; comment "firewall"; }'
+    let userdata = vec![
+        0x00, 0x09, 0x66, 0x69, 0x72, 0x65, 0x77, 0x61, 0x6c, 0x6c, 0x00,
+    ];
+    let attributes = vec![
+        TableAttribute::Name("fw".to_string()),
+        TableAttribute::Flags(TableFlags::Dormant | TableFlags::Owner),
+        TableAttribute::Userdata(userdata),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewTable(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewTable) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+// Reply of the kernel to NFT_MSG_GETTABLE for the table of
+// test_new_nftables_table_owner, owned by the netlink port id 5254
+// (netlink message header removed)
+#[test]
+fn test_get_nftables_table_owner() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x14, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x14, 0x86,
+        0x0f, 0x00, 0x06, 0x00, 0x00, 0x09, 0x66, 0x69, 0x72, 0x65, 0x77, 0x61,
+        0x6c, 0x6c, 0x00, 0x00,
+    ];
+
+    let userdata = vec![
+        0x00, 0x09, 0x66, 0x69, 0x72, 0x65, 0x77, 0x61, 0x6c, 0x6c, 0x00,
+    ];
+    let attributes = vec![
+        TableAttribute::Name("fw".to_string()),
+        TableAttribute::Use(1),
+        TableAttribute::Handle(10),
+        TableAttribute::Flags(TableFlags::Dormant | TableFlags::Owner),
+        TableAttribute::Owner(5254),
+        TableAttribute::Userdata(userdata),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 20),
+        NftMessage::NewTable(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewTable) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_chain_counters() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x03, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x07, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x1c, 0x00, 0x08, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xe2, 0x40, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x15, 0x08, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x0c, 0x00, 0x00, 0x0d, 0x69, 0x6e,
+        0x70, 0x75, 0x74, 0x20, 0x74, 0x72, 0x61, 0x66, 0x66, 0x69, 0x63, 0x00,
+    ];
+
+    // nft add chain ip fw input '{ type filter hook input priority 0;
+    // policy drop; comment "input traffic"; }', restoring the counters of the
+    // chain
+    let userdata = vec![
+        0x00, 0x0d, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x20, 0x74, 0x72, 0x61, 0x66,
+        0x66, 0x69, 0x63, 0x00,
+    ];
+    let attributes = vec![
+        ChainAttribute::Table("fw".to_string()),
+        ChainAttribute::Name("input".to_string()),
+        ChainAttribute::Hook(vec![
+            ChainHook::HookNum(1),
+            ChainHook::Priority(0),
+        ]),
+        ChainAttribute::Policy(ChainPolicy::Drop),
+        ChainAttribute::Type("filter".to_string()),
+        ChainAttribute::Counters(vec![
+            NftCounter::Bytes(123456),
+            NftCounter::Packets(789),
+        ]),
+        ChainAttribute::Id(1),
+        ChainAttribute::Userdata(userdata),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewChain(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewChain) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_nftables_batch() {
+    let raw: Vec<u8> = vec![
+        0x14, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    ];
+
+    let message = batch_begin();
+
+    let mut buffer = vec![0; message.buffer_len()];
+    message.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetlinkMessage::<NetfilterMessage>::deserialize(&raw).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn test_new_nftables_rule() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75,
+        0x74, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0xc0, 0x00, 0x04, 0x80, 0x34, 0x00, 0x01, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00,
+        0x24, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x0c, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x2c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00,
+        0x20, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x00,
+        0x1c, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // ip saddr 10.0.0.1 counter accept
+    let attributes = vec![
+        RuleAttribute::Table("filter".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Handle(4),
+        RuleAttribute::Expressions(vec![
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(12),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 1,
+                ])]),
+            ]),
+            Expression::Counter(vec![
+                NftCounter::Bytes(0),
+                NftCounter::Packets(0),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Verdict),
+                ImmediateAttribute::Data(vec![DataAttribute::Verdict(vec![
+                    VerdictAttribute::Code(VerdictCode::Accept),
+                ])]),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_unknown_expression() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75,
+        0x74, 0x00, 0x00, 0x00, 0x74, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x08, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x80, 0x07, 0x00, 0x01, 0x00,
+        0x72, 0x74, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let rt_data = vec![
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+    let attributes = vec![
+        RuleAttribute::Table("filter".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::L4Proto),
+                MetaAttribute::Dreg(Register::Reg32(0)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg32(0)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::Value(vec![6])]),
+            ]),
+            // The NFTA_EXPR_DATA of expressions without a typed
+            // representation is kept as is, including its NLA_F_NESTED flag
+            Expression::Other {
+                name: "rt".to_string(),
+                data: Some(DefaultNla::new(2 | NLA_F_NESTED, rt_data)),
+            },
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Inet, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_nftables_register_range() {
+    let registers = [
+        (Register::Verdict, 0),
+        (Register::Reg(1), 1),
+        (Register::Reg(4), 4),
+        (Register::Other(5), 5),
+        (Register::Reg32(0), 8),
+        (Register::Reg32(15), 23),
+        (Register::Other(24), 24),
+    ];
+    for (register, value) in registers {
+        assert_eq!(u32::from(register), value);
+        assert_eq!(Register::from(value), register);
+    }
+
+    // Out of range values are emitted as another register
+    assert_eq!(
+        Register::from(u32::from(Register::Reg(0))),
+        Register::Verdict
+    );
+    assert_eq!(
+        Register::from(u32::from(Register::Reg(8))),
+        Register::Reg32(0)
+    );
+    assert_eq!(
+        Register::from(u32::from(Register::Reg32(16))),
+        Register::Other(24)
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_range_byteorder() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x9c, 0x01, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00,
+        0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x80, 0x05, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x70, 0x61, 0x79, 0x6c,
+        0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x3c, 0x00, 0x01, 0x80, 0x0a, 0x00, 0x01, 0x00,
+        0x72, 0x61, 0x6e, 0x67, 0x65, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x03, 0xe8, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x07, 0xd0, 0x00, 0x00, 0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x02, 0x40, 0x00, 0x01, 0x80,
+        0x0e, 0x00, 0x01, 0x00, 0x62, 0x79, 0x74, 0x65, 0x6f, 0x72, 0x64, 0x65,
+        0x72, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x40, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x62, 0x69, 0x74, 0x77,
+        0x69, 0x73, 0x65, 0x00, 0x30, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x07, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00,
+        0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // tcp dport 1000-2000 meta mark set tcp dport << 16, the kernel this was
+    // checked against is little-endian
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::L4Proto),
+                MetaAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::from(Protocol::Tcp)]),
+            ]),
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Transport),
+                PayloadAttribute::Offset(2),
+                PayloadAttribute::Len(2),
+            ]),
+            Expression::Range(vec![
+                RangeAttribute::Sreg(Register::Reg(1)),
+                RangeAttribute::Op(RangeOp::Eq),
+                RangeAttribute::FromData(vec![DataAttribute::Value(
+                    1000u16.to_be_bytes().to_vec(),
+                )]),
+                RangeAttribute::ToData(vec![DataAttribute::Value(
+                    2000u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Transport),
+                PayloadAttribute::Offset(2),
+                PayloadAttribute::Len(2),
+            ]),
+            Expression::Byteorder(vec![
+                ByteorderAttribute::Sreg(Register::Reg(1)),
+                ByteorderAttribute::Dreg(Register::Reg(1)),
+                ByteorderAttribute::Op(ByteorderOp::Ntoh),
+                ByteorderAttribute::Len(2),
+                ByteorderAttribute::Size(2),
+            ]),
+            Expression::Bitwise(vec![
+                BitwiseAttribute::Sreg(Register::Reg(1)),
+                BitwiseAttribute::Dreg(Register::Reg(1)),
+                BitwiseAttribute::Len(4),
+                BitwiseAttribute::Op(BitwiseOp::Lshift),
+                // The shift is in host byte order
+                BitwiseAttribute::Data(vec![DataAttribute::Value(
+                    16u32.to_le_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::Mark),
+                MetaAttribute::Sreg(Register::Reg(1)),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_lookup_payload_write() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x94, 0x01, 0x04, 0x80, 0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0c,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x04, 0x38, 0x00, 0x01, 0x80,
+        0x0b, 0x00, 0x01, 0x00, 0x6c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x00, 0x00,
+        0x28, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x61, 0x6c, 0x6c, 0x6f,
+        0x77, 0x65, 0x64, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x04, 0x4c, 0x00, 0x01, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x62, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x00,
+        0x3c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0x00,
+        0x0c, 0x00, 0x05, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00,
+        0x20, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80,
+        0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74,
+        0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x1f, 0x90, 0x00, 0x00, 0x4c, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x3c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // ip saddr != @allowed ip daddr 10.0.0.0/24 tcp dport set 8080
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(12),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Lookup(vec![
+                LookupAttribute::Set("allowed".to_string()),
+                LookupAttribute::Sreg(Register::Reg(1)),
+                LookupAttribute::SetId(1),
+                LookupAttribute::Flags(LookupFlags::Inv),
+            ]),
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(16),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Bitwise(vec![
+                BitwiseAttribute::Sreg(Register::Reg(1)),
+                BitwiseAttribute::Dreg(Register::Reg(1)),
+                BitwiseAttribute::Len(4),
+                BitwiseAttribute::Op(BitwiseOp::Bool),
+                BitwiseAttribute::Mask(vec![DataAttribute::Value(vec![
+                    255, 255, 255, 0,
+                ])]),
+                BitwiseAttribute::Xor(vec![DataAttribute::Value(vec![
+                    0, 0, 0, 0,
+                ])]),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 0,
+                ])]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    8080u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Payload(vec![
+                PayloadAttribute::Sreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Transport),
+                PayloadAttribute::Offset(2),
+                PayloadAttribute::Len(2),
+                // NFT_PAYLOAD_CSUM_INET, the TCP checksum is at offset 16
+                PayloadAttribute::CsumType(1),
+                PayloadAttribute::CsumOffset(16),
+                PayloadAttribute::CsumFlags(0),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_ct_state() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75,
+        0x74, 0x00, 0x00, 0x00, 0xc4, 0x00, 0x04, 0x80, 0x20, 0x00, 0x01, 0x80,
+        0x07, 0x00, 0x01, 0x00, 0x63, 0x74, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x44, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x62, 0x69, 0x74, 0x77, 0x69, 0x73, 0x65, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x0c, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x05, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00,
+        0x20, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x03, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x01, 0x80,
+        0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74,
+        0x65, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // ct state established,related accept
+    let attributes = vec![
+        RuleAttribute::Table("filter".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Ct(vec![
+                CtAttribute::Key(CtKey::State),
+                CtAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Bitwise(vec![
+                BitwiseAttribute::Sreg(Register::Reg(1)),
+                BitwiseAttribute::Dreg(Register::Reg(1)),
+                BitwiseAttribute::Len(4),
+                BitwiseAttribute::Mask(vec![DataAttribute::from(
+                    CtState::Established | CtState::Related,
+                )]),
+                BitwiseAttribute::Xor(vec![DataAttribute::from(
+                    CtState::empty(),
+                )]),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Neq),
+                CmpAttribute::Data(vec![DataAttribute::from(CtState::empty())]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Verdict),
+                ImmediateAttribute::Data(vec![DataAttribute::Verdict(vec![
+                    VerdictAttribute::Code(VerdictCode::Accept),
+                ])]),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Inet, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_dnat() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x6e, 0x61, 0x74, 0x00,
+        0x0f, 0x00, 0x02, 0x00, 0x70, 0x72, 0x65, 0x72, 0x6f, 0x75, 0x74, 0x69,
+        0x6e, 0x67, 0x00, 0x00, 0xe4, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02,
+        0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65,
+        0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x38, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x6e, 0x61, 0x74, 0x00, 0x2c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // meta l4proto tcp dnat to 10.0.0.2:8080
+    let attributes = vec![
+        RuleAttribute::Table("nat".to_string()),
+        RuleAttribute::Chain("prerouting".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::L4Proto),
+                MetaAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::from(Protocol::Tcp)]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 2,
+                ])]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(2)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    8080u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Nat(vec![
+                NatAttribute::Type(NatType::Dnat),
+                NatAttribute::Family(ProtoFamily::IPv4),
+                NatAttribute::RegAddrMin(Register::Reg(1)),
+                NatAttribute::RegProtoMin(Register::Reg(2)),
+                NatAttribute::Flags(NatFlags::ProtoSpecified),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_stateful() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x72, 0x61, 0x77, 0x00,
+        0x0f, 0x00, 0x02, 0x00, 0x70, 0x72, 0x65, 0x72, 0x6f, 0x75, 0x74, 0x69,
+        0x6e, 0x67, 0x00, 0x00, 0x18, 0x01, 0x04, 0x80, 0x10, 0x00, 0x01, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x6e, 0x6f, 0x74, 0x72, 0x61, 0x63, 0x6b, 0x00,
+        0x44, 0x00, 0x01, 0x80, 0x0a, 0x00, 0x01, 0x00, 0x6c, 0x69, 0x6d, 0x69,
+        0x74, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x6c, 0x6f, 0x67, 0x00, 0x10, 0x00, 0x02, 0x80,
+        0x0c, 0x00, 0x02, 0x00, 0x66, 0x6c, 0x6f, 0x6f, 0x64, 0x3a, 0x20, 0x00,
+        0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x70, 0x61, 0x79, 0x6c,
+        0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x70, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x01, 0x00,
+        0x64, 0x79, 0x6e, 0x73, 0x65, 0x74, 0x00, 0x00, 0x60, 0x00, 0x02, 0x80,
+        0x0a, 0x00, 0x01, 0x00, 0x66, 0x6c, 0x6f, 0x6f, 0x64, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xea, 0x60, 0x2c, 0x00, 0x07, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // notrack limit rate 10/minute burst 5 packets log prefix "flood: "
+    // update @flood { ip saddr counter }
+    let attributes = vec![
+        RuleAttribute::Table("raw".to_string()),
+        RuleAttribute::Chain("prerouting".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Notrack,
+            Expression::Limit(vec![
+                LimitAttribute::Rate(10),
+                LimitAttribute::Unit(60),
+                LimitAttribute::Burst(5),
+                LimitAttribute::Type(LimitType::Packets),
+                LimitAttribute::Flags(LimitFlags::empty()),
+            ]),
+            Expression::Log(vec![LogAttribute::Prefix("flood: ".to_string())]),
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(12),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Dynset(vec![
+                DynsetAttribute::SetName("flood".to_string()),
+                DynsetAttribute::Op(DynsetOp::Update),
+                DynsetAttribute::SregKey(Register::Reg(1)),
+                DynsetAttribute::Timeout(60000),
+                DynsetAttribute::Expr(Expression::Counter(vec![
+                    NftCounter::Bytes(0),
+                    NftCounter::Packets(0),
+                ])),
+                DynsetAttribute::Flags(DynsetFlags::empty()),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_tproxy() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0f, 0x00, 0x02, 0x00, 0x70, 0x72, 0x65, 0x72, 0x6f, 0x75, 0x74, 0x69,
+        0x6e, 0x67, 0x00, 0x00, 0x50, 0x01, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00,
+        0x68, 0x61, 0x73, 0x68, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x24, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x28, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x6c, 0x6f, 0x67, 0x00,
+        0x1c, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x80, 0x06, 0x00, 0x04, 0x00,
+        0x00, 0x0a, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x7f, 0x00, 0x00, 0x01,
+        0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65,
+        0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x1f, 0x90, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80,
+        0x0b, 0x00, 0x01, 0x00, 0x74, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x00, 0x00,
+        0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // meta l4proto tcp meta mark set symhash mod 2 log group 2 snaplen 128
+    // queue-threshold 10 tproxy to 127.0.0.1:8080
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("prerouting".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::L4Proto),
+                MetaAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::from(Protocol::Tcp)]),
+            ]),
+            Expression::Hash(vec![
+                HashAttribute::Dreg(Register::Reg(1)),
+                HashAttribute::Modulus(2),
+                HashAttribute::Type(HashType::Sym),
+            ]),
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::Mark),
+                MetaAttribute::Sreg(Register::Reg(1)),
+            ]),
+            Expression::Log(vec![
+                LogAttribute::Group(2),
+                LogAttribute::Snaplen(128),
+                LogAttribute::QThreshold(10),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(vec![
+                    127, 0, 0, 1,
+                ])]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(2)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    8080u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Tproxy(vec![
+                TproxyAttribute::Family(ProtoFamily::IPv4),
+                TproxyAttribute::RegAddr(Register::Reg(1)),
+                TproxyAttribute::RegPort(Register::Reg(2)),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_ct_quota_hash() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x90, 0x02, 0x04, 0x80, 0x28, 0x00, 0x01, 0x80, 0x07, 0x00, 0x01, 0x00,
+        0x63, 0x74, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x01, 0x28, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x63, 0x6f, 0x6e, 0x6e, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x0a,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x34, 0x00, 0x01, 0x80,
+        0x0a, 0x00, 0x01, 0x00, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x01, 0x00, 0x6e, 0x75, 0x6d, 0x67,
+        0x65, 0x6e, 0x00, 0x00, 0x24, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x20, 0x00, 0x01, 0x80, 0x07, 0x00, 0x01, 0x00,
+        0x63, 0x74, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x70, 0x61, 0x79, 0x6c,
+        0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x4c, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00,
+        0x68, 0x61, 0x73, 0x68, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x10, 0x08, 0x00, 0x05, 0x00,
+        0xde, 0xad, 0xbe, 0xef, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x64,
+        0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x6c, 0x6f, 0x67, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x0c, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x3a, 0x20, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x24, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x01, 0x00,
+        0x6f, 0x62, 0x6a, 0x72, 0x65, 0x66, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x63, 0x6e, 0x74, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01,
+        0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65,
+        0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x64, 0x75, 0x70, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x01, 0x00,
+        0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // ct original ip saddr 10.0.0.1 ct count over 10 quota over 1 mbytes
+    // ct mark set numgen inc mod 4 offset 1
+    // meta mark set jhash ip saddr mod 16 seed 0xdeadbeef offset 100
+    // log prefix "input: " level debug flags tcp sequence,options
+    // counter name "cnt"
+    // dup to 10.0.0.1 device "lo" reject with tcp reset
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Ct(vec![
+                CtAttribute::Key(CtKey::SrcIp),
+                CtAttribute::Direction(CtDirection::Original),
+                CtAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 1,
+                ])]),
+            ]),
+            Expression::Connlimit(vec![
+                ConnlimitAttribute::Count(10),
+                ConnlimitAttribute::Flags(ConnlimitFlags::Inv),
+            ]),
+            Expression::Quota(vec![
+                QuotaAttribute::Bytes(1048576),
+                QuotaAttribute::Flags(QuotaFlags::Inv),
+                QuotaAttribute::Consumed(0),
+            ]),
+            Expression::Numgen(vec![
+                NumgenAttribute::Dreg(Register::Reg(1)),
+                NumgenAttribute::Modulus(4),
+                NumgenAttribute::Type(NumgenType::Incremental),
+                NumgenAttribute::Offset(1),
+            ]),
+            Expression::Ct(vec![
+                CtAttribute::Key(CtKey::Mark),
+                CtAttribute::Sreg(Register::Reg(1)),
+            ]),
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(12),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Hash(vec![
+                HashAttribute::Sreg(Register::Reg(1)),
+                HashAttribute::Dreg(Register::Reg(1)),
+                HashAttribute::Len(4),
+                HashAttribute::Modulus(16),
+                HashAttribute::Seed(0xdeadbeef),
+                HashAttribute::Offset(100),
+                HashAttribute::Type(HashType::Jenkins),
+            ]),
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::Mark),
+                MetaAttribute::Sreg(Register::Reg(1)),
+            ]),
+            Expression::Log(vec![
+                LogAttribute::Prefix("input: ".to_string()),
+                LogAttribute::Level(LogLevel::Debug),
+                LogAttribute::Flags(LogFlags::TcpSeq | LogFlags::TcpOpt),
+            ]),
+            Expression::Objref(vec![
+                ObjrefAttribute::ImmType(ObjectType::Counter),
+                ObjrefAttribute::ImmName("cnt".to_string()),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 1,
+                ])]),
+            ]),
+            // Index of lo, in the host byte order of a little-endian kernel
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(2)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    1u32.to_le_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Dup(vec![
+                DupAttribute::SregAddr(Register::Reg(1)),
+                DupAttribute::SregDev(Register::Reg(2)),
+            ]),
+            Expression::Reject(vec![RejectAttribute::Type(RejectType::TcpRst)]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_netdev_fwd() {
+    let raw: Vec<u8> = vec![
+        0x05, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x00,
+        0x9c, 0x00, 0x04, 0x80, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x64, 0x75, 0x70, 0x00,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65,
+        0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x28, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x66, 0x77, 0x64, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // dup to "lo" fwd ip to 10.0.0.2 device "lo"
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("ingress".to_string()),
+        RuleAttribute::Expressions(vec![
+            // Index of lo, in the host byte order of a little-endian kernel
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    1u32.to_le_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Dup(vec![DupAttribute::SregDev(Register::Reg(1))]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(2)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 2,
+                ])]),
+            ]),
+            Expression::Fwd(vec![
+                FwdAttribute::SregDev(Register::Reg(1)),
+                FwdAttribute::SregAddr(Register::Reg(2)),
+                FwdAttribute::NfProto(ProtoFamily::IPv4),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::NetDev, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_masq() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x10, 0x00, 0x02, 0x00, 0x70, 0x6f, 0x73, 0x74, 0x72, 0x6f, 0x75, 0x74,
+        0x69, 0x6e, 0x67, 0x00, 0x88, 0x00, 0x04, 0x80, 0x2c, 0x00, 0x01, 0x80,
+        0x0e, 0x00, 0x01, 0x00, 0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74,
+        0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x0c, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x01, 0x80, 0x09, 0x00, 0x01, 0x00, 0x6d, 0x61, 0x73, 0x71,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // masquerade to :1024-2048 random
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("postrouting".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    1024u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(2)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    2048u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Masq(vec![
+                MasqAttribute::Flags(
+                    NatFlags::ProtoSpecified | NatFlags::ProtoRandom,
+                ),
+                MasqAttribute::RegProtoMin(Register::Reg(1)),
+                MasqAttribute::RegProtoMax(Register::Reg(2)),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_redir() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0f, 0x00, 0x02, 0x00, 0x70, 0x72, 0x65, 0x72, 0x6f, 0x75, 0x74, 0x69,
+        0x6e, 0x67, 0x00, 0x00, 0xa4, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80,
+        0x09, 0x00, 0x01, 0x00, 0x6d, 0x65, 0x74, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x63, 0x6d, 0x70, 0x00, 0x20, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x0c, 0x00, 0x02, 0x80, 0x06, 0x00, 0x01, 0x00, 0x1f, 0x90, 0x00, 0x00,
+        0x24, 0x00, 0x01, 0x80, 0x0a, 0x00, 0x01, 0x00, 0x72, 0x65, 0x64, 0x69,
+        0x72, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // meta l4proto tcp redirect to :8080
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("prerouting".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Meta(vec![
+                MetaAttribute::Key(MetaKey::L4Proto),
+                MetaAttribute::Dreg(Register::Reg(1)),
+            ]),
+            Expression::Cmp(vec![
+                CmpAttribute::Sreg(Register::Reg(1)),
+                CmpAttribute::Op(CmpOp::Eq),
+                CmpAttribute::Data(vec![DataAttribute::from(Protocol::Tcp)]),
+            ]),
+            Expression::Immediate(vec![
+                ImmediateAttribute::Dreg(Register::Reg(1)),
+                ImmediateAttribute::Data(vec![DataAttribute::Value(
+                    8080u16.to_be_bytes().to_vec(),
+                )]),
+            ]),
+            Expression::Redir(vec![
+                RedirAttribute::RegProtoMin(Register::Reg(1)),
+                RedirAttribute::Flags(NatFlags::ProtoSpecified),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_reject_icmpx() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x28, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x01, 0x00,
+        0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x05, 0x00, 0x02, 0x00,
+        0x03, 0x00, 0x00, 0x00,
+    ];
+
+    // reject with icmpx admin-prohibited
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![Expression::Reject(vec![
+            RejectAttribute::Type(RejectType::IcmpxUnreach),
+            // NFT_REJECT_ICMPX_ADMIN_PROHIBITED
+            RejectAttribute::IcmpCode(3),
+        ])]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Inet, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_queue() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0x30, 0x00, 0x04, 0x80, 0x2c, 0x00, 0x01, 0x80, 0x0a, 0x00, 0x01, 0x00,
+        0x71, 0x75, 0x65, 0x75, 0x65, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x00,
+    ];
+
+    // queue flags bypass,fanout num 1-4, not checked against the kernel as
+    // it was built without nft_queue
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![Expression::Queue(vec![
+            QueueAttribute::Num(1),
+            QueueAttribute::Total(4),
+            QueueAttribute::Flags(QueueFlags::Bypass | QueueFlags::CpuFanout),
+        ])]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::Inet, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_concat_interval_set() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x61, 0x6c, 0x6c, 0x6f,
+        0x77, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x84,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0xcd, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x20, 0x00, 0x09, 0x80, 0x1c, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x04, 0x0c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    // set allow { type ipv4_addr . inet_service; flags interval; }
+    let attributes = vec![
+        SetAttribute::Table("filter".to_string()),
+        SetAttribute::Name("allow".to_string()),
+        SetAttribute::Flags(SetFlags::Interval | SetFlags::Concat),
+        SetAttribute::KeyType(461),
+        SetAttribute::KeyLen(8),
+        SetAttribute::Id(1),
+        SetAttribute::Desc(vec![SetDescAttribute::Concat(vec![
+            SetConcatField::Field(vec![SetFieldAttribute::Len(4)]),
+            SetConcatField::Field(vec![SetFieldAttribute::Len(2)]),
+        ])]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_concat_interval_set_elements() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x61, 0x6c, 0x6c, 0x6f,
+        0x77, 0x00, 0x00, 0x00, 0x58, 0x00, 0x03, 0x80, 0x24, 0x00, 0x01, 0x80,
+        0x10, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x10, 0x00, 0x0a, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0xff, 0x00, 0x16, 0x00, 0x00, 0x30, 0x00, 0x01, 0x80,
+        0x10, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x01,
+        0x01, 0xbb, 0x00, 0x00, 0x10, 0x00, 0x0a, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0xc0, 0xa8, 0x01, 0x01, 0x01, 0xbb, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x36, 0xee, 0x80,
+    ];
+
+    // add element filter allow {
+    //     10.0.0.0/24 . 22, 192.168.1.1 . 443 timeout 1h
+    // }
+    let ssh = 22u16.to_be_bytes();
+    let https = 443u16.to_be_bytes();
+    let attributes =
+        vec![
+            SetElementListAttribute::Table("filter".to_string()),
+            SetElementListAttribute::Set("allow".to_string()),
+            SetElementListAttribute::Elements(vec![
+                SetElement::Element(vec![
+                    SetElementAttribute::Key(vec![DataAttribute::concat(&[
+                        &[10, 0, 0, 0],
+                        &ssh,
+                    ])]),
+                    SetElementAttribute::KeyEnd(vec![DataAttribute::concat(
+                        &[&[10, 0, 0, 255], &ssh],
+                    )]),
+                ]),
+                SetElement::Element(vec![
+                    SetElementAttribute::Key(vec![DataAttribute::concat(&[
+                        &[192, 168, 1, 1],
+                        &https,
+                    ])]),
+                    SetElementAttribute::KeyEnd(vec![DataAttribute::concat(
+                        &[&[192, 168, 1, 1], &https],
+                    )]),
+                    SetElementAttribute::Timeout(3_600_000),
+                ]),
+            ]),
+        ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSetElement(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSetElement) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_verdict_map() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0e, 0x00, 0x02, 0x00, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x6c, 0x69, 0x73,
+        0x74, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x18,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x36, 0xee, 0x80, 0x08, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x75, 0x30,
+        0x12, 0x00, 0x0d, 0x00, 0x00, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x65,
+        0x64, 0x20, 0x69, 0x70, 0x73, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x11, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x00,
+        0x1c, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // map blocklist { type ipv4_addr : verdict; flags timeout; timeout 1h;
+    // gc-interval 30s; policy memory; counter; comment "blocked ips"; }
+    let userdata = vec![
+        0x00, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x69, 0x70,
+        0x73, 0x00,
+    ];
+    let attributes = vec![
+        SetAttribute::Table("fw".to_string()),
+        SetAttribute::Name("blocklist".to_string()),
+        SetAttribute::Flags(SetFlags::Map | SetFlags::Timeout | SetFlags::Expr),
+        // ipv4_addr
+        SetAttribute::KeyType(7),
+        SetAttribute::KeyLen(4),
+        // verdict
+        SetAttribute::DataType(0xffffff00),
+        SetAttribute::Policy(SetPolicy::Memory),
+        SetAttribute::Id(1),
+        SetAttribute::Timeout(3_600_000),
+        SetAttribute::GcInterval(30_000),
+        SetAttribute::Userdata(userdata),
+        SetAttribute::Expr(Expression::Counter(vec![
+            NftCounter::Bytes(0),
+            NftCounter::Packets(0),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_verdict_map_elements() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0e, 0x00, 0x02, 0x00, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x6c, 0x69, 0x73,
+        0x74, 0x00, 0x00, 0x00, 0xec, 0x00, 0x03, 0x80, 0x64, 0x00, 0x01, 0x80,
+        0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x01,
+        0x10, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x09, 0x27, 0xc0, 0x0c, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x93, 0xe0, 0x2c, 0x00, 0x07, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x68, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x02, 0x80, 0x18, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0xfd, 0x0a, 0x00, 0x02, 0x00,
+        0x6f, 0x74, 0x68, 0x65, 0x72, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x06, 0x00,
+        0x00, 0x07, 0x73, 0x73, 0x68, 0x20, 0x62, 0x66, 0x00, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x07, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x63, 0x6f, 0x75, 0x6e,
+        0x74, 0x65, 0x72, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x02, 0x80,
+        0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // add element ip fw blocklist {
+    //     10.0.0.1 timeout 10m expires 5m counter : drop,
+    //     10.0.0.2 counter comment "ssh bf" : jump other,
+    //     * : accept
+    // }
+    let userdata = vec![0x00, 0x07, 0x73, 0x73, 0x68, 0x20, 0x62, 0x66, 0x00];
+    let attributes = vec![
+        SetElementListAttribute::Table("fw".to_string()),
+        SetElementListAttribute::Set("blocklist".to_string()),
+        SetElementListAttribute::Elements(vec![
+            SetElement::Element(vec![
+                SetElementAttribute::Key(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 1,
+                ])]),
+                SetElementAttribute::Data(vec![DataAttribute::Verdict(vec![
+                    VerdictAttribute::Code(VerdictCode::Drop),
+                ])]),
+                SetElementAttribute::Timeout(600_000),
+                SetElementAttribute::Expiration(300_000),
+                SetElementAttribute::Expr(Expression::Counter(vec![
+                    NftCounter::Bytes(0),
+                    NftCounter::Packets(0),
+                ])),
+            ]),
+            SetElement::Element(vec![
+                SetElementAttribute::Key(vec![DataAttribute::Value(vec![
+                    10, 0, 0, 2,
+                ])]),
+                SetElementAttribute::Data(vec![DataAttribute::Verdict(vec![
+                    VerdictAttribute::Code(VerdictCode::Jump),
+                    VerdictAttribute::Chain("other".to_string()),
+                ])]),
+                SetElementAttribute::Userdata(userdata),
+                SetElementAttribute::Expr(Expression::Counter(vec![
+                    NftCounter::Bytes(0),
+                    NftCounter::Packets(0),
+                ])),
+            ]),
+            SetElement::Element(vec![
+                SetElementAttribute::Flags(SetElementFlags::Catchall),
+                SetElementAttribute::Data(vec![DataAttribute::Verdict(vec![
+                    VerdictAttribute::Code(VerdictCode::Accept),
+                ])]),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSetElement(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSetElement) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_object_map() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x09, 0x00, 0x02, 0x00, 0x63, 0x6e, 0x74, 0x73, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x40, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x07, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x0c, 0x00, 0x09, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x40,
+        0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // map cnts { type ipv4_addr : counter; size 64; }
+    let attributes = vec![
+        SetAttribute::Table("fw".to_string()),
+        SetAttribute::Name("cnts".to_string()),
+        SetAttribute::Flags(SetFlags::Object),
+        SetAttribute::KeyType(7),
+        SetAttribute::KeyLen(4),
+        SetAttribute::Desc(vec![SetDescAttribute::Size(64)]),
+        SetAttribute::Id(2),
+        SetAttribute::ObjType(ObjectType::Counter),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSet(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSet) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_object_map_elements() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x09, 0x00, 0x02, 0x00, 0x63, 0x6e, 0x74, 0x73, 0x00, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x03, 0x80, 0x18, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0xa8, 0x01, 0x01, 0x08, 0x00, 0x09, 0x00,
+        0x63, 0x6e, 0x74, 0x00,
+    ];
+
+    // add element ip fw cnts { 192.168.1.1 : "cnt" }
+    let attributes = vec![
+        SetElementListAttribute::Table("fw".to_string()),
+        SetElementListAttribute::Set("cnts".to_string()),
+        SetElementListAttribute::Elements(vec![SetElement::Element(vec![
+            SetElementAttribute::Key(vec![DataAttribute::Value(vec![
+                192, 168, 1, 1,
+            ])]),
+            SetElementAttribute::Objref("cnt".to_string()),
+        ])]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewSetElement(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewSetElement) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_rule_map_lookup() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x0a, 0x00, 0x02, 0x00, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x00, 0x00, 0x00,
+        0xa4, 0x00, 0x04, 0x80, 0x34, 0x00, 0x01, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x00, 0x24, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0c,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x04, 0x30, 0x00, 0x01, 0x80,
+        0x0b, 0x00, 0x01, 0x00, 0x6f, 0x62, 0x6a, 0x72, 0x65, 0x66, 0x00, 0x00,
+        0x20, 0x00, 0x02, 0x80, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x09, 0x00, 0x04, 0x00, 0x63, 0x6e, 0x74, 0x73, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x02, 0x3c, 0x00, 0x01, 0x80,
+        0x0b, 0x00, 0x01, 0x00, 0x6c, 0x6f, 0x6f, 0x6b, 0x75, 0x70, 0x00, 0x00,
+        0x2c, 0x00, 0x02, 0x80, 0x0e, 0x00, 0x01, 0x00, 0x62, 0x6c, 0x6f, 0x63,
+        0x6b, 0x6c, 0x69, 0x73, 0x74, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // counter name ip saddr map @cnts ip saddr vmap @blocklist
+    let attributes = vec![
+        RuleAttribute::Table("fw".to_string()),
+        RuleAttribute::Chain("input".to_string()),
+        RuleAttribute::Expressions(vec![
+            Expression::Payload(vec![
+                PayloadAttribute::Dreg(Register::Reg(1)),
+                PayloadAttribute::Base(PayloadBase::Network),
+                PayloadAttribute::Offset(12),
+                PayloadAttribute::Len(4),
+            ]),
+            Expression::Objref(vec![
+                ObjrefAttribute::SetSreg(Register::Reg(1)),
+                ObjrefAttribute::SetName("cnts".to_string()),
+                ObjrefAttribute::SetId(2),
+            ]),
+            Expression::Lookup(vec![
+                LookupAttribute::Set("blocklist".to_string()),
+                LookupAttribute::Sreg(Register::Reg(1)),
+                LookupAttribute::Dreg(Register::Verdict),
+                LookupAttribute::SetId(1),
+            ]),
+        ]),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewRule(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewRule) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_nftables_counter_object_reset() {
+    // nft reset counter filter web
+    let request: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x77, 0x65, 0x62, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::GetObjectReset(vec![
+            ObjectAttribute::Table("filter".to_string()),
+            ObjectAttribute::Name("web".to_string()),
+            ObjectAttribute::Type(ObjectType::Counter),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, request);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::GetObjectReset) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&request),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x77, 0x65, 0x62, 0x00,
+        0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x1c, 0x00, 0x04, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0xe3, 0x60, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xb0,
+    ];
+
+    // The kernel replies with the values of the counter before the reset
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(vec![
+            ObjectAttribute::Table("filter".to_string()),
+            ObjectAttribute::Name("web".to_string()),
+            ObjectAttribute::Handle(3),
+            ObjectAttribute::Type(ObjectType::Counter),
+            ObjectAttribute::Use(1),
+            ObjectAttribute::Data(ObjectData::Counter(vec![
+                NftCounter::Bytes(1_500_000),
+                NftCounter::Packets(1200),
+            ])),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_ct_timeout_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x66, 0x69, 0x6c, 0x74,
+        0x65, 0x72, 0x00, 0x00, 0x0e, 0x00, 0x02, 0x00, 0x74, 0x63, 0x70, 0x2d,
+        0x73, 0x68, 0x6f, 0x72, 0x74, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x07, 0x28, 0x00, 0x04, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x03, 0x80, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x2c,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    ];
+
+    // add ct timeout filter tcp-short {
+    //     protocol tcp; l3proto ip; policy = { established: 300, close: 10 }
+    // }
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(vec![
+            ObjectAttribute::Table("filter".to_string()),
+            ObjectAttribute::Name("tcp-short".to_string()),
+            ObjectAttribute::Type(ObjectType::CtTimeout),
+            ObjectAttribute::Data(ObjectData::CtTimeout(vec![
+                CtTimeoutAttribute::L3Proto(ProtoFamily::IPv4),
+                CtTimeoutAttribute::L4Proto(Protocol::Tcp),
+                CtTimeoutAttribute::Data(TimeoutData::Tcp(vec![
+                    TcpTimeout::Established(300),
+                    TcpTimeout::Close(10),
+                ])),
+            ])),
+        ]),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_quota_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x71, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x24, 0x00, 0x04, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x40, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // add quota fw q { over 100 mbytes }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("q".to_string()),
+        ObjectAttribute::Type(ObjectType::Quota),
+        ObjectAttribute::Data(ObjectData::Quota(vec![
+            QuotaAttribute::Bytes(100 * 1024 * 1024),
+            QuotaAttribute::Flags(QuotaFlags::Inv),
+            QuotaAttribute::Consumed(0),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_limit_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x6c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x34, 0x00, 0x04, 0x80, 0x0c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x0c, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // add limit fw l { rate 10/minute burst 5 packets }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("l".to_string()),
+        ObjectAttribute::Type(ObjectType::Limit),
+        ObjectAttribute::Data(ObjectData::Limit(vec![
+            LimitAttribute::Rate(10),
+            LimitAttribute::Unit(60),
+            LimitAttribute::Burst(5),
+            LimitAttribute::Type(LimitType::Packets),
+            LimitAttribute::Flags(LimitFlags::empty()),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_connlimit_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x63, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x14, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x14, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    // add ct count fw c { over 20 }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("c".to_string()),
+        ObjectAttribute::Type(ObjectType::Connlimit),
+        ObjectAttribute::Data(ObjectData::Connlimit(vec![
+            ConnlimitAttribute::Count(20),
+            ConnlimitAttribute::Flags(ConnlimitFlags::Inv),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_ct_helper_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x11, 0x00, 0x02, 0x00, 0x66, 0x74, 0x70, 0x2d, 0x73, 0x74, 0x61, 0x6e,
+        0x64, 0x61, 0x72, 0x64, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x1c, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x66, 0x74, 0x70, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x06, 0x00, 0x00, 0x00,
+    ];
+
+    // add ct helper fw ftp-standard { type "ftp" protocol tcp; l3proto ip; }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("ftp-standard".to_string()),
+        ObjectAttribute::Type(ObjectType::CtHelper),
+        ObjectAttribute::Data(ObjectData::CtHelper(vec![
+            CtHelperAttribute::Name("ftp".to_string()),
+            CtHelperAttribute::L3Proto(ProtoFamily::IPv4),
+            CtHelperAttribute::L4Proto(Protocol::Tcp),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_ct_expect_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x65, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x09, 0x2c, 0x00, 0x04, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x26, 0x94, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x01, 0xd4, 0xc0, 0x05, 0x00, 0x05, 0x00, 0x0c, 0x00, 0x00, 0x00,
+    ];
+
+    // add ct expectation fw e {
+    //     protocol tcp; dport 9876; timeout 2m; size 12; l3proto ip;
+    // }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("e".to_string()),
+        ObjectAttribute::Type(ObjectType::CtExpect),
+        ObjectAttribute::Data(ObjectData::CtExpect(vec![
+            CtExpectAttribute::L3Proto(ProtoFamily::IPv4),
+            CtExpectAttribute::L4Proto(Protocol::Tcp),
+            CtExpectAttribute::DPort(9876),
+            CtExpectAttribute::Timeout(120_000),
+            CtExpectAttribute::Size(12),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_synproxy_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x73, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x1c, 0x00, 0x04, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x05, 0xb4, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0c,
+    ];
+
+    // add synproxy fw s { mss 1460; wscale 7; timestamp sack-perm; }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("s".to_string()),
+        ObjectAttribute::Type(ObjectType::Synproxy),
+        ObjectAttribute::Data(ObjectData::Synproxy(vec![
+            SynproxyAttribute::Mss(1460),
+            SynproxyAttribute::Wscale(7),
+            SynproxyAttribute::Flags(
+                SynproxyFlags::SackPerm | SynproxyFlags::Timestamp,
+            ),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_new_nftables_secmark_object() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x01, 0x00, 0x66, 0x77, 0x00, 0x00,
+        0x07, 0x00, 0x02, 0x00, 0x73, 0x6d, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x08, 0x34, 0x00, 0x04, 0x80, 0x2d, 0x00, 0x01, 0x00,
+        0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x5f, 0x75, 0x3a, 0x6f, 0x62, 0x6a,
+        0x65, 0x63, 0x74, 0x5f, 0x72, 0x3a, 0x73, 0x73, 0x68, 0x5f, 0x73, 0x65,
+        0x72, 0x76, 0x65, 0x72, 0x5f, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x74, 0x5f,
+        0x74, 0x3a, 0x73, 0x30, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // add secmark fw sm { "system_u:object_r:ssh_server_packet_t:s0" }
+    let attributes = vec![
+        ObjectAttribute::Table("fw".to_string()),
+        ObjectAttribute::Name("sm".to_string()),
+        ObjectAttribute::Type(ObjectType::Secmark),
+        ObjectAttribute::Data(ObjectData::Secmark(vec![
+            SecmarkAttribute::Ctx(
+                "system_u:object_r:ssh_server_packet_t:s0".to_string(),
+            ),
+        ])),
+    ];
+
+    let expected: NetfilterMessage = NetfilterMessage::new(
+        NetfilterHeader::new(ProtoFamily::IPv4, 0, 0),
+        NftMessage::NewObject(attributes),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+
+    // Check if the serialization was correct
+    assert_eq!(buffer, raw);
+
+    let message_type = ((u8::from(Subsystem::Nftables) as u16) << 8)
+        | (u8::from(NftMessageType::NewObject) as u16);
+    // Check if the deserialization was correct
+    assert_eq!(
+        NetfilterMessage::parse_with_param(
+            &NetfilterBuffer::new(&raw),
+            message_type
+        )
+        .unwrap(),
+        expected
+    );
+}